@@ -0,0 +1,22 @@
+#![no_main]
+#[macro_use] extern crate libfuzzer_sys;
+extern crate notty;
+
+use std::io;
+
+use notty::Parser;
+use notty::cfg::TerminalConfig;
+use notty::terminal::Terminal;
+
+// Every input is wrapped in the introducer and terminator of a notty escape code, so that the
+// fuzzer spends its time in the parsing of the arguments and attachments of notty codes and in
+// applying the commands they describe.
+fuzz_target!(|data: &[u8]| {
+    let mut terminal = Terminal::new(80, 24, io::sink(), TerminalConfig::default());
+    let mut parser = Parser::new();
+    let mut apply = |cmd| { let _ = terminal.apply(cmd); };
+    parser.feed(b"\x1b_[", &mut apply);
+    parser.feed(data, &mut apply);
+    parser.feed("\u{9c}".as_bytes(), &mut apply);
+    parser.finish(&mut apply);
+});
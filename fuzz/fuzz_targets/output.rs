@@ -0,0 +1,19 @@
+#![no_main]
+#[macro_use] extern crate libfuzzer_sys;
+extern crate notty;
+
+use std::io::{self, BufReader};
+
+use notty::Output;
+use notty::cfg::TerminalConfig;
+use notty::terminal::Terminal;
+
+fuzz_target!(|data: &[u8]| {
+    let mut terminal = Terminal::new(80, 24, io::sink(), TerminalConfig::default());
+    for cmd in Output::new(BufReader::new(data)) {
+        if let Ok(cmd) = cmd {
+            let _ = cmd.to_string();
+            let _ = terminal.apply(cmd);
+        }
+    }
+});
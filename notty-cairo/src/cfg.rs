@@ -3,7 +3,7 @@ use std::collections::BTreeMap;
 use std::mem;
 
 use notty::datatypes::{Color, ConfigStyle};
-use notty::terminal::Styles;
+use notty::terminal::{Palette, Styles};
 
 pub type TrueColor = (u8, u8, u8);
 pub type GtkColor = (f64, f64, f64);
@@ -56,6 +56,21 @@ impl Config {
     }
 }
 
+impl Palette for Config {
+    fn styles(&self, style: ConfigStyle) -> Styles {
+        self.styles.get(&style).or_else(|| self.styles.get(&ConfigStyle::Plain))
+                   .cloned().unwrap_or_else(Styles::new)
+    }
+
+    fn fg_color(&self, color: Color) -> TrueColor {
+        Config::fg_color(self, color)
+    }
+
+    fn bg_color(&self, color: Color) -> TrueColor {
+        Config::bg_color(self, color)
+    }
+}
+
 pub fn gtk_color((r, g, b): TrueColor) -> GtkColor {
     (r as f64 / 255.0, g as f64 / 255.0, b as f64 / 255.0)
 }
@@ -5,7 +5,6 @@ extern crate gdk_pixbuf as pixbuf;
 extern crate gdk_pixbuf_sys as pixbuf_sys;
 extern crate gio_sys as gio;
 extern crate glib;
-extern crate itertools;
 extern crate libc;
 extern crate notty;
 extern crate pangocairo;
@@ -19,10 +18,8 @@ use std::sync::Arc;
 
 use glib::translate::ToGlibPtr;
 
-use itertools::Itertools;
-
-use notty::datatypes::Coords;
-use notty::terminal::{CellData, Terminal, ImageData, Styleable};
+use notty::datatypes::{Coords, LineSize, Region};
+use notty::terminal::{CellData, Damage, Terminal, ImageData, RunContent, Styleable};
 
 use pangocairo::wrap::{PangoLayout, PangoAttrList};
 
@@ -34,6 +31,8 @@ pub use self::cfg::{Config, TrueColor, PALETTE};
 
 pub struct Renderer {
     images: HashMap<Arc<ImageData>, ImageRenderer>,
+    placed_images: Vec<(Region, Arc<ImageData>)>,
+    frame: Option<(cairo::ImageSurface, (usize, usize))>,
     char_d: Option<(f64, f64)>,
     cfg: Config,
 }
@@ -42,6 +41,8 @@ impl Renderer {
     pub fn new(cfg: Config) -> Renderer {
         Renderer {
             images: HashMap::new(),
+            placed_images: Vec::new(),
+            frame: None,
             char_d: None,
             cfg: cfg,
         }
@@ -59,63 +60,171 @@ impl Renderer {
         terminal.set_winsize(Some(width), Some(height)).unwrap_or_else(|e| panic!("{}", e));
     }
 
-    pub fn draw(&mut self, terminal: &Terminal, canvas: &cairo::Context) {
+    /// Draw the terminal onto the canvas. Only the rows which have been damaged since the last
+    /// draw are redrawn; the rest of the screen is copied from the previous frame.
+    pub fn draw(&mut self, terminal: &Terminal, damage: &Damage, canvas: &cairo::Context) {
 
         if self.char_d.is_none() { self.char_d = Some(self.char_dimensions(canvas)); }
-        let (r, g, b) = gtk_color(self.cfg.bg_color);
-        canvas.set_source_rgb(r, g, b);
-        canvas.paint();
 
         let col_n = terminal.area().width() as usize;
-        let rows = terminal.cells().chunks(col_n);
+        let row_n = terminal.area().height() as usize;
+        let full = damage.is_all() || self.frame.as_ref().map_or(true, |&(_, dims)| {
+            dims != (col_n, row_n)
+        });
+        if full {
+            let w_pix = self.x_pixels(col_n as u32).ceil() as i32;
+            let h_pix = self.y_pixels(row_n as u32).ceil() as i32;
+            let surface = cairo::ImageSurface::create(cairo::Format::ARgb32, w_pix, h_pix);
+            self.frame = Some((surface, (col_n, row_n)));
+            self.placed_images.clear();
+        }
+
+        // Nothing the program asked for survives a hard reset, so start again from the config.
+        if damage.reset() {
+            self.images.clear();
+        }
 
         // Remove dead images from the cache.
         for key in self.images.keys().filter(|k| Arc::strong_count(k) == 1).cloned().collect::<Vec<_>>() {
             self.images.remove(&key);
         }
 
-        for (y_pos, row) in rows.into_iter().enumerate() {
-            let y_pix = self.y_pixels(y_pos as u32);
-            let mut text = TextRenderer::new(&self.cfg, 0.0, y_pix);
-            for (x_pos, cell) in row.enumerate() {
-                let style = *cell.styles();
-                if (Coords { x: x_pos as u32, y: y_pos as u32 } == terminal.cursor().position()) {
-                    let cursor_style = *terminal.cursor().styles();
+        let surface = self.frame.take().unwrap();
+        {
+            let frame = cairo::Context::new(&surface.0);
+            let (r, g, b) = gtk_color(self.cfg.bg_color);
+            if full {
+                frame.set_source_rgb(r, g, b);
+                frame.paint();
+            }
+
+            let cursor = terminal.cursor_in_view();
+            for (y_pos, row) in terminal.rows().enumerate() {
+                let y = y_pos as u32;
+                if !full && !damage.is_row_damaged(y) { continue; }
+                let y_pix = self.y_pixels(y);
+                if !full {
+                    frame.rectangle(0.0, y_pix, self.x_pixels(col_n as u32), self.y_pixels(1));
+                    frame.set_source_rgb(r, g, b);
+                    frame.fill();
+                    self.placed_images.retain(|&(region, _)| region.top != y);
+                }
+
+                // Columns which no run covers are covered by an image from an earlier row.
+                let mut covered = vec![];
+                let mut next_column = 0;
+                let mut text = TextRenderer::new(&self.cfg, 0.0, y_pix);
+                for run in &row {
+                    covered.extend((next_column..run.column).map(|x| Coords { x: x, y: y }));
+                    if run.column != next_column {
+                        text.draw(&frame);
+                        text = TextRenderer::new(&self.cfg, self.x_pixels(run.column), y_pix);
+                    }
+                    next_column = run.column + run.width;
+                    match run.content {
+                        RunContent::Text(ref s) | RunContent::Wide(ref s) => {
+                            if run.line == LineSize::Normal {
+                                text.push_str(s, run.styles);
+                                continue;
+                            }
+                            text.draw(&frame);
+                            let mut scaled = TextRenderer::new(&self.cfg, 0.0, 0.0);
+                            scaled.push_str(s, run.styles);
+                            self.draw_scaled(&frame, &scaled, run.column, y, run.width, run.line);
+                            text = TextRenderer::new(&self.cfg, self.x_pixels(next_column), y_pix);
+                        }
+                        RunContent::Image(ref image) => {
+                            text.draw(&frame);
+                            text = TextRenderer::new(&self.cfg, self.x_pixels(next_column), y_pix);
+                            self.placed_images.push((image.area, image.data.clone()));
+                            if let Some(renderer) = self.images.get(&image.data) {
+                                renderer.draw(&frame);
+                                continue;
+                            }
+                            let x_pix = self.x_pixels(run.column);
+                            let w_pix = self.x_pixels(image.area.width());
+                            let h_pix = self.y_pixels(image.area.height());
+                            let renderer = ImageRenderer::new(&image.data.data, x_pix, y_pix,
+                                                              w_pix, h_pix, image.pos);
+                            renderer.draw(&frame);
+                            self.images.insert(image.data.clone(), renderer);
+                        }
+                    }
+                }
+                covered.extend((next_column..col_n as u32).map(|x| Coords { x: x, y: y }));
+                text.draw(&frame);
+
+                // Draw the cursor over the cell it is in.
+                if let Some(coords) = cursor.and_then(|c| if c.y == y { Some(c) } else { None }) {
+                    let cell = &terminal[coords];
+                    let (style, cursor_style) = (*cell.styles(), *terminal.cursor().styles());
+                    let line = terminal.line_size_in_view(coords.y);
+                    let mut text = match line {
+                        LineSize::Normal    => {
+                            TextRenderer::new(&self.cfg, self.x_pixels(coords.x), y_pix)
+                        }
+                        _                   => TextRenderer::new(&self.cfg, 0.0, 0.0),
+                    };
                     match *cell.content() {
                         CellData::Empty             => text.push_cursor(' ', style, cursor_style),
                         CellData::Char(ch)          => text.push_cursor(ch, style, cursor_style),
                         CellData::Grapheme(ref s)   => text.push_str_cursor(s, style, cursor_style),
-                        CellData::Extension(_)      => unreachable!(),
-                        CellData::Image { .. }      => continue,
+                        CellData::Extension(_)      => { }
+                        CellData::Image { .. }      => { }
+                    }
+                    match line {
+                        LineSize::Normal    => text.draw(&frame),
+                        _                   => self.draw_scaled(&frame, &text, coords.x * 2, y, 2,
+                                                                line),
                     }
-                    continue;
                 }
-                match *cell.content() {
-                    CellData::Empty             => text.push(' ', style),
-                    CellData::Char(ch)          => text.push(ch, style),
-                    CellData::Grapheme(ref s)   => text.push_str(s, style),
-                    CellData::Extension(_)      => { }
-                    CellData::Image { ref data, ref pos, ref width, ref height, .. } => {
-                        let x_pix = self.x_pixels(x_pos as u32);
-                        if (x_pos + *width as usize) < col_n {
-                            text.draw(canvas);
-                            text = TextRenderer::new(&self.cfg, x_pix, y_pix);
-                        }
-                        if let Some(image) = self.images.get(data) {
-                            image.draw(canvas);
-                            continue;
+
+                // Redraw the part of this row covered by images which begin on a row above it.
+                if !full {
+                    frame.save();
+                    frame.rectangle(0.0, y_pix, self.x_pixels(col_n as u32), self.y_pixels(1));
+                    frame.clip();
+                    for &(region, ref data) in &self.placed_images {
+                        if region.top == y { continue; }
+                        if covered.iter().any(|&coords| region.contains(coords)) {
+                            self.images.get(data).map(|image| image.draw(&frame));
                         }
-                        let w_pix = self.x_pixels(*width);
-                        let h_pix = self.y_pixels(*height);
-                        let img = ImageRenderer::new(&data.data, x_pix, y_pix, w_pix, h_pix,
-                                                     *pos);
-                        img.draw(canvas);
-                        self.images.insert(data.clone(), img);
                     }
+                    frame.restore();
                 }
             }
-            text.draw(canvas);
         }
+
+        canvas.set_source_surface(&surface.0, 0.0, 0.0);
+        canvas.paint();
+        self.frame = Some(surface);
+    }
+
+    /// Draw text which was laid out at the origin over `width` columns of row `y`, beginning at
+    /// column `x`, scaled to the size of the line. Only the half of a double height line which
+    /// belongs in this row is drawn.
+    fn draw_scaled(&self, canvas: &cairo::Context, text: &TextRenderer, x: u32, y: u32,
+                   width: u32, line: LineSize) {
+        let (x_pix, y_pix) = (self.x_pixels(x), self.y_pixels(y));
+        canvas.save();
+        canvas.rectangle(x_pix, y_pix, self.x_pixels(width), self.y_pixels(1));
+        canvas.clip();
+        match line {
+            LineSize::Normal | LineSize::DoubleWidth    => {
+                canvas.translate(x_pix, y_pix);
+                canvas.scale(2.0, 1.0);
+            }
+            LineSize::DoubleHeightTop                   => {
+                canvas.translate(x_pix, y_pix);
+                canvas.scale(2.0, 2.0);
+            }
+            LineSize::DoubleHeightBottom                => {
+                canvas.translate(x_pix, y_pix - self.y_pixels(1));
+                canvas.scale(2.0, 2.0);
+            }
+        }
+        text.draw(canvas);
+        canvas.restore();
     }
 
     fn char_dimensions(&self, canvas: &cairo::Context) -> (f64, f64) {
@@ -0,0 +1,159 @@
+//  notty is a new kind of terminal emulator.
+//  Copyright (C) 2015 without boats
+//  
+//  This program is free software: you can redistribute it and/or modify
+//  it under the terms of the GNU Affero General Public License as published by
+//  the Free Software Foundation, either version 3 of the License, or
+//  (at your option) any later version.
+//  
+//  This program is distributed in the hope that it will be useful,
+//  but WITHOUT ANY WARRANTY; without even the implied warranty of
+//  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+//  GNU Affero General Public License for more details.
+//  
+//  You should have received a copy of the GNU Affero General Public License
+//  along with this program.  If not, see <http://www.gnu.org/licenses/>.
+//! Print what notty parses from a stream of output, one command per line, with the offsets of
+//! the bytes each command was parsed from.
+extern crate notty;
+
+use std::cell::RefCell;
+use std::cmp;
+use std::env;
+use std::fs::File;
+use std::io::{self, BufReader, Read, Write};
+use std::ops::Range;
+use std::process;
+use std::rc::Rc;
+
+use notty::{Output, Parsed};
+use notty::cfg::{TerminalConfig, WidthPolicy};
+
+const USAGE: &'static str = "\
+Usage: notty-dump [OPTIONS] [FILE]
+
+Parse the output of a program from FILE, or from stdin if FILE is absent or -,
+and print each command parsed from it on its own line, with the offsets of the
+bytes it was parsed from and those bytes. Lines marked ? are sequences notty
+does not support; lines marked ! are bytes which were dropped.
+
+Options:
+    -w, --width POLICY      characters of ambiguous width are narrow, wide, or as
+                            wide as in the locale (default narrow)
+    -h, --help              print this message
+";
+
+fn main() {
+    let mut config = TerminalConfig::default();
+    let mut path = None;
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match &arg[..] {
+            "-h" | "--help"             => {
+                print!("{}", USAGE);
+                return
+            }
+            "-w" | "--width"            => {
+                let policy = match args.next().as_ref().map(|s| &s[..]) {
+                    Some("narrow")  => WidthPolicy::Narrow,
+                    Some("wide")    => WidthPolicy::Wide,
+                    Some("locale")  => WidthPolicy::Locale,
+                    _               => usage("the width must be narrow, wide or locale"),
+                };
+                config = config.width_policy(policy);
+            }
+            "-"                         => path = None,
+            _ if arg.starts_with('-')   => usage(&format!("unknown option '{}'", arg)),
+            _                           => path = Some(arg.clone()),
+        }
+    }
+    if let Err(err) = dump(path, &config) {
+        let _ = writeln!(io::stderr(), "notty-dump: {}", err);
+        process::exit(1);
+    }
+}
+
+fn usage(msg: &str) -> ! {
+    let _ = write!(io::stderr(), "notty-dump: {}\n\n{}", msg, USAGE);
+    process::exit(2);
+}
+
+fn dump(path: Option<String>, config: &TerminalConfig) -> io::Result<()> {
+    let input: Box<Read> = match path {
+        Some(path)  => Box::new(try!(File::open(path))),
+        None        => Box::new(io::stdin()),
+    };
+    let pending = Rc::new(RefCell::new(Pending::default()));
+    let stdout = io::stdout();
+    let mut out = stdout.lock();
+    let input = BufReader::new(Keep { reader: input, pending: pending.clone() });
+    let mut output = Output::with_config(input, config);
+    while let Some(span) = output.next_span() {
+        let (range, parsed) = try!(span);
+        let bytes = escape(&pending.borrow_mut().take(range.clone()));
+        let (mark, repr) = match parsed {
+            Parsed::Command(cmd)        => (' ', cmd.to_string()),
+            Parsed::Unsupported(code)   => ('?', code),
+            Parsed::Dropped             => ('!', String::from("DROPPED")),
+        };
+        try!(writeln!(out, "{:>8} {:>8} {} {:<40} {}", range.start, range.end, mark,
+                      escape_str(&repr), bytes));
+    }
+    Ok(())
+}
+
+/// A reader which keeps the bytes read from it until the spans they were parsed into have been
+/// printed.
+struct Keep<R> {
+    reader: R,
+    pending: Rc<RefCell<Pending>>,
+}
+
+impl<R: Read> Read for Keep<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let n = try!(self.reader.read(buf));
+        self.pending.borrow_mut().bytes.extend_from_slice(&buf[..n]);
+        Ok(n)
+    }
+}
+
+/// The bytes which have been read but not printed yet, the first of which is at offset `start`.
+#[derive(Default)]
+struct Pending {
+    start: u64,
+    bytes: Vec<u8>,
+}
+
+impl Pending {
+    /// Take the bytes of a span, forgetting every byte before its end.
+    fn take(&mut self, range: Range<u64>) -> Vec<u8> {
+        let begin = cmp::min(range.start.saturating_sub(self.start) as usize, self.bytes.len());
+        let end = cmp::min(range.end.saturating_sub(self.start) as usize, self.bytes.len());
+        let span = self.bytes[begin..end].to_vec();
+        self.bytes.drain(..end);
+        self.start += end as u64;
+        span
+    }
+}
+
+/// Escape control characters and bytes which are not ASCII, so that each command is printed on
+/// one line and the output is not interpreted by the terminal it is printed to.
+fn escape(bytes: &[u8]) -> String {
+    let mut escaped = String::new();
+    for &b in bytes {
+        match b {
+            b'\\'           => escaped.push_str("\\\\"),
+            b' '...b'~'     => escaped.push(b as char),
+            _               => escaped.push_str(&format!("\\x{:02x}", b)),
+        }
+    }
+    escaped
+}
+
+fn escape_str(s: &str) -> String {
+    let mut escaped = String::new();
+    for c in s.chars() {
+        if c.is_control() { escaped.extend(c.escape_default()); } else { escaped.push(c); }
+    }
+    escaped
+}
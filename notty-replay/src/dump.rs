@@ -0,0 +1,212 @@
+//  notty is a new kind of terminal emulator.
+//  Copyright (C) 2015 without boats
+//  
+//  This program is free software: you can redistribute it and/or modify
+//  it under the terms of the GNU Affero General Public License as published by
+//  the Free Software Foundation, either version 3 of the License, or
+//  (at your option) any later version.
+//  
+//  This program is distributed in the hope that it will be useful,
+//  but WITHOUT ANY WARRANTY; without even the implied warranty of
+//  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+//  GNU Affero General Public License for more details.
+//  
+//  You should have received a copy of the GNU Affero General Public License
+//  along with this program.  If not, see <http://www.gnu.org/licenses/>.
+use std::io::{self, Write};
+
+use notty::asciicast::quote;
+use notty::datatypes::{Color, ConfigStyle};
+use notty::datatypes::args::Argument;
+use notty::terminal::{ExportFormat, ExportRange, Palette, RunContent, StyledRun, Styles,
+                      Terminal, UseStyles};
+
+#[derive(Copy, Clone)]
+pub enum Format {
+    Text,
+    Json,
+    Html,
+}
+
+/// Print the screen of the terminal. If `commands` is given, it is the number of commands which
+/// have been applied so far, and the screen is labeled with it.
+pub fn dump<W: Write>(terminal: &Terminal, format: Format, commands: Option<usize>, out: &mut W)
+        -> io::Result<()> {
+    match format {
+        Format::Text    => {
+            if let Some(n) = commands {
+                try!(writeln!(out, "-- after {} commands --", n));
+            }
+            for row in terminal.snapshot().rows() {
+                try!(writeln!(out, "{}", text(row).trim_right()));
+            }
+            Ok(())
+        }
+        Format::Json    => writeln!(out, "{}", json(terminal, commands)),
+        Format::Html    => {
+            if let Some(n) = commands {
+                try!(writeln!(out, "<!-- after {} commands -->", n));
+            }
+            let html = terminal.export(ExportRange::Screen, ExportFormat::Html(&XtermPalette));
+            write!(out, "{}", html)
+        }
+    }
+}
+
+/// The text of a row of the screen. Cells which are covered by images are spaces.
+fn text(row: &[StyledRun]) -> String {
+    let mut text = String::new();
+    let mut column = 0;
+    for run in row {
+        for _ in column..run.column { text.push(' '); }
+        match run.content {
+            RunContent::Text(ref s) | RunContent::Wide(ref s)   => text.push_str(s),
+            RunContent::Image(_)                                => {
+                for _ in 0..run.width { text.push(' '); }
+            }
+        }
+        column = run.column + run.width;
+    }
+    text
+}
+
+/// The screen as a single line of JSON, with the runs of cells in each row and their styles.
+fn json(terminal: &Terminal, commands: Option<usize>) -> String {
+    let frame = terminal.snapshot();
+    let (width, height) = frame.dims();
+    let cursor = match frame.cursor() {
+        Some(cursor)    => format!("{{\"x\":{},\"y\":{}}}", cursor.position.x, cursor.position.y),
+        None            => String::from("null"),
+    };
+    let rows = frame.rows().iter().map(|row| {
+        let runs = row.iter().map(|run| {
+            let content = match run.content {
+                RunContent::Text(ref s) | RunContent::Wide(ref s)   => {
+                    format!("\"text\":{}", quote(s))
+                }
+                RunContent::Image(ref image)                        => {
+                    format!("\"image\":{{\"mime\":{},\"height\":{}}}",
+                            quote(&image.mime.to_string()), image.area.height())
+                }
+            };
+            format!("{{\"column\":{},\"width\":{},{},\"styles\":{}}}",
+                    run.column, run.width, content, styles(run.styles))
+        }).collect::<Vec<_>>();
+        format!("[{}]", runs.join(","))
+    }).collect::<Vec<_>>();
+    let commands = commands.map_or(String::new(), |n| format!("\"commands\":{},", n));
+    format!("{{{}\"width\":{},\"height\":{},\"cursor\":{},\"rows\":[{}]}}",
+            commands, width, height, cursor, rows.join(","))
+}
+
+fn styles(styles: UseStyles) -> String {
+    match styles {
+        UseStyles::Config(style)    => format!("{{\"config\":{}}}", quote(&style.encode())),
+        UseStyles::Custom(styles)   => {
+            format!("{{\"fg\":{},\"bg\":{},\"opacity\":{},\"bold\":{},\"italic\":{},\
+                     \"underline\":{},\"double_underline\":{},\"strikethrough\":{},\
+                     \"inverted\":{},\"blink\":{}}}",
+                    color(styles.fg_color), color(styles.bg_color), styles.opacity,
+                    styles.bold, styles.italic, styles.underline, styles.double_underline,
+                    styles.strikethrough, styles.inverted, styles.blink)
+        }
+    }
+}
+
+/// Colors are `"default"`, an index into the palette, or a `"#rrggbb"` string.
+fn color(color: Color) -> String {
+    match color {
+        Color::Default          => String::from("\"default\""),
+        Color::Palette(n)       => n.to_string(),
+        Color::True(r, g, b)    => format!("\"#{:02x}{:02x}{:02x}\"", r, g, b),
+    }
+}
+
+/// The default colors of xterm, white on black.
+struct XtermPalette;
+
+const BASE_COLORS: [(u8, u8, u8); 16] = [
+    (0x00, 0x00, 0x00), (0xcd, 0x00, 0x00), (0x00, 0xcd, 0x00), (0xcd, 0xcd, 0x00),
+    (0x00, 0x00, 0xee), (0xcd, 0x00, 0xcd), (0x00, 0xcd, 0xcd), (0xe5, 0xe5, 0xe5),
+    (0x7f, 0x7f, 0x7f), (0xff, 0x00, 0x00), (0x00, 0xff, 0x00), (0xff, 0xff, 0x00),
+    (0x5c, 0x5c, 0xff), (0xff, 0x00, 0xff), (0x00, 0xff, 0xff), (0xff, 0xff, 0xff),
+];
+
+impl XtermPalette {
+    fn color(&self, color: Color, default: (u8, u8, u8)) -> (u8, u8, u8) {
+        const LEVELS: [u8; 6] = [0x00, 0x5f, 0x87, 0xaf, 0xd7, 0xff];
+        match color {
+            Color::Default              => default,
+            Color::Palette(n) if n < 16 => BASE_COLORS[n as usize],
+            Color::Palette(n) if n < 232 => {
+                let n = (n - 16) as usize;
+                (LEVELS[n / 36], LEVELS[n / 6 % 6], LEVELS[n % 6])
+            }
+            Color::Palette(n)           => {
+                let level = 8 + 10 * (n - 232);
+                (level, level, level)
+            }
+            Color::True(r, g, b)        => (r, g, b),
+        }
+    }
+}
+
+impl Palette for XtermPalette {
+    fn styles(&self, _: ConfigStyle) -> Styles {
+        Styles::default()
+    }
+
+    fn fg_color(&self, color: Color) -> (u8, u8, u8) {
+        self.color(color, (0xff, 0xff, 0xff))
+    }
+
+    fn bg_color(&self, color: Color) -> (u8, u8, u8) {
+        self.color(color, (0x00, 0x00, 0x00))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io;
+
+    use notty::Output;
+    use notty::asciicast::quote;
+    use notty::cfg::TerminalConfig;
+    use notty::datatypes::DEFAULT_CONFIG_STYLE;
+    use notty::datatypes::args::Argument;
+    use notty::terminal::Terminal;
+
+    use super::{dump, Format};
+
+    const CAPTURE: &'static [u8] = b"ab\x1b[1mc\x1b[0m\r\nd";
+
+    fn replay(format: Format) -> String {
+        let mut terminal = Terminal::new(4, 2, io::sink(), TerminalConfig::default());
+        for cmd in Output::new(CAPTURE) {
+            terminal.apply(cmd.unwrap()).unwrap();
+        }
+        let mut out = vec![];
+        dump(&terminal, format, None, &mut out).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn golden_text() {
+        assert_eq!(replay(Format::Text), "abc\nd\n");
+    }
+
+    #[test]
+    fn golden_json() {
+        let config = format!("{{\"config\":{}}}", quote(&DEFAULT_CONFIG_STYLE.encode()));
+        let bold = "{\"fg\":\"default\",\"bg\":\"default\",\"opacity\":255,\"bold\":true,\
+                    \"italic\":false,\"underline\":false,\"double_underline\":false,\
+                    \"strikethrough\":false,\"inverted\":false,\"blink\":false}";
+        assert_eq!(replay(Format::Json), format!(
+            "{{\"width\":4,\"height\":2,\"cursor\":{{\"x\":1,\"y\":1}},\"rows\":[\
+             [{{\"column\":0,\"width\":2,\"text\":\"ab\",\"styles\":{config}}},\
+             {{\"column\":2,\"width\":1,\"text\":\"c\",\"styles\":{bold}}},\
+             {{\"column\":3,\"width\":1,\"text\":\" \",\"styles\":{config}}}],\
+             [{{\"column\":0,\"width\":4,\"text\":\"d   \",\"styles\":{config}}}]]}}\n",
+            config = config, bold = bold));
+    }
+}
@@ -0,0 +1,157 @@
+//  notty is a new kind of terminal emulator.
+//  Copyright (C) 2015 without boats
+//  
+//  This program is free software: you can redistribute it and/or modify
+//  it under the terms of the GNU Affero General Public License as published by
+//  the Free Software Foundation, either version 3 of the License, or
+//  (at your option) any later version.
+//  
+//  This program is distributed in the hope that it will be useful,
+//  but WITHOUT ANY WARRANTY; without even the implied warranty of
+//  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+//  GNU Affero General Public License for more details.
+//  
+//  You should have received a copy of the GNU Affero General Public License
+//  along with this program.  If not, see <http://www.gnu.org/licenses/>.
+//! Replay a raw capture of the output of a pty into a terminal which is not connected to any
+//! process, and print the screen it produces. This is useful for regression tests and for
+//! debugging reports of misrendering without a graphical session.
+extern crate notty;
+
+mod dump;
+
+use std::env;
+use std::fs::File;
+use std::io::{self, BufReader, Read, Write};
+use std::process;
+
+use notty::Output;
+use notty::cfg::{TerminalConfig, WidthPolicy};
+use notty::terminal::Terminal;
+
+use dump::Format;
+
+const USAGE: &'static str = "\
+Usage: notty-replay [OPTIONS] [FILE]
+
+Replay a raw capture of pty output from FILE, or from stdin if FILE is absent
+or -, and print the screen it produces.
+
+Options:
+    -s, --size COLSxROWS    the size of the terminal (default 80x24)
+    -f, --format FORMAT     print the screen as text, json or html (default text)
+    -e, --every N           also print the screen after every N commands
+    -w, --width POLICY      characters of ambiguous width are narrow, wide, or as
+                            wide as in the locale (default narrow)
+    -h, --help              print this message
+";
+
+struct Options {
+    width: u32,
+    height: u32,
+    format: Format,
+    every: Option<usize>,
+    config: TerminalConfig,
+    path: Option<String>,
+}
+
+fn main() {
+    let options = match parse_args(env::args().skip(1)) {
+        Ok(options) => options,
+        Err(msg)    => {
+            let _ = write!(io::stderr(), "notty-replay: {}\n\n{}", msg, USAGE);
+            process::exit(2);
+        }
+    };
+    if let Err(err) = replay(options) {
+        let _ = writeln!(io::stderr(), "notty-replay: {}", err);
+        process::exit(1);
+    }
+}
+
+fn parse_args<I: Iterator<Item=String>>(mut args: I) -> Result<Options, String> {
+    let mut options = Options {
+        width: 80,
+        height: 24,
+        format: Format::Text,
+        every: None,
+        config: TerminalConfig::default(),
+        path: None,
+    };
+    while let Some(arg) = args.next() {
+        match &arg[..] {
+            "-h" | "--help"     => {
+                print!("{}", USAGE);
+                process::exit(0);
+            }
+            "-s" | "--size"     => {
+                let size = args.next().unwrap_or_else(String::new);
+                let mut dims = size.splitn(2, 'x').map(str::parse::<u32>);
+                match (dims.next(), dims.next()) {
+                    (Some(Ok(w)), Some(Ok(h))) if w > 0 && h > 0    => {
+                        options.width = w;
+                        options.height = h;
+                    }
+                    _   => return Err(format!("invalid size '{}'", size)),
+                }
+            }
+            "-f" | "--format"   => {
+                options.format = match args.next().as_ref().map(|s| &s[..]) {
+                    Some("text")    => Format::Text,
+                    Some("json")    => Format::Json,
+                    Some("html")    => Format::Html,
+                    _               => return Err(String::from("the format must be text, json \
+                                                                or html")),
+                }
+            }
+            "-e" | "--every"    => {
+                match args.next().and_then(|n| n.parse().ok()) {
+                    Some(n) if n > 0    => options.every = Some(n),
+                    _                   => return Err(String::from("--every needs a positive \
+                                                                    number of commands")),
+                }
+            }
+            "-w" | "--width"    => {
+                let policy = match args.next().as_ref().map(|s| &s[..]) {
+                    Some("narrow")  => WidthPolicy::Narrow,
+                    Some("wide")    => WidthPolicy::Wide,
+                    Some("locale")  => WidthPolicy::Locale,
+                    _               => return Err(String::from("the width must be narrow, wide \
+                                                                or locale")),
+                };
+                options.config = options.config.width_policy(policy);
+            }
+            "-"                 => options.path = None,
+            _ if arg.starts_with('-')   => return Err(format!("unknown option '{}'", arg)),
+            _                   => options.path = Some(arg.clone()),
+        }
+    }
+    Ok(options)
+}
+
+fn replay(options: Options) -> io::Result<()> {
+    let input: Box<Read> = match options.path {
+        Some(ref path)  => Box::new(try!(File::open(path))),
+        None            => Box::new(io::stdin()),
+    };
+    let mut terminal = Terminal::new(options.width, options.height, io::sink(), options.config);
+    let stdout = io::stdout();
+    let mut out = stdout.lock();
+    let mut count = 0;
+    for cmd in Output::with_config(BufReader::new(input), &options.config) {
+        try!(terminal.apply(try!(cmd)));
+        count += 1;
+        if options.every.map_or(false, |n| count % n == 0) {
+            try!(dump::dump(&terminal, options.format, Some(count), &mut out));
+        }
+    }
+    match options.every {
+        Some(n) if count > 0 && count % n == 0  => Ok(()),
+        Some(_)                                 => {
+            dump::dump(&terminal, options.format, Some(count), &mut out)
+        }
+        None                                    => {
+            dump::dump(&terminal, options.format, None, &mut out)
+        }
+    }
+}
@@ -2,27 +2,46 @@ use std::borrow::Cow;
 use std::env;
 use std::path::PathBuf;
 
+use notty::cfg::TerminalConfig;
 use notty_cairo::Config as CairoConfig;
 
 mod toml;
 
 const CONFIG_FILE: &'static str = "scaffolding.toml";
+const SCROLLBACK_FILE: &'static str = "scrollback";
 
 pub struct Config {
     pub cairo: CairoConfig,
     pub shell: Cow<'static, str>,
+    pub persist_scrollback: bool,
+    pub terminal: TerminalConfig,
 }
 
 impl Config {
     pub fn new() -> Config {
         let mut config = Config::default();
         let user_config_path = match env::var("XDG_CONFIG_HOME") {
-            Ok(dir) => PathBuf::from(dir).join(CONFIG_FILE),
-            Err(_)  => env::home_dir().unwrap().join(".config").join(CONFIG_FILE),
+            Ok(dir) => Some(PathBuf::from(dir).join(CONFIG_FILE)),
+            Err(_)  => env::home_dir().map(|home| home.join(".config").join(CONFIG_FILE)),
         };
-        let _ = toml::update_from_file(&mut config, user_config_path);
+        if let Some(path) = user_config_path {
+            let _ = toml::update_from_file(&mut config, path);
+        }
         config
     }
+
+    /// The file which the scrollback is saved to when `persist_scrollback` is set, or `None` if
+    /// there is no data directory to save it in.
+    pub fn scrollback_path(&self) -> Option<PathBuf> {
+        let data_dir = match env::var("XDG_DATA_HOME") {
+            Ok(dir) => PathBuf::from(dir),
+            Err(_)  => match env::home_dir() {
+                Some(home)  => home.join(".local").join("share"),
+                None        => return None,
+            },
+        };
+        Some(data_dir.join("notty").join(SCROLLBACK_FILE))
+    }
 }
 
 impl Default for Config {
@@ -30,6 +49,8 @@ impl Default for Config {
         Config {
             cairo: CairoConfig::default(),
             shell: Cow::Borrowed("sh"),
+            persist_scrollback: false,
+            terminal: TerminalConfig::default(),
         }
     }
 }
@@ -17,11 +17,10 @@ use std::borrow::Cow;
 use std::fs::File;
 use std::io::prelude::*;
 use std::path::Path;
-use std::sync::atomic::Ordering::Relaxed;
 use std::{error, fmt, io, result};
 
-use notty::cfg::{SCROLLBACK, TAB_STOP};
-use notty::datatypes::{CodeGroup, Color, ConfigStyle};
+use notty::cfg::{Encoding, TerminalConfig, WidthPolicy};
+use notty::datatypes::{CodeGroup, Color, ConfigStyle, ScrollbackPolicy};
 use notty::terminal::Styles;
 use notty_cairo::{Config as CairoConfig, TrueColor, PALETTE};
 
@@ -69,7 +68,8 @@ impl From<io::Error> for ConfigError {
 
 pub type Result<T> = result::Result<T, ConfigError>;
 
-fn update_general(shell: &mut Cow<'static, str>, font: &mut Cow<'static, str>, table: &Table) {
+fn update_general(shell: &mut Cow<'static, str>, font: &mut Cow<'static, str>,
+                  persist_scrollback: &mut bool, terminal: &mut TerminalConfig, table: &Table) {
     for (k, v) in table.iter() {
         match &k[..] {
             "shell" => {
@@ -78,8 +78,23 @@ fn update_general(shell: &mut Cow<'static, str>, font: &mut Cow<'static, str>, t
             "font" => {
                 *font = Cow::Owned(String::from(v.as_str().unwrap()))
             }
-            "tabstop" => TAB_STOP.store(v.as_integer().unwrap() as usize, Relaxed),
-            "scrollback" => SCROLLBACK.store(v.as_integer().unwrap() as isize, Relaxed),
+            "tabstop" => *terminal = terminal.tab_stop(v.as_integer().unwrap() as u32),
+            "scrollback" => *terminal = terminal.scrollback(match v.as_integer().unwrap() {
+                n if n > 0  => ScrollbackPolicy::MaxRows(n as usize),
+                _           => ScrollbackPolicy::Unlimited,
+            }),
+            "persist_scrollback" => *persist_scrollback = v.as_bool().unwrap(),
+            "ambiguous_width" => *terminal = terminal.width_policy(match v.as_str().unwrap() {
+                "wide"      => WidthPolicy::Wide,
+                "locale"    => WidthPolicy::Locale,
+                _           => WidthPolicy::Narrow,
+            }),
+            "encoding" => *terminal = terminal.encoding(match v.as_str().unwrap() {
+                "latin1"        => Encoding::Latin1,
+                "windows1252"   => Encoding::Windows1252,
+                "cp437"         => Encoding::Cp437,
+                _               => Encoding::Utf8,
+            }),
             _ => {},
         };
     }
@@ -137,7 +152,9 @@ pub fn update_from_file<P: AsRef<Path>>(cfg: &mut Config, path: P) -> Result<()>
     for (k, v) in table.iter() {
         match &k[..] {
             "color"     => update_colors(&mut cfg.cairo, v.as_table().unwrap()),
-            "general"   => update_general(&mut cfg.shell, &mut cfg.cairo.font, v.as_table().unwrap()),
+            "general"   => update_general(&mut cfg.shell, &mut cfg.cairo.font,
+                                          &mut cfg.persist_scrollback, &mut cfg.terminal,
+                                          v.as_table().unwrap()),
             "syntax"    => update_syntax(&mut cfg.cairo, v.as_table().unwrap()),
             _ => {},
         };
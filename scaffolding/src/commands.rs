@@ -37,7 +37,7 @@ impl CommandApplicator {
         loop {
             match self.rx.try_recv() {
                 Ok(cmd)             => {
-                    match terminal.apply(&cmd) {
+                    match terminal.apply(cmd) {
                         Err(e) => return Err(CommandError::Io(e)),
                         _ => {},
                     }
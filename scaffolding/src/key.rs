@@ -23,6 +23,8 @@ pub enum KeyEvent {
     Scroll(Direction),
     Copy,
     Paste,
+    DumpUnsupported,
+    ToggleTrace,
     Ignore,
 }
 
@@ -38,6 +40,10 @@ impl KeyEvent {
             (0x43, true) | (0x63, true) => KeyEvent::Copy,
             // Shift+Ctrl+V
             (0x56, true) | (0x76, true) => KeyEvent::Paste,
+            // Shift+Ctrl+U
+            (0x55, true) | (0x75, true) => KeyEvent::DumpUnsupported,
+            // Shift+Ctrl+T
+            (0x54, true) | (0x74, true) => KeyEvent::ToggleTrace,
             // Shift+Ctrl+Up Arrow
             (0xff52, true)              => KeyEvent::Scroll(Direction::Up),
             // Shift+Ctrl+Down Arrow
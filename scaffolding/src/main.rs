@@ -26,7 +26,11 @@ extern crate notty_cairo;
 
 use std::cell::RefCell;
 use std::env;
-use std::io::BufReader;
+use std::fs::{File, OpenOptions};
+use std::io::{self, BufReader};
+use std::os::unix::fs::OpenOptionsExt;
+use std::path::PathBuf;
+use std::process;
 use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::mpsc;
@@ -53,6 +57,11 @@ static mut Y_PIXELS: Option<u32> = None;
 static COLS: u32 = 80;
 static ROWS: u32 = 25;
 
+static TRACE_FILE: &'static str = "notty-trace";
+static TRACE_CAPACITY: usize = 10_000;
+
+static SCROLLBACK_SYNC_MS: u32 = 5_000;
+
 fn main() {
 
     // Read in configurations
@@ -75,8 +84,9 @@ fn main() {
 
     let pty_open = Arc::new(AtomicBool::new(true));
     let pty_open_checker = pty_open.clone();
+    let terminal_config = config.terminal;
     thread::spawn(move || {
-        let output = Output::new(BufReader::new(tty_r));
+        let output = Output::with_config(BufReader::new(tty_r), &terminal_config);
         for result in output {
             match result {
                 Ok(cmd) => {
@@ -102,10 +112,38 @@ fn main() {
         }
     });
 
+    // Persistent scrollback is disabled if there is no directory to save it in.
+    let scrollback_path = if config.persist_scrollback { config.scrollback_path() } else { None };
+    if config.persist_scrollback && scrollback_path.is_none() {
+        println!("Could not restore scrollback: no data directory");
+    }
+
     // Set up logical terminal and renderer.
-    let terminal = Rc::new(RefCell::new(Terminal::new(COLS, ROWS, tty_w)));
+    let terminal = Rc::new(RefCell::new(Terminal::new(COLS, ROWS, tty_w, config.terminal)));
     let renderer = RefCell::new(Renderer::new(config.cairo));
 
+    // Restore the saved scrollback before any output from the shell is applied.
+    let scrollback_terminal = terminal.clone();
+    let debug_terminal = terminal.clone();
+    if let Some(path) = scrollback_path {
+        if let Err(err) = terminal.borrow_mut().restore_scrollback(path) {
+            println!("Could not restore scrollback: {}", err);
+        }
+
+        // Save the whole scrollback every few seconds, so that little of it is lost if notty
+        // does not exit cleanly.
+        let sync_terminal = terminal.clone();
+        glib::timeout_add(SCROLLBACK_SYNC_MS, move || {
+            match sync_terminal.borrow_mut().sync_scrollback() {
+                Ok(())      => glib::Continue(true),
+                Err(err)    => {
+                    println!("Could not save scrollback: {}", err);
+                    glib::Continue(false)
+                }
+            }
+        });
+    }
+
     // Process screen logic every 25 milliseconds.
     let cmd = CommandApplicator::new(rx, terminal.clone(), canvas.clone());
     glib::timeout_add(25, move || {
@@ -124,7 +162,8 @@ fn main() {
         if let (Some(x_pix), Some(y_pix)) = unsafe {(X_PIXELS.take(), Y_PIXELS.take())} {
             renderer.borrow_mut().reset_dimensions(&canvas, &mut terminal, x_pix, y_pix);
         }
-        renderer.borrow_mut().draw(&terminal, &canvas);
+        let damage = terminal.take_damage();
+        renderer.borrow_mut().draw(&terminal, &damage, &canvas);
         gtk::Inhibit(false)
     });
 
@@ -151,6 +190,35 @@ fn main() {
                     tx_key_press.send(Command::paste(text)).unwrap();
                 }
             }
+            KeyEvent::DumpUnsupported   => {
+                let terminal = debug_terminal.borrow();
+                let unsupported = terminal.unsupported();
+                println!("Unsupported sequences:");
+                for seq in unsupported.sequences() {
+                    println!("{:>8}  {}  (first seen in \"{}\")", seq.count, seq.sequence,
+                             seq.title);
+                }
+                if unsupported.untracked() > 0 {
+                    println!("{:>8}  other sequences", unsupported.untracked());
+                }
+            }
+            KeyEvent::ToggleTrace       => {
+                let mut terminal = debug_terminal.borrow_mut();
+                match terminal.stop_trace() {
+                    Some(trace) => {
+                        match create_trace_file().and_then(|(path, mut file)| {
+                            trace.dump(&mut file).map(|_| path)
+                        }) {
+                            Ok(path)    => println!("Saved trace to {}", path.display()),
+                            Err(err)    => println!("Could not save trace: {}", err),
+                        }
+                    }
+                    None        => {
+                        terminal.start_trace(TRACE_CAPACITY);
+                        println!("Tracing commands");
+                    }
+                }
+            }
             KeyEvent::Ignore        => window.queue_draw(),
         }
         gtk::Inhibit(false)
@@ -174,4 +242,25 @@ fn main() {
     window.set_default_size(800, 800);
     window.show_all();
     gtk::main();
+
+    if let Err(err) = scrollback_terminal.borrow_mut().sync_scrollback() {
+        println!("Could not save scrollback: {}", err);
+    }
+}
+
+/// Create a new file in the temporary directory to save a trace to. The file is never one which
+/// already existed, and only the user can read it.
+fn create_trace_file() -> io::Result<(PathBuf, File)> {
+    let mut n = 0;
+    loop {
+        let name = format!("{}-{}-{}.log", TRACE_FILE, process::id(), n);
+        let path = env::temp_dir().join(name);
+        match OpenOptions::new().write(true).create_new(true).mode(0o600).open(&path) {
+            Ok(file)                                                    => {
+                return Ok((path, file))
+            }
+            Err(ref err) if err.kind() == io::ErrorKind::AlreadyExists && n < 100   => n += 1,
+            Err(err)                                                    => return Err(err),
+        }
+    }
 }
@@ -0,0 +1,209 @@
+#!/usr/bin/env python3
+#  notty is a new kind of terminal emulator.
+#  Copyright (C) 2015 without boats
+#  
+#  This program is free software: you can redistribute it and/or modify
+#  it under the terms of the GNU Affero General Public License as published by
+#  the Free Software Foundation, either version 3 of the License, or
+#  (at your option) any later version.
+#  
+#  This program is distributed in the hope that it will be useful,
+#  but WITHOUT ANY WARRANTY; without even the implied warranty of
+#  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+#  GNU Affero General Public License for more details.
+#  
+#  You should have received a copy of the GNU Affero General Public License
+#  along with this program.  If not, see <http://www.gnu.org/licenses/>.
+
+# Generates src/grapheme_tables.rs from the Unicode Character Database.
+#
+#     scripts/grapheme_tables.py 14.0.0 > src/grapheme_tables.rs
+#
+# The files of the UCD which are needed are downloaded into the working directory, unless they
+# are there already. When the tables are regenerated, the version of the unicode-width crate in
+# Cargo.toml should be bumped to one which implements the same version of Unicode.
+
+import os
+import re
+import sys
+import textwrap
+import urllib.request
+
+UCD_URL = "https://www.unicode.org/Public/%s/ucd/"
+FILES = {
+    "GraphemeBreakProperty.txt": "auxiliary/GraphemeBreakProperty.txt",
+    "emoji-data.txt": "emoji/emoji-data.txt",
+    "EastAsianWidth.txt": "EastAsianWidth.txt",
+}
+
+LICENSE = """\
+//  notty is a new kind of terminal emulator.
+//  Copyright (C) 2015 without boats
+//  
+//  This program is free software: you can redistribute it and/or modify
+//  it under the terms of the GNU Affero General Public License as published by
+//  the Free Software Foundation, either version 3 of the License, or
+//  (at your option) any later version.
+//  
+//  This program is distributed in the hope that it will be useful,
+//  but WITHOUT ANY WARRANTY; without even the implied warranty of
+//  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+//  GNU Affero General Public License for more details.
+//  
+//  You should have received a copy of the GNU Affero General Public License
+//  along with this program.  If not, see <http://www.gnu.org/licenses/>."""
+
+HEAD = """\
+#![allow(missing_docs, non_upper_case_globals, non_snake_case)]
+
+#[cfg(feature = "no_std")]
+use core::slice::SliceExt;
+#[cfg(feature = "no_std")]
+use core::result::Result::{Ok, Err};
+
+pub use self::GraphemeCat::*;
+"""
+
+COMMENT = """\
+// The Grapheme_Cluster_Break property of Unicode %s, as defined in UAX #29. CR and LF are
+// included in GC_Control, and characters which are Extended_Pictographic are given a category
+// of their own.
+"""
+
+BODY = """\
+#[allow(non_camel_case_types)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum GraphemeCat {
+    GC_Any,
+    GC_Control,
+    GC_Extend,
+    GC_Extended_Pictographic,
+    GC_L,
+    GC_LV,
+    GC_LVT,
+    GC_Prepend,
+    GC_Regional_Indicator,
+    GC_SpacingMark,
+    GC_T,
+    GC_V,
+    GC_ZWJ,
+}
+
+fn bsearch_range_value_table(c: char, r: &'static [(char, char, GraphemeCat)]) -> GraphemeCat {
+    use std::cmp::Ordering::{Equal, Less, Greater};
+    if let Ok(idx) = r.binary_search_by(|&(lo, hi, _)| {
+        if lo <= c && c <= hi { Equal }
+        else if hi < c { Less }
+        else { Greater }
+    }) { r[idx].2 }
+    else { GC_Any }
+}
+
+pub fn grapheme_category(c: char) -> GraphemeCat {
+    bsearch_range_value_table(c, grapheme_cat_table)
+}
+
+/// Whether `c` is an emoji which is presented two cells wide by default (East_Asian_Width is
+/// Wide).
+pub fn is_wide_emoji(c: char) -> bool {
+    use std::cmp::Ordering::{Equal, Less, Greater};
+    wide_emoji_table.binary_search_by(|&(lo, hi)| {
+        if lo <= c && c <= hi { Equal }
+        else if hi < c { Less }
+        else { Greater }
+    }).is_ok()
+}
+"""
+
+
+def fetch(version):
+    for name, path in sorted(FILES.items()):
+        if not os.path.exists(name):
+            urllib.request.urlretrieve(UCD_URL % version + path, name)
+
+
+def load(name):
+    """Yield the range and value of every entry in a UCD file."""
+    with open(name, encoding="utf-8") as f:
+        for line in f:
+            line = line.split("#", 1)[0].strip()
+            if not line:
+                continue
+            codes, value = [field.strip() for field in line.split(";")[:2]]
+            lo, _, hi = codes.partition("..")
+            yield int(lo, 16), int(hi or lo, 16), value
+
+
+def ranges(codes):
+    """Merge a set of code points into sorted, inclusive ranges."""
+    merged = []
+    for code in sorted(codes):
+        if merged and merged[-1][1] + 1 == code:
+            merged[-1][1] = code
+        else:
+            merged.append([code, code])
+    return merged
+
+
+def grapheme_categories():
+    categories = {}
+    for lo, hi, value in load("GraphemeBreakProperty.txt"):
+        if value in ("CR", "LF"):
+            value = "Control"
+        for code in range(lo, hi + 1):
+            categories[code] = value
+    for lo, hi, value in load("emoji-data.txt"):
+        if value == "Extended_Pictographic":
+            for code in range(lo, hi + 1):
+                categories.setdefault(code, value)
+    entries = []
+    for code in sorted(categories):
+        value = categories[code]
+        if entries and entries[-1][1] + 1 == code and entries[-1][2] == value:
+            entries[-1][1] = code
+        else:
+            entries.append([code, code, value])
+    return entries
+
+
+def wide_emoji(pictographic):
+    wide = set()
+    for lo, hi, value in load("EastAsianWidth.txt"):
+        if value in ("W", "F"):
+            wide.update(range(lo, hi + 1))
+    return ranges(wide & pictographic)
+
+
+def char(code):
+    return "'\\u{%x}'" % code
+
+
+def table(name, ty, entries):
+    text = ", ".join(entries)
+    wrapped = textwrap.fill(text, width=100, initial_indent="    ", subsequent_indent="    ",
+                            break_long_words=False, break_on_hyphens=False)
+    return "const %s: &'static [%s] = &[\n%s\n];\n" % (name, ty, wrapped)
+
+
+def main():
+    if len(sys.argv) != 2 or not re.match(r"^\d+\.\d+\.\d+$", sys.argv[1]):
+        sys.exit("usage: %s <unicode version>" % sys.argv[0])
+    version = sys.argv[1]
+    fetch(version)
+    categories = grapheme_categories()
+    pictographic = set()
+    for lo, hi, value in categories:
+        if value == "Extended_Pictographic":
+            pictographic.update(range(lo, hi + 1))
+    sys.stdout.write(LICENSE + "\n" + HEAD + "\n" + COMMENT % version + "\n" + BODY + "\n")
+    sys.stdout.write(table("wide_emoji_table", "(char, char)", [
+        "(%s, %s)" % (char(lo), char(hi)) for lo, hi in wide_emoji(pictographic)
+    ]))
+    sys.stdout.write("\n")
+    sys.stdout.write(table("grapheme_cat_table", "(char, char, GraphemeCat)", [
+        "(%s, %s, GC_%s)" % (char(lo), char(hi), value) for lo, hi, value in categories
+    ]))
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,249 @@
+//  notty is a new kind of terminal emulator.
+//  Copyright (C) 2015 without boats
+//  
+//  This program is free software: you can redistribute it and/or modify
+//  it under the terms of the GNU Affero General Public License as published by
+//  the Free Software Foundation, either version 3 of the License, or
+//  (at your option) any later version.
+//  
+//  This program is distributed in the hope that it will be useful,
+//  but WITHOUT ANY WARRANTY; without even the implied warranty of
+//  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+//  GNU Affero General Public License for more details.
+//  
+//  You should have received a copy of the GNU Affero General Public License
+//  along with this program.  If not, see <http://www.gnu.org/licenses/>.
+//! Just enough JSON to read and write asciicast files.
+use std::char;
+use std::str::Chars;
+use std::iter::Peekable;
+
+/// How deeply arrays and objects may be nested. Asciicast files nest them two deep.
+const MAX_DEPTH: u32 = 64;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Json {
+    Null,
+    Bool(bool),
+    Number(f64),
+    String(String),
+    Array(Vec<Json>),
+    Object(Vec<(String, Json)>),
+}
+
+impl Json {
+    /// Parse a line of JSON, which must hold exactly one value.
+    pub fn parse(s: &str) -> Option<Json> {
+        let mut chars = s.chars().peekable();
+        let value = parse_value(&mut chars, 0);
+        skip_whitespace(&mut chars);
+        if chars.peek().is_some() { None } else { value }
+    }
+
+    pub fn get(&self, key: &str) -> Option<&Json> {
+        match *self {
+            Json::Object(ref members)   => {
+                members.iter().find(|&&(ref k, _)| k == key).map(|&(_, ref value)| value)
+            }
+            _                           => None,
+        }
+    }
+
+    pub fn as_f64(&self) -> Option<f64> {
+        match *self {
+            Json::Number(n) => Some(n),
+            _               => None,
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match *self {
+            Json::String(ref s) => Some(s),
+            _                   => None,
+        }
+    }
+}
+
+/// Quote and escape a string as a JSON string.
+pub fn quote(s: &str) -> String {
+    let mut quoted = String::with_capacity(s.len() + 2);
+    quoted.push('"');
+    for c in s.chars() {
+        match c {
+            '"'                 => quoted.push_str("\\\""),
+            '\\'                => quoted.push_str("\\\\"),
+            '\n'                => quoted.push_str("\\n"),
+            '\r'                => quoted.push_str("\\r"),
+            '\t'                => quoted.push_str("\\t"),
+            '\0'...'\x1f'       => quoted.push_str(&format!("\\u{:04x}", c as u32)),
+            _                   => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+fn skip_whitespace(chars: &mut Peekable<Chars>) {
+    while chars.peek().map_or(false, |c| c.is_whitespace()) {
+        chars.next();
+    }
+}
+
+fn parse_value(chars: &mut Peekable<Chars>, depth: u32) -> Option<Json> {
+    skip_whitespace(chars);
+    match chars.peek().cloned() {
+        Some('n')                       => parse_word(chars, "null", Json::Null),
+        Some('t')                       => parse_word(chars, "true", Json::Bool(true)),
+        Some('f')                       => parse_word(chars, "false", Json::Bool(false)),
+        Some('"')                       => parse_string(chars).map(Json::String),
+        Some('[') if depth < MAX_DEPTH  => parse_array(chars, depth + 1),
+        Some('{') if depth < MAX_DEPTH  => parse_object(chars, depth + 1),
+        Some('-') | Some('0'...'9')     => parse_number(chars),
+        _                               => None,
+    }
+}
+
+fn parse_word(chars: &mut Peekable<Chars>, word: &str, value: Json) -> Option<Json> {
+    if word.chars().all(|c| chars.next() == Some(c)) { Some(value) } else { None }
+}
+
+fn parse_number(chars: &mut Peekable<Chars>) -> Option<Json> {
+    let mut number = String::new();
+    while let Some(c) = chars.peek().cloned() {
+        match c {
+            '0'...'9' | '-' | '+' | '.' | 'e' | 'E' => number.push(c),
+            _                                       => break,
+        }
+        chars.next();
+    }
+    number.parse().ok().map(Json::Number)
+}
+
+fn parse_string(chars: &mut Peekable<Chars>) -> Option<String> {
+    if chars.next() != Some('"') { return None; }
+    let mut s = String::new();
+    loop {
+        match chars.next() {
+            Some('"')   => return Some(s),
+            Some('\\')  => match chars.next() {
+                Some('"')   => s.push('"'),
+                Some('\\')  => s.push('\\'),
+                Some('/')   => s.push('/'),
+                Some('b')   => s.push('\x08'),
+                Some('f')   => s.push('\x0c'),
+                Some('n')   => s.push('\n'),
+                Some('r')   => s.push('\r'),
+                Some('t')   => s.push('\t'),
+                Some('u')   => {
+                    let unit = match parse_hex(chars) { Some(n) => n, None => return None };
+                    let code = if 0xd800 <= unit && unit < 0xdc00 {
+                        if chars.next() != Some('\\') || chars.next() != Some('u') {
+                            return None;
+                        }
+                        match parse_hex(chars) {
+                            Some(low) if 0xdc00 <= low && low < 0xe000 => {
+                                0x10000 + ((unit - 0xd800) << 10) + (low - 0xdc00)
+                            }
+                            _                                           => return None,
+                        }
+                    } else { unit };
+                    s.push(char::from_u32(code).unwrap_or('\u{fffd}'));
+                }
+                _           => return None,
+            },
+            Some(c)     => s.push(c),
+            None        => return None,
+        }
+    }
+}
+
+fn parse_hex(chars: &mut Peekable<Chars>) -> Option<u32> {
+    let digits = chars.by_ref().take(4).collect::<String>();
+    if digits.len() == 4 { u32::from_str_radix(&digits, 16).ok() } else { None }
+}
+
+fn parse_array(chars: &mut Peekable<Chars>, depth: u32) -> Option<Json> {
+    chars.next();
+    let mut values = vec![];
+    skip_whitespace(chars);
+    if chars.peek() == Some(&']') {
+        chars.next();
+        return Some(Json::Array(values));
+    }
+    loop {
+        match parse_value(chars, depth) {
+            Some(value) => values.push(value),
+            None        => return None,
+        }
+        skip_whitespace(chars);
+        match chars.next() {
+            Some(',')   => continue,
+            Some(']')   => return Some(Json::Array(values)),
+            _           => return None,
+        }
+    }
+}
+
+fn parse_object(chars: &mut Peekable<Chars>, depth: u32) -> Option<Json> {
+    chars.next();
+    let mut members = vec![];
+    skip_whitespace(chars);
+    if chars.peek() == Some(&'}') {
+        chars.next();
+        return Some(Json::Object(members));
+    }
+    loop {
+        skip_whitespace(chars);
+        let key = match parse_string(chars) { Some(key) => key, None => return None };
+        skip_whitespace(chars);
+        if chars.next() != Some(':') { return None; }
+        match parse_value(chars, depth) {
+            Some(value) => members.push((key, value)),
+            None        => return None,
+        }
+        skip_whitespace(chars);
+        match chars.next() {
+            Some(',')   => continue,
+            Some('}')   => return Some(Json::Object(members)),
+            _           => return None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_event() {
+        let event = Json::parse(r#"[1.5, "o", "a\u001b[1m💩\"\n"]"#);
+        assert_eq!(event, Some(Json::Array(vec![
+            Json::Number(1.5),
+            Json::String(String::from("o")),
+            Json::String(String::from("a\x1b[1m\u{1f4a9}\"\n")),
+        ])));
+    }
+
+    #[test]
+    fn parse_header() {
+        let header = Json::parse(r#"{"version": 2, "width": 80, "env": {"TERM": null}}"#).unwrap();
+        assert_eq!(header.get("width").and_then(Json::as_f64), Some(80.0));
+        assert_eq!(header.get("env").and_then(|env| env.get("TERM")), Some(&Json::Null));
+        assert_eq!(Json::parse("[1, 2"), None);
+        assert_eq!(Json::parse("{} {}"), None);
+    }
+
+    #[test]
+    fn quote_round_trip() {
+        let s = "\x1b[0m\t\"\\\u{7f}\u{e9}";
+        assert_eq!(Json::parse(&quote(s)), Some(Json::String(String::from(s))));
+    }
+
+    #[test]
+    fn deeply_nested() {
+        let nested = |depth: usize| format!("{}{}", "[".repeat(depth), "]".repeat(depth));
+        assert!(Json::parse(&nested(MAX_DEPTH as usize)).is_some());
+        assert_eq!(Json::parse(&nested(MAX_DEPTH as usize + 1)), None);
+        assert_eq!(Json::parse(&"[{\"a\":".repeat(100_000)), None);
+    }
+}
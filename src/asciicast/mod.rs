@@ -0,0 +1,281 @@
+//  notty is a new kind of terminal emulator.
+//  Copyright (C) 2015 without boats
+//  
+//  This program is free software: you can redistribute it and/or modify
+//  it under the terms of the GNU Affero General Public License as published by
+//  the Free Software Foundation, either version 3 of the License, or
+//  (at your option) any later version.
+//  
+//  This program is distributed in the hope that it will be useful,
+//  but WITHOUT ANY WARRANTY; without even the implied warranty of
+//  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+//  GNU Affero General Public License for more details.
+//  
+//  You should have received a copy of the GNU Affero General Public License
+//  along with this program.  If not, see <http://www.gnu.org/licenses/>.
+//! Recording and playback of terminal sessions in the asciicast v2 format.
+//!
+//! An asciicast file is a JSON header on its first line, followed by one JSON array on each line
+//! for every event: the time in seconds since the recording began, the kind of event and its
+//! data. Output from the controlling process is recorded as `"o"` events, input written to it as
+//! `"i"` events and changes to the size of the terminal as `"r"` events.
+use std::io::{self, Read, Write};
+use std::mem;
+use std::str;
+use std::sync::{Arc, Mutex};
+use std::time::{Instant, SystemTime, UNIX_EPOCH};
+
+use terminal::Tty;
+
+mod json;
+mod player;
+
+pub use self::json::quote;
+pub use self::player::{Event, Player};
+
+/// A recording in progress. A recording is shared by the reader which records the output of the
+/// controlling process and the tty which records input and resizes, which may be on different
+/// threads. Cloning a recording creates another handle to the same recording.
+///
+/// If writing the recording fails, nothing more is recorded, and the error is reported by the
+/// next call to `flush`.
+#[derive(Clone)]
+pub struct Recording {
+    inner: Arc<Mutex<Cast>>,
+}
+
+struct Cast {
+    out: Box<Write + Send>,
+    start: Instant,
+    input: bool,
+    pending: Vec<u8>,
+    error: Option<io::Error>,
+}
+
+impl Recording {
+    /// Begin a recording of a terminal which is `width` columns by `height` rows, writing it to
+    /// `out`. Input is only recorded if `input` is true, because it may contain passwords.
+    pub fn new<W>(mut out: W, width: u32, height: u32, input: bool) -> io::Result<Recording>
+            where W: Write + Send + 'static {
+        let timestamp = SystemTime::now().duration_since(UNIX_EPOCH)
+                                         .map(|time| time.as_secs()).unwrap_or(0);
+        try!(writeln!(out, "{{\"version\": 2, \"width\": {}, \"height\": {}, \"timestamp\": {}}}",
+                      width, height, timestamp));
+        Ok(Recording {
+            inner: Arc::new(Mutex::new(Cast {
+                out: Box::new(out),
+                start: Instant::now(),
+                input: input,
+                pending: vec![],
+                error: None,
+            })),
+        })
+    }
+
+    /// Wrap the reader from which the output of the controlling process is read, so that
+    /// everything read from it is recorded.
+    pub fn reader<R: Read>(&self, reader: R) -> Recorder<R> {
+        Recorder { reader: reader, recording: self.clone() }
+    }
+
+    /// Wrap the tty which the terminal writes to, so that changes to the size of the terminal
+    /// are recorded, and so is input if this recording records input.
+    pub fn tty<T: Tty>(&self, tty: T) -> RecordedTty<T> {
+        RecordedTty { tty: tty, recording: self.clone() }
+    }
+
+    /// Record a change to the size of the terminal.
+    pub fn resize(&self, width: u32, height: u32) {
+        self.record("r", &format!("{}x{}", width, height));
+    }
+
+    /// Write any output which is waiting for the rest of a UTF-8 sequence and flush the
+    /// recording, reporting the error which stopped the recording if there was one.
+    pub fn flush(&self) -> io::Result<()> {
+        let mut cast = self.inner.lock().unwrap();
+        let pending = mem::replace(&mut cast.pending, vec![]);
+        if !pending.is_empty() {
+            let data = String::from_utf8_lossy(&pending).into_owned();
+            cast.write("o", &data);
+        }
+        if cast.error.is_none() {
+            let result = cast.out.flush();
+            cast.error = result.err();
+        }
+        match cast.error.take() {
+            Some(err)   => Err(err),
+            None        => Ok(()),
+        }
+    }
+
+    fn output(&self, data: &[u8]) {
+        let mut cast = self.inner.lock().unwrap();
+        cast.pending.extend_from_slice(data);
+        let data = take_utf8(&mut cast.pending);
+        if !data.is_empty() { cast.write("o", &data); }
+    }
+
+    fn input(&self, data: &[u8]) {
+        let mut cast = self.inner.lock().unwrap();
+        if cast.input {
+            cast.write("i", &String::from_utf8_lossy(data));
+        }
+    }
+
+    fn record(&self, code: &str, data: &str) {
+        self.inner.lock().unwrap().write(code, data);
+    }
+}
+
+impl Cast {
+    fn write(&mut self, code: &str, data: &str) {
+        if self.error.is_some() { return; }
+        let elapsed = self.start.elapsed();
+        let time = elapsed.as_secs() as f64 + elapsed.subsec_nanos() as f64 / 1e9;
+        if let Err(err) = writeln!(self.out, "[{:.6}, \"{}\", {}]", time, code, quote(data)) {
+            self.error = Some(err);
+        }
+    }
+}
+
+/// Take the longest prefix of `buf` which is valid UTF-8, leaving behind an incomplete sequence
+/// at the end of it, if there is one. Invalid bytes are replaced.
+fn take_utf8(buf: &mut Vec<u8>) -> String {
+    let mut data = String::new();
+    loop {
+        let (valid, invalid) = match str::from_utf8(buf) {
+            Ok(s)       => {
+                data.push_str(s);
+                buf.clear();
+                return data
+            }
+            Err(err)    => (err.valid_up_to(), err.error_len()),
+        };
+        data.push_str(str::from_utf8(&buf[..valid]).unwrap());
+        match invalid {
+            Some(len)   => {
+                data.push('\u{fffd}');
+                buf.drain(..valid + len);
+            }
+            None        => {
+                buf.drain(..valid);
+                return data
+            }
+        }
+    }
+}
+
+/// A reader which records everything read from it as output.
+pub struct Recorder<R> {
+    reader: R,
+    recording: Recording,
+}
+
+impl<R: Read> Read for Recorder<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let n = try!(self.reader.read(buf));
+        self.recording.output(&buf[..n]);
+        Ok(n)
+    }
+}
+
+/// A tty which records changes to the size of the terminal, and may record input.
+pub struct RecordedTty<T> {
+    tty: T,
+    recording: Recording,
+}
+
+impl<T: Tty> Write for RecordedTty<T> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let n = try!(self.tty.write(buf));
+        self.recording.input(&buf[..n]);
+        Ok(n)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.tty.flush()
+    }
+}
+
+impl<T: Tty> Tty for RecordedTty<T> {
+    fn set_winsize(&mut self, width: u16, height: u16) -> io::Result<()> {
+        try!(self.tty.set_winsize(width, height));
+        self.recording.resize(width as u32, height as u32);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64;
+    use std::io::{self, BufReader, Read, Write};
+    use std::sync::{Arc, Mutex};
+
+    use cfg::TerminalConfig;
+    use datatypes::Coords;
+    use terminal::Terminal;
+    use terminal::interfaces::Styleable;
+
+    use super::*;
+
+    #[derive(Clone, Default)]
+    struct Shared(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for Shared {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.lock().unwrap().write(buf)
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn split_utf8() {
+        let mut buf = vec![b'a', 0xf0, 0x9f];
+        assert_eq!(take_utf8(&mut buf), "a");
+        assert_eq!(buf, [0xf0, 0x9f]);
+        buf.extend_from_slice(&[0x92, 0xa9, 0xff, b'b']);
+        assert_eq!(take_utf8(&mut buf), "\u{1f4a9}\u{fffd}b");
+        assert!(buf.is_empty());
+        buf.extend_from_slice(&[0xe2, 0x82, b'c', 0xed, 0xa0, 0x80]);
+        assert_eq!(take_utf8(&mut buf), "\u{fffd}c\u{fffd}\u{fffd}\u{fffd}");
+        assert!(buf.is_empty());
+    }
+
+    #[test]
+    fn record_and_play() {
+        let file = Shared::default();
+        let recording = Recording::new(file.clone(), 8, 2, true).unwrap();
+        let output = "ab\x1b[1mc\u{e9}".as_bytes();
+        let mut recorder = recording.reader(&output[..output.len() - 1]);
+        io::copy(&mut recorder, &mut io::sink()).unwrap();
+        let mut tty = recording.tty(io::sink());
+        tty.set_winsize(10, 3).unwrap();
+        tty.write_all(b"ls\r").unwrap();
+        recording.reader(&output[output.len() - 1..]).read_to_end(&mut vec![]).unwrap();
+        recording.flush().unwrap();
+
+        let cast = file.0.lock().unwrap().clone();
+        let mut player = Player::new(BufReader::new(&cast[..])).unwrap().speed(f64::INFINITY);
+        assert_eq!(player.dims(), (8, 2));
+        let events = (0..4).map(|_| player.next_event().unwrap().unwrap().1).collect::<Vec<_>>();
+        assert_eq!(events, vec![
+            Event::Output(String::from("ab\x1b[1mc")),
+            Event::Resize(10, 3),
+            Event::Input(String::from("ls\r")),
+            Event::Output(String::from("\u{e9}")),
+        ]);
+        assert!(player.next_event().unwrap().is_none());
+
+        let mut terminal = Terminal::new(8, 2, io::sink(), TerminalConfig::default());
+        let mut player = Player::new(BufReader::new(&cast[..])).unwrap().speed(f64::INFINITY);
+        player.play(&mut terminal).unwrap();
+        assert_eq!(terminal.area().width(), 10);
+        assert_eq!(terminal.cell_at(Coords { x: 3, y: 0 }).unwrap().to_string(),
+                   "\u{e9}");
+        assert!(terminal.cell_at(Coords { x: 2, y: 0 }).unwrap().styles() !=
+                terminal.cell_at(Coords { x: 0, y: 0 }).unwrap().styles());
+    }
+}
@@ -0,0 +1,178 @@
+//  notty is a new kind of terminal emulator.
+//  Copyright (C) 2015 without boats
+//  
+//  This program is free software: you can redistribute it and/or modify
+//  it under the terms of the GNU Affero General Public License as published by
+//  the Free Software Foundation, either version 3 of the License, or
+//  (at your option) any later version.
+//  
+//  This program is distributed in the hope that it will be useful,
+//  but WITHOUT ANY WARRANTY; without even the implied warranty of
+//  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+//  GNU Affero General Public License for more details.
+//  
+//  You should have received a copy of the GNU Affero General Public License
+//  along with this program.  If not, see <http://www.gnu.org/licenses/>.
+use std::cell::RefCell;
+use std::cmp;
+use std::collections::VecDeque;
+use std::io::{self, BufRead, BufReader, Read};
+use std::rc::Rc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use Output;
+use terminal::Terminal;
+
+use super::json::Json;
+
+/// An event in a recording.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Event {
+    /// Output from the controlling process.
+    Output(String),
+    /// Input written to the controlling process.
+    Input(String),
+    /// A change to the size of the terminal, in columns and rows.
+    Resize(u32, u32),
+}
+
+/// Plays back an asciicast v2 recording, feeding the output it holds through `Output` into a
+/// `Terminal`.
+pub struct Player<R: BufRead> {
+    lines: io::Lines<R>,
+    width: u32,
+    height: u32,
+    speed: f64,
+    queue: Rc<RefCell<VecDeque<u8>>>,
+    output: Output<BufReader<Queue>>,
+}
+
+impl<R: BufRead> Player<R> {
+    /// Read the header of a recording.
+    pub fn new(reader: R) -> io::Result<Player<R>> {
+        let mut lines = reader.lines();
+        let header = match lines.next() {
+            Some(line)  => Json::parse(&try!(line)),
+            None        => None,
+        };
+        let dims = header.as_ref().and_then(|header| {
+            match (header.get("version").and_then(Json::as_f64),
+                   header.get("width").and_then(Json::as_f64),
+                   header.get("height").and_then(Json::as_f64)) {
+                (Some(version), Some(w), Some(h)) if version == 2.0 => {
+                    Some((w as u32, h as u32))
+                }
+                _                                                   => None,
+            }
+        });
+        let (width, height) = match dims {
+            Some(dims)  => dims,
+            None        => return Err(invalid("not an asciicast v2 recording")),
+        };
+        let queue = Rc::new(RefCell::new(VecDeque::new()));
+        Ok(Player {
+            lines: lines,
+            width: width,
+            height: height,
+            speed: 1.0,
+            output: Output::new(BufReader::new(Queue(queue.clone()))),
+            queue: queue,
+        })
+    }
+
+    /// Play the recording `speed` times faster than it was recorded. A speed of infinity plays
+    /// the recording without waiting between events.
+    pub fn speed(self, speed: f64) -> Player<R> {
+        Player { speed: speed, ..self }
+    }
+
+    /// The size of the terminal when the recording began, in columns and rows.
+    pub fn dims(&self) -> (u32, u32) {
+        (self.width, self.height)
+    }
+
+    /// The next event in the recording and the time it happened, in seconds since the
+    /// recording began. Events of kinds other than output, input and resizes are skipped.
+    pub fn next_event(&mut self) -> io::Result<Option<(f64, Event)>> {
+        while let Some(line) = self.lines.next() {
+            let line = try!(line);
+            if line.trim().is_empty() { continue }
+            let event = match Json::parse(&line) {
+                Some(Json::Array(event))    => event,
+                _                           => return Err(invalid("malformed asciicast event")),
+            };
+            let (time, code, data) = match (event.get(0).and_then(Json::as_f64),
+                                            event.get(1).and_then(Json::as_str),
+                                            event.get(2).and_then(Json::as_str)) {
+                (Some(time), Some(code), Some(data))    => (time, code, data),
+                _   => return Err(invalid("malformed asciicast event")),
+            };
+            let event = match code {
+                "o" => Event::Output(String::from(data)),
+                "i" => Event::Input(String::from(data)),
+                "r" => {
+                    let mut dims = data.splitn(2, 'x').map(str::parse::<u32>);
+                    match (dims.next(), dims.next()) {
+                        (Some(Ok(w)), Some(Ok(h)))  => Event::Resize(w, h),
+                        _   => return Err(invalid("malformed asciicast resize event")),
+                    }
+                }
+                _   => continue,
+            };
+            return Ok(Some((time, event)))
+        }
+        Ok(None)
+    }
+
+    /// Play the rest of the recording into `terminal`, waiting between events so that they are
+    /// applied at the speed of the player. Input events are not applied, because the output
+    /// which the controlling process produced in response to them is part of the recording.
+    /// The output is parsed according to the config of `terminal`.
+    pub fn play(&mut self, terminal: &mut Terminal) -> io::Result<()> {
+        self.output.set_config(terminal.config());
+        let start = Instant::now();
+        while let Some((time, event)) = try!(self.next_event()) {
+            if self.speed.is_finite() && self.speed > 0.0 {
+                let due = duration(time / self.speed);
+                let elapsed = start.elapsed();
+                if due > elapsed { thread::sleep(due - elapsed); }
+            }
+            match event {
+                Event::Output(data)     => {
+                    self.queue.borrow_mut().extend(data.bytes());
+                    for cmd in &mut self.output {
+                        try!(terminal.apply(try!(cmd)));
+                    }
+                }
+                Event::Resize(w, h)     => try!(terminal.set_winsize(Some(w), Some(h))),
+                Event::Input(_)         => { }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The output of the recording which has not been parsed yet. It is shared between the player,
+/// which adds each output event to it, and the `Output` which parses it.
+struct Queue(Rc<RefCell<VecDeque<u8>>>);
+
+impl Read for Queue {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let mut queue = self.0.borrow_mut();
+        let n = cmp::min(buf.len(), queue.len());
+        for (dst, byte) in buf.iter_mut().zip(queue.drain(..n)) {
+            *dst = byte;
+        }
+        Ok(n)
+    }
+}
+
+fn duration(secs: f64) -> Duration {
+    let secs = secs.max(0.0);
+    Duration::new(secs as u64, (secs.fract() * 1e9) as u32)
+}
+
+fn invalid(msg: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, msg)
+}
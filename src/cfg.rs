@@ -0,0 +1,278 @@
+//  notty is a new kind of terminal emulator.
+//  Copyright (C) 2015 without boats
+//  
+//  This program is free software: you can redistribute it and/or modify
+//  it under the terms of the GNU Affero General Public License as published by
+//  the Free Software Foundation, either version 3 of the License, or
+//  (at your option) any later version.
+//  
+//  This program is distributed in the hope that it will be useful,
+//  but WITHOUT ANY WARRANTY; without even the implied warranty of
+//  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+//  GNU Affero General Public License for more details.
+//  
+//  You should have received a copy of the GNU Affero General Public License
+//  along with this program.  If not, see <http://www.gnu.org/licenses/>.
+//! Per-terminal configuration.
+//!
+//! A `TerminalConfig` is passed to `Terminal::new`; every grid the terminal creates takes its
+//! defaults from the config of the terminal it belongs to, so several terminals in the same
+//! process can be configured independently.
+use std::cmp;
+use std::env;
+
+use datatypes::{Flow, GridSettings, ScrollbackPolicy};
+use terminal::session::{Persist, SessionReader, SessionWriter};
+
+/// How to treat characters whose East Asian Width property is ambiguous.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum WidthPolicy {
+    /// Ambiguous width characters occupy one cell.
+    Narrow,
+    /// Ambiguous width characters occupy two cells, as in most CJK locales.
+    Wide,
+    /// Ambiguous width characters occupy two cells if the locale of the environment, as given
+    /// by `LC_ALL`, `LC_CTYPE` or `LANG`, is Chinese, Japanese or Korean.
+    Locale,
+}
+
+impl WidthPolicy {
+    /// Whether characters of ambiguous width occupy two cells under this policy.
+    pub fn ambiguous_is_wide(&self) -> bool {
+        match *self {
+            WidthPolicy::Narrow => false,
+            WidthPolicy::Wide   => true,
+            WidthPolicy::Locale => {
+                ["LC_ALL", "LC_CTYPE", "LANG"].iter().filter_map(|var| env::var(var).ok())
+                                              .find(|locale| !locale.is_empty())
+                                              .map_or(false, |locale| is_cjk_locale(&locale))
+            }
+        }
+    }
+}
+
+impl Default for WidthPolicy {
+    fn default() -> WidthPolicy {
+        WidthPolicy::Narrow
+    }
+}
+
+/// The encoding of the output of the controlling process.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Encoding {
+    Utf8,
+    /// ISO 8859-1, in which every byte is the code point of the same value.
+    Latin1,
+    /// Windows-1252, the superset of Latin-1 used by Windows.
+    Windows1252,
+    /// Code page 437, the character set of the IBM PC and of many serial consoles.
+    Cp437,
+}
+
+impl Default for Encoding {
+    fn default() -> Encoding {
+        Encoding::Utf8
+    }
+}
+
+/// Which queries from the controlling process the terminal will answer. A query which is not
+/// answered is silently ignored.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct ResponsePolicy {
+    /// Answer device status reports (`CSI 5 n`).
+    pub status: bool,
+    /// Answer cursor position reports (`CSI 6 n`).
+    pub position: bool,
+}
+
+impl Default for ResponsePolicy {
+    fn default() -> ResponsePolicy {
+        ResponsePolicy { status: true, position: true }
+    }
+}
+
+/// The configuration of a single terminal.
+///
+/// This is constructed with the builder methods, starting from the default:
+///
+/// ```ignore
+/// let config = TerminalConfig::default().tab_stop(8).scrollback(ScrollbackPolicy::MaxRows(1000));
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct TerminalConfig {
+    /// The distance between each tab stop.
+    pub tab_stop: u32,
+    /// The scrollback policy of grids which save their offscreen state.
+    pub scrollback: ScrollbackPolicy,
+    /// The flow of newly created grids. Reflowable grids are not implemented yet, so this is
+    /// always `Flow::Moveable`.
+    pub(crate) flow: Flow,
+    /// The policy for characters of ambiguous width.
+    pub width: WidthPolicy,
+    /// The encoding of the output. If this is a single byte encoding, it is also the encoding
+    /// which the controlling process switches to with `ESC % @`; otherwise that is Latin-1.
+    pub encoding: Encoding,
+    /// Which queries from the controlling process are answered.
+    pub responses: ResponsePolicy,
+}
+
+impl TerminalConfig {
+    /// Set the distance between each tab stop. A tab stop of zero is treated as one.
+    pub fn tab_stop(mut self, tab_stop: u32) -> TerminalConfig {
+        self.tab_stop = cmp::max(tab_stop, 1);
+        self
+    }
+
+    /// Set the scrollback policy of grids which save their offscreen state.
+    pub fn scrollback(mut self, scrollback: ScrollbackPolicy) -> TerminalConfig {
+        self.scrollback = scrollback;
+        self
+    }
+
+    /// Set the policy for characters of ambiguous width.
+    pub fn width_policy(mut self, width: WidthPolicy) -> TerminalConfig {
+        self.width = width;
+        self
+    }
+
+    /// Set the encoding of the output.
+    pub fn encoding(mut self, encoding: Encoding) -> TerminalConfig {
+        self.encoding = encoding;
+        self
+    }
+
+    /// Set which queries from the controlling process are answered.
+    pub fn responses(mut self, responses: ResponsePolicy) -> TerminalConfig {
+        self.responses = responses;
+        self
+    }
+
+    /// The settings for a new grid of these dimensions in a terminal with this config.
+    pub(crate) fn grid_settings(&self, width: u32, height: u32, retain_offscreen_state: bool)
+            -> GridSettings {
+        GridSettings {
+            width: width,
+            height: height,
+            retain_offscreen_state: retain_offscreen_state,
+            scrollback: self.scrollback,
+            flow: self.flow,
+            tab_stop: self.tab_stop,
+        }
+    }
+}
+
+impl Default for TerminalConfig {
+    fn default() -> TerminalConfig {
+        TerminalConfig {
+            tab_stop: 4,
+            scrollback: ScrollbackPolicy::Unlimited,
+            flow: Flow::Moveable,
+            width: WidthPolicy::Narrow,
+            encoding: Encoding::Utf8,
+            responses: ResponsePolicy::default(),
+        }
+    }
+}
+
+impl Persist for TerminalConfig {
+    fn persist(&self, session: &mut SessionWriter) {
+        session.write_uint(self.tab_stop as u64);
+        self.scrollback.persist(session);
+        session.write_u8(match self.flow { Flow::Moveable => 0, Flow::Reflowable => 1 });
+        session.write_u8(match self.width {
+            WidthPolicy::Narrow => 0,
+            WidthPolicy::Wide   => 1,
+            WidthPolicy::Locale => 2,
+        });
+        session.write_u8(match self.encoding {
+            Encoding::Utf8          => 0,
+            Encoding::Latin1        => 1,
+            Encoding::Windows1252   => 2,
+            Encoding::Cp437         => 3,
+        });
+        session.write_bool(self.responses.status);
+        session.write_bool(self.responses.position);
+    }
+
+    fn restore(session: &mut SessionReader) -> Option<TerminalConfig> {
+        let tab_stop = match session.read_uint() { Some(n) => n as u32, None => return None };
+        let scrollback = match ScrollbackPolicy::restore(session) {
+            Some(scrollback)    => scrollback,
+            None                => return None,
+        };
+        match session.read_u8() {
+            Some(0) => { }
+            _       => return None,
+        }
+        let width = match session.read_u8() {
+            Some(0) => WidthPolicy::Narrow,
+            Some(1) => WidthPolicy::Wide,
+            Some(2) => WidthPolicy::Locale,
+            _       => return None,
+        };
+        let encoding = match session.read_u8() {
+            Some(0) => Encoding::Utf8,
+            Some(1) => Encoding::Latin1,
+            Some(2) => Encoding::Windows1252,
+            Some(3) => Encoding::Cp437,
+            _       => return None,
+        };
+        match (session.read_bool(), session.read_bool()) {
+            (Some(status), Some(position))  => Some(TerminalConfig::default()
+                .tab_stop(tab_stop)
+                .scrollback(scrollback)
+                .width_policy(width)
+                .encoding(encoding)
+                .responses(ResponsePolicy { status: status, position: position })),
+            _                               => None,
+        }
+    }
+}
+
+fn is_cjk_locale(locale: &str) -> bool {
+    match locale.split(|c| c == '_' || c == '.' || c == '@').next() {
+        Some("ja") | Some("ko") | Some("zh")    => true,
+        _                                       => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use datatypes::{Flow, GridSettings, ScrollbackPolicy};
+    use terminal::session;
+    use super::*;
+
+    #[test]
+    fn builder_sets_fields() {
+        let config = TerminalConfig::default().tab_stop(8)
+                                              .scrollback(ScrollbackPolicy::MaxRows(10))
+                                              .width_policy(WidthPolicy::Wide);
+        assert_eq!(config.tab_stop, 8);
+        assert_eq!(config.scrollback, ScrollbackPolicy::MaxRows(10));
+        assert_eq!(config.flow, Flow::Moveable);
+        assert_eq!(config.width, WidthPolicy::Wide);
+    }
+
+    #[test]
+    fn cjk_locales() {
+        assert!(super::is_cjk_locale("ja_JP.UTF-8"));
+        assert!(super::is_cjk_locale("zh_TW"));
+        assert!(super::is_cjk_locale("ko"));
+        assert!(!super::is_cjk_locale("en_US.UTF-8"));
+        assert!(!super::is_cjk_locale("C"));
+        assert!(!super::is_cjk_locale("jam_JM"));
+    }
+
+    #[test]
+    fn zero_tab_stop() {
+        assert_eq!(TerminalConfig::default().tab_stop(0).tab_stop, 1);
+    }
+
+    #[test]
+    fn reflowable_session_rejected() {
+        let config = TerminalConfig { flow: Flow::Reflowable, ..TerminalConfig::default() };
+        let mut data = vec![];
+        session::save(&mut data, |session| config.persist(session)).unwrap();
+        assert!(session::restore(&mut &data[..], TerminalConfig::restore).is_err());
+    }
+}
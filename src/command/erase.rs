@@ -18,36 +18,33 @@ use notty_encoding::cmds::{Erase, RemoveChars, RemoveRows, InsertBlank, InsertRo
 use command::prelude::*;
 
 impl Command for Erase {
-    fn apply(&self, terminal: &mut Terminal) -> io::Result<()> {
+    fn apply(self, terminal: &mut Terminal) -> io::Result<()> {
         terminal.erase(self.area);
         Ok(())
     }
 
-    #[cfg(any(test, debug_assertions))]
     fn repr(&self) -> String {
         String::from("ERASE")
     }
 }
 
 impl Command for RemoveChars {
-    fn apply(&self, terminal: &mut Terminal) -> io::Result<()> {
+    fn apply(self, terminal: &mut Terminal) -> io::Result<()> {
         terminal.remove_at(self.count);
         Ok(())
     }
 
-    #[cfg(any(test, debug_assertions))]
     fn repr(&self) -> String {
         format!("REMOVE {} CHARS", self.count)
     }
 }
 
 impl Command for RemoveRows {
-    fn apply(&self, terminal: &mut Terminal) -> io::Result<()> {
+    fn apply(self, terminal: &mut Terminal) -> io::Result<()> {
         terminal.remove_rows_at(self.count, self.include);
         Ok(())
     }
 
-    #[cfg(any(test, debug_assertions))]
     fn repr(&self) -> String {
         match self.include {
             true    => format!("REMOVE {} ROWS INCL CURSOR", self.count),
@@ -57,24 +54,22 @@ impl Command for RemoveRows {
 }
 
 impl Command for InsertBlank {
-    fn apply(&self, terminal: &mut Terminal) -> io::Result<()> {
+    fn apply(self, terminal: &mut Terminal) -> io::Result<()> {
         terminal.insert_blank_at(self.count);
         Ok(())
     }
 
-    #[cfg(any(test, debug_assertions))]
     fn repr(&self) -> String {
         format!("INSERT {} BLANK SPACES", self.count)
     }
 }
 
 impl Command for InsertRows {
-    fn apply(&self, terminal: &mut Terminal) -> io::Result<()> {
+    fn apply(self, terminal: &mut Terminal) -> io::Result<()> {
         terminal.insert_rows_at(self.count, self.include);
         Ok(())
     }
 
-    #[cfg(any(test, debug_assertions))]
     fn repr(&self) -> String {
         match self.include {
             true    => format!("INSERT {} ROWS ABOVE CURSOR", self.count),
@@ -82,3 +77,16 @@ impl Command for InsertRows {
         }
     }
 }
+
+pub struct ClearScrollback;
+
+impl Command for ClearScrollback {
+    fn apply(self, terminal: &mut Terminal) -> io::Result<()> {
+        terminal.clear_scrollback();
+        Ok(())
+    }
+
+    fn repr(&self) -> String {
+        String::from("CLEAR SCROLLBACK")
+    }
+}
@@ -19,11 +19,10 @@ use datatypes::Key;
 pub struct KeyPress(pub Key);
 
 impl Command for KeyPress {
-    fn apply(&self, terminal: &mut Terminal) -> io::Result<()> {
-        terminal.send_input(self.0.clone(), true)
+    fn apply(self, terminal: &mut Terminal) -> io::Result<()> {
+        terminal.send_input(self.0, true)
     }
 
-    #[cfg(any(test, debug_assertions))]
     fn repr(&self) -> String {
         String::from("KEY PRESS")
     }
@@ -32,11 +31,10 @@ impl Command for KeyPress {
 pub struct KeyRelease(pub Key);
 
 impl Command for KeyRelease {
-    fn apply(&self, terminal: &mut Terminal) -> io::Result<()> {
-        terminal.send_input(self.0.clone(), false)
+    fn apply(self, terminal: &mut Terminal) -> io::Result<()> {
+        terminal.send_input(self.0, false)
     }
 
-    #[cfg(any(test, debug_assertions))]
     fn repr(&self) -> String {
         String::from("KEY RELEASE")
     }
@@ -45,11 +43,10 @@ impl Command for KeyRelease {
 pub struct Paste(pub String);
 
 impl Command for Paste {
-    fn apply(&self, terminal: &mut Terminal) -> io::Result<()> {
+    fn apply(self, terminal: &mut Terminal) -> io::Result<()> {
         terminal.paste(&self.0)
     }
 
-    #[cfg(any(test, debug_assertions))]
     fn repr(&self) -> String {
         String::from("PASTE")
     }
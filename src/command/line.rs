@@ -0,0 +1,48 @@
+//  notty is a new kind of terminal emulator.
+//  Copyright (C) 2015 without boats
+//  
+//  This program is free software: you can redistribute it and/or modify
+//  it under the terms of the GNU Affero General Public License as published by
+//  the Free Software Foundation, either version 3 of the License, or
+//  (at your option) any later version.
+//  
+//  This program is distributed in the hope that it will be useful,
+//  but WITHOUT ANY WARRANTY; without even the implied warranty of
+//  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+//  GNU Affero General Public License for more details.
+//  
+//  You should have received a copy of the GNU Affero General Public License
+//  along with this program.  If not, see <http://www.gnu.org/licenses/>.
+use command::prelude::*;
+use datatypes::LineSize;
+
+pub struct SetLineSize(pub LineSize);
+
+impl Command for SetLineSize {
+    fn apply(self, terminal: &mut Terminal) -> io::Result<()> {
+        terminal.set_line_size(self.0);
+        Ok(())
+    }
+
+    fn repr(&self) -> String {
+        match self.0 {
+            LineSize::Normal                => String::from("SET LINE SIZE NORMAL"),
+            LineSize::DoubleWidth           => String::from("SET LINE SIZE DOUBLE WIDTH"),
+            LineSize::DoubleHeightTop       => String::from("SET LINE SIZE DOUBLE HEIGHT TOP"),
+            LineSize::DoubleHeightBottom    => String::from("SET LINE SIZE DOUBLE HEIGHT BOTTOM"),
+        }
+    }
+}
+
+pub struct AlignmentTest;
+
+impl Command for AlignmentTest {
+    fn apply(self, terminal: &mut Terminal) -> io::Result<()> {
+        terminal.alignment_test();
+        Ok(())
+    }
+
+    fn repr(&self) -> String {
+        String::from("ALIGNMENT TEST")
+    }
+}
@@ -13,37 +13,30 @@
 //  
 //  You should have received a copy of the GNU Affero General Public License
 //  along with this program.  If not, see <http://www.gnu.org/licenses/>.
-use std::cell::RefCell;
-
 use notty_encoding::cmds::SetInputMode;
 
 use command::prelude::*;
-#[cfg(any(test, debug_assertions))]
 use datatypes::InputSettings;
 
-pub struct SetTitle(pub RefCell<Option<String>>);
+pub struct SetTitle(pub String);
 
 impl Command for SetTitle {
-    fn apply(&self, terminal: &mut Terminal) -> io::Result<()> {
-        if let Some(title) = self.0.borrow_mut().take() {
-            terminal.set_title(title);
-        }
+    fn apply(self, terminal: &mut Terminal) -> io::Result<()> {
+        terminal.set_title(self.0);
         Ok(())
     }
 
-    #[cfg(any(test, debug_assertions))]
     fn repr(&self) -> String {
         String::from("SET TITLE")
     }
 }
 
 impl Command for SetInputMode {
-    fn apply(&self, terminal: &mut Terminal) -> io::Result<()> {
+    fn apply(self, terminal: &mut Terminal) -> io::Result<()> {
         terminal.set_input_mode(self.0);
         Ok(())
     }
 
-    #[cfg(any(test, debug_assertions))]
     fn repr(&self) -> String {
         match self.0 {
             InputSettings::Ansi(false)              => String::from("SET MODE ANSI"),
@@ -56,16 +49,43 @@ impl Command for SetInputMode {
     }
 }
 
+#[derive(Default, Copy, Clone)]
+pub struct HardReset;
+
+impl Command for HardReset {
+    fn apply(self, terminal: &mut Terminal) -> io::Result<()> {
+        terminal.reset(true);
+        Ok(())
+    }
+
+    fn repr(&self) -> String {
+        String::from("HARD RESET")
+    }
+}
+
+#[derive(Default, Copy, Clone)]
+pub struct SoftReset;
+
+impl Command for SoftReset {
+    fn apply(self, terminal: &mut Terminal) -> io::Result<()> {
+        terminal.reset(false);
+        Ok(())
+    }
+
+    fn repr(&self) -> String {
+        String::from("SOFT RESET")
+    }
+}
+
 #[derive(Default, Copy, Clone)]
 pub struct Bell;
 
 impl Command for Bell {
-    fn apply(&self, terminal: &mut Terminal) -> io::Result<()> {
+    fn apply(self, terminal: &mut Terminal) -> io::Result<()> {
         terminal.bell();
         Ok(())
     }
 
-    #[cfg(any(test, debug_assertions))]
     fn repr(&self) -> String {
         String::from("BELL")
     }
@@ -15,11 +15,12 @@
 //  along with this program.  If not, see <http://www.gnu.org/licenses/>.
 use std::io;
 
-use terminal::Terminal;
+use terminal::{Terminal, WideChar, CharExtender, Image};
 use Command;
 
 mod erase;
 mod input;
+mod line;
 mod meta;
 mod movement;
 mod panel;
@@ -39,9 +40,11 @@ pub use notty_encoding::cmds::{
     SetStyleInArea, DefaultStyleInArea,
 };
 
+pub use self::erase::ClearScrollback;
 pub use self::input::{KeyPress, KeyRelease, Paste};
-pub use self::meta::{SetTitle, Bell};
-pub use self::put::{Put, PutAt};
+pub use self::line::{SetLineSize, AlignmentTest};
+pub use self::meta::{SetTitle, Bell, HardReset, SoftReset};
+pub use self::put::{Put, PutAt, PutStr};
 pub use self::respond::{StaticResponse, ReportPosition};
 pub use self::tooltip::{AddToolTip, RemoveToolTip, AddDropDown};
 
@@ -52,22 +55,100 @@ mod prelude {
 }
 
 pub trait CommandTrait: Send + 'static {
-    fn apply(&self, &mut Terminal) -> io::Result<()>;
-    #[cfg(any(test, debug_assertions))]
+    fn apply(self, &mut Terminal) -> io::Result<()>;
     fn repr(&self) -> String;
 }
 
+macro_rules! commands {
+    ($($variant:ident($cmd:ty),)*) => {
+        /// Every kind of command. A `Command` holds one of these by value, so that parsing a
+        /// command does not allocate.
+        pub enum CommandKind {
+            $($variant($cmd),)*
+        }
+
+        impl CommandKind {
+            pub fn apply(self, terminal: &mut Terminal) -> io::Result<()> {
+                match self {
+                    $(CommandKind::$variant(cmd) => cmd.apply(terminal),)*
+                }
+            }
+
+            pub fn repr(&self) -> String {
+                match *self {
+                    $(CommandKind::$variant(ref cmd) => cmd.repr(),)*
+                }
+            }
+        }
+
+        $(impl From<$cmd> for CommandKind {
+            fn from(cmd: $cmd) -> CommandKind {
+                CommandKind::$variant(cmd)
+            }
+        })*
+    }
+}
+
+commands! {
+    Series(CommandSeries),
+    NoFeature(NoFeature),
+    Erase(Erase),
+    RemoveChars(RemoveChars),
+    RemoveRows(RemoveRows),
+    InsertBlank(InsertBlank),
+    InsertRows(InsertRows),
+    ClearScrollback(ClearScrollback),
+    SetLineSize(SetLineSize),
+    AlignmentTest(AlignmentTest),
+    KeyPress(KeyPress),
+    KeyRelease(KeyRelease),
+    Paste(Paste),
+    SetTitle(SetTitle),
+    SetInputMode(SetInputMode),
+    Bell(Bell),
+    HardReset(HardReset),
+    SoftReset(SoftReset),
+    Move(Move),
+    ScrollScreen(ScrollScreen),
+    PushPanel(PushPanel),
+    PopPanel(PopPanel),
+    SplitPanel(SplitPanel),
+    UnsplitPanel(UnsplitPanel),
+    AdjustPanelSplit(AdjustPanelSplit),
+    RotateSectionDown(RotateSectionDown),
+    RotateSectionUp(RotateSectionUp),
+    SwitchActiveSection(SwitchActiveSection),
+    PutChar(Put<char>),
+    PutWideChar(Put<WideChar>),
+    PutExtender(Put<CharExtender>),
+    PutImage(Put<Image>),
+    PutImageAt(PutAt<Image>),
+    PutStr(PutStr),
+    StaticResponse(StaticResponse),
+    ReportPosition(ReportPosition),
+    SetCursorStyle(SetCursorStyle),
+    DefaultCursorStyle(DefaultCursorStyle),
+    SetTextStyle(SetTextStyle),
+    DefaultTextStyle(DefaultTextStyle),
+    SetStyleInArea(SetStyleInArea),
+    DefaultStyleInArea(DefaultStyleInArea),
+    AddToolTip(AddToolTip),
+    RemoveToolTip(RemoveToolTip),
+    AddDropDown(AddDropDown),
+}
+
 pub struct CommandSeries(pub Vec<Command>);
 
 impl CommandTrait for CommandSeries {
-    fn apply(&self, terminal: &mut Terminal) -> io::Result<()> {
-        for cmd in &self.0 {
-            try!(terminal.apply(cmd));
+    fn apply(self, terminal: &mut Terminal) -> io::Result<()> {
+        // The commands in a series are applied directly, so that a trace records the series
+        // as one command.
+        for cmd in self.0 {
+            try!(cmd.inner.apply(terminal));
         }
         Ok(())
     }
 
-    #[cfg(any(test, debug_assertions))]
     fn repr(&self) -> String {
         String::from("SERIES: ") + &self.0.iter().map(|c| c.inner.repr())
                                           .collect::<Vec<_>>().join("; ")
@@ -77,11 +158,11 @@ impl CommandTrait for CommandSeries {
 pub struct NoFeature(pub String);
 
 impl CommandTrait for NoFeature {
-    fn apply(&self, _: &mut Terminal) -> io::Result<()> {
+    fn apply(self, terminal: &mut Terminal) -> io::Result<()> {
+        terminal.record_unsupported(&self.0);
         Ok(())
     }
 
-    #[cfg(any(test, debug_assertions))]
     fn repr(&self) -> String {
         format!("NO FEATURE: {}", self.0)
     }
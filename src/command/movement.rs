@@ -16,18 +16,15 @@
 use notty_encoding::cmds::{Move, ScrollScreen};
 
 use command::prelude::*;
-#[cfg(any(test, debug_assertions))]
 use datatypes::Direction::*;
-#[cfg(any(test, debug_assertions))]
 use datatypes::Movement::*;
 
 impl Command for Move {
-    fn apply(&self, terminal: &mut Terminal) -> io::Result<()> {
+    fn apply(self, terminal: &mut Terminal) -> io::Result<()> {
         terminal.move_cursor(self.movement);
         Ok(())
     }
 
-    #[cfg(any(test, debug_assertions))]
     fn repr(&self) -> String {
         match self.movement {
             To(Up, n, _)        => format!("MOVE UP {}", n),
@@ -58,12 +55,11 @@ impl Command for Move {
 }
 
 impl Command for ScrollScreen {
-    fn apply(&self, terminal: &mut Terminal) -> io::Result<()> {
+    fn apply(self, terminal: &mut Terminal) -> io::Result<()> {
         terminal.scroll(self.dir, self.n);
         Ok(())
     }
 
-    #[cfg(any(test, debug_assertions))]
     fn repr(&self) -> String {
         String::from("SCROLL SCREEN")
     }
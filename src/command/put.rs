@@ -35,8 +35,8 @@ impl Put<WideChar> {
 }
 
 impl Put<CharExtender> {
-    pub fn new_extender(ch: char) -> Put<CharExtender> {
-        Put(CharExtender::new(ch))
+    pub fn new_extender(ch: char, width: u32) -> Put<CharExtender> {
+        Put(CharExtender::new(ch, width))
     }
 }
 
@@ -48,18 +48,39 @@ impl Put<Image> {
 
 impl<T: CharData> Command for Put<T> {
 
-    fn apply(&self, terminal: &mut Terminal) -> io::Result<()> {
-        terminal.write(&self.0);
+    fn apply(self, terminal: &mut Terminal) -> io::Result<()> {
+        terminal.write(self.0);
         Ok(())
     }
 
-    #[cfg(any(test, debug_assertions))]
     fn repr(&self) -> String {
         self.0.repr()
     }
 
 }
 
+/// Write a run of characters which all have the same width.
+pub struct PutStr(String, u32);
+
+impl PutStr {
+    pub fn new(text: String, width: u32) -> PutStr {
+        PutStr(text, width)
+    }
+}
+
+impl Command for PutStr {
+
+    fn apply(self, terminal: &mut Terminal) -> io::Result<()> {
+        terminal.write_str(&self.0, self.1);
+        Ok(())
+    }
+
+    fn repr(&self) -> String {
+        self.0.clone()
+    }
+
+}
+
 pub struct PutAt<T: CharData>(T, Coords);
 
 impl PutAt<Image> {
@@ -72,15 +93,14 @@ impl PutAt<Image> {
 
 impl<T: CharData> Command for PutAt<T> {
 
-    fn apply(&self, terminal: &mut Terminal) -> io::Result<()> {
+    fn apply(self, terminal: &mut Terminal) -> io::Result<()> {
         let coords = terminal.cursor().position();
         terminal.move_cursor(Position(self.1));
-        terminal.write(&self.0);
+        terminal.write(self.0);
         terminal.move_cursor(Position(coords));
         Ok(())
     }
 
-    #[cfg(any(test, debug_assertions))]
     fn repr(&self) -> String {
         String::from("PUT AT")
     }
@@ -21,11 +21,11 @@ use datatypes::{Key, Coords, Code};
 pub struct StaticResponse(pub &'static str);
 
 impl Command for StaticResponse {
-    fn apply(&self, terminal: &mut Terminal) -> io::Result<()> {
+    fn apply(self, terminal: &mut Terminal) -> io::Result<()> {
+        if !terminal.config().responses.status { return Ok(()) }
         terminal.send_input(Key::Cmd(Cow::Borrowed(self.0)), true)
     }
 
-    #[cfg(any(test, debug_assertions))]
     fn repr(&self) -> String {
         String::from("RESPOND ") + self.0
     }
@@ -34,7 +34,8 @@ impl Command for StaticResponse {
 pub struct ReportPosition(pub Code);
 
 impl Command for ReportPosition {
-    fn apply(&self, terminal: &mut Terminal) -> io::Result<()> {
+    fn apply(self, terminal: &mut Terminal) -> io::Result<()> {
+        if !terminal.config().responses.position { return Ok(()) }
         let Coords { x, y } = terminal.cursor().position();
         let cmd = match self.0 {
             Code::ANSI  => Cow::Owned(format!("\x1b[{};{}R", y, x)),
@@ -43,7 +44,6 @@ impl Command for ReportPosition {
         terminal.send_input(Key::Cmd(cmd), true)
     }
 
-    #[cfg(any(test, debug_assertions))]
     fn repr(&self) -> String {
         String::from("REPORT POSITION")
     }
@@ -13,22 +13,17 @@
 //  
 //  You should have received a copy of the GNU Affero General Public License
 //  along with this program.  If not, see <http://www.gnu.org/licenses/>.
-use std::cell::RefCell;
-
 use command::prelude::*;
 use datatypes::Coords;
 
-pub struct AddToolTip(pub Coords, pub RefCell<Option<String>>);
+pub struct AddToolTip(pub Coords, pub String);
 
 impl Command for AddToolTip {
-    fn apply(&self, terminal: &mut Terminal) -> io::Result<()> {
-        if let Some(string) = self.1.borrow_mut().take() {
-            terminal.add_tooltip(self.0, string);
-        }
+    fn apply(self, terminal: &mut Terminal) -> io::Result<()> {
+        terminal.add_tooltip(self.0, self.1);
         Ok(())
     }
 
-    #[cfg(any(test, debug_assertions))]
     fn repr(&self) -> String {
         String::from("ADD TOOL TIP")
     }
@@ -37,12 +32,11 @@ impl Command for AddToolTip {
 pub struct RemoveToolTip(pub Coords);
 
 impl Command for RemoveToolTip {
-    fn apply(&self, terminal: &mut Terminal) -> io::Result<()> {
+    fn apply(self, terminal: &mut Terminal) -> io::Result<()> {
         terminal.remove_tooltip(self.0);
         Ok(())
     }
 
-    #[cfg(any(test, debug_assertions))]
     fn repr(&self) -> String {
         String::from("REMOVE TOOL TIP")
     }
@@ -50,18 +44,15 @@ impl Command for RemoveToolTip {
 
 pub struct AddDropDown {
     pub coords: Coords,
-    pub options: RefCell<Option<Vec<String>>>,
+    pub options: Vec<String>,
 }
 
 impl Command for AddDropDown {
-    fn apply(&self, terminal: &mut Terminal) -> io::Result<()> {
-        if let Some(options) = self.options.borrow_mut().take() {
-            terminal.add_drop_down(self.coords, options);
-        }
+    fn apply(self, terminal: &mut Terminal) -> io::Result<()> {
+        terminal.add_drop_down(self.coords, self.options);
         Ok(())
     }
 
-    #[cfg(any(test, debug_assertions))]
     fn repr(&self) -> String {
         String::from("ADD TOOL TIP - DROP DOWN MENU")
     }
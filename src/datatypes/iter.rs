@@ -32,7 +32,7 @@ pub struct CoordsIter {
 
 impl CoordsIter {
 
-    pub fn from_area(area: Area, cursor: Coords, screen: Region) -> CoordsIter {
+    pub fn from_area(area: Area, cursor: Coords, screen: Region, tab_stop: u32) -> CoordsIter {
         match area {
             CursorCell              => CoordsIter {
                 point: cursor,
@@ -57,13 +57,13 @@ impl CoordsIter {
             },
             CursorTo(mov)           => CoordsIter {
                 point: cursor,
-                back_point: move_within(cursor, mov, screen),
+                back_point: move_within(cursor, mov, screen, tab_stop),
                 region: screen,
                 dir: mov.direction(cursor),
                 fin: false,
             },
             CursorBound(coords) if coords == cursor => {
-                CoordsIter::from_area(CursorCell, cursor, screen)
+                CoordsIter::from_area(CursorCell, cursor, screen, tab_stop)
             }
             CursorBound(coords)     => {
                 let (l, r) = (cmp::min(coords.x, cursor.x), cmp::max(coords.x, cursor.x) + 1);
@@ -131,7 +131,7 @@ impl Iterator for CoordsIter {
                 Some(self.point)
             }
             (false, _)  => {
-                let point = move_within(self.point, To(self.dir, 1, true), self.region);
+                let point = step(self.point, self.dir, self.region);
                 Some(mem::replace(&mut self.point, point))
             }
         }
@@ -153,7 +153,7 @@ impl DoubleEndedIterator for CoordsIter {
                 Some(self.point)
             }
             (false, _)  => {
-                let point = move_within(self.back_point, To(self.dir.rev(), 1, true), self.region);
+                let point = step(self.back_point, self.dir.rev(), self.region);
                 Some(mem::replace(&mut self.back_point, point))
             }
         }
@@ -210,6 +210,11 @@ impl ExactSizeIterator for CoordsIter {
 
 }
 
+/// Move one cell in a direction within a region; tab stops play no part in this movement.
+fn step(point: Coords, dir: Direction, region: Region) -> Coords {
+    move_within(point, To(dir, 1, true), region, 1)
+}
+
 impl From<Region> for CoordsIter {
     fn from(region: Region) -> CoordsIter {
         CoordsIter::from_region(region)
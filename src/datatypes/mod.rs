@@ -18,13 +18,10 @@
 //! The types in this module are intended to be passed between modules. As a design restriction,
 //! any methods on any type in this submodule are required to take the receiver immutably.
 use std::cmp;
-use std::sync::atomic::Ordering::Relaxed;
 
 mod iter;
 mod key;
 
-use cfg::TAB_STOP;
-
 pub use self::iter::CoordsIter;
 pub use self::key::Key;
 
@@ -67,11 +64,13 @@ pub enum Code {
     Notty,
 }
 
-/// Calculate the movement from one coordinate to another within a region.
-pub fn move_within(Coords {x, y}: Coords, movement: Movement, region: Region) -> Coords {
+/// Calculate the movement from one coordinate to another within a region, with tab stops every
+/// `tab_stop` columns.
+pub fn move_within(Coords {x, y}: Coords, movement: Movement, region: Region, tab_stop: u32)
+        -> Coords {
     use self::Movement::*;
     use self::Direction::*;
-    let tab_stop = TAB_STOP.load(Relaxed) as u32;
+    let tab_stop = cmp::max(tab_stop, 1);
     match movement {
         Position(coords)    => region.xy_within(coords),
         Column(n)           => Coords {x: region.x_within(n), y: y},
@@ -82,36 +81,42 @@ pub fn move_within(Coords {x, y}: Coords, movement: Movement, region: Region) ->
         ToEdge(Right)       => Coords {x: region.right - 1, y: y},
         ToBeginning         => Coords {x: region.left, y: region.top},
         ToEnd               => Coords {x: region.right - 1, y: region.bottom - 1},
-        To(Up, n, true) if region.top + n > y       => {
-            let x = x.saturating_sub((region.top + n - y) / (region.bottom - region.top) + 1);
-            let y = region.bottom - (region.top + n - y) % (region.bottom - region.top);
-            if x < region.left {
+        To(Up, n, true) if n > y.saturating_sub(region.top)         => {
+            let over = n - y.saturating_sub(region.top) - 1;
+            let height = region.bottom - region.top;
+            let columns = over / height + 1;
+            if columns > x.saturating_sub(region.left) {
                 Coords { x: region.left, y: region.top }
             } else {
-                Coords { x: x, y: y }
+                Coords { x: x - columns, y: region.bottom - 1 - over % height }
             }
         }
-        To(Down, n, true) if y + n >= region.bottom  => {
-            let x = x + (y + n - region.bottom) / (region.bottom - region.top) + 1;
-            let y = region.top + (y + n - region.bottom) % (region.bottom - region.top);
+        To(Down, n, true) if n >= region.bottom.saturating_sub(y)   => {
+            let over = n - region.bottom.saturating_sub(y);
+            let height = region.bottom - region.top;
+            let x = x.saturating_add(over / height + 1);
+            let y = region.top + over % height;
             if x >= region.right {
                 Coords { x: region.right - 1, y: region.bottom - 1 }
             } else {
                 Coords { x: x, y: y }
             }
         }
-        To(Left, n, true) if region.left + n > x    => {
-            let y = y.saturating_sub((region.left + n - x) / (region.right - region.left) + 1);
-            let x = region.right - (region.left + n - x) % (region.right - region.left);
-            if y < region.top {
+        To(Left, n, true) if n > x.saturating_sub(region.left)      => {
+            let over = n - x.saturating_sub(region.left) - 1;
+            let width = region.right - region.left;
+            let lines = over / width + 1;
+            if lines > y.saturating_sub(region.top) {
                 Coords { x: region.left, y: region.top }
             } else {
-                Coords { x: x, y: y }
+                Coords { x: region.right - 1 - over % width, y: y - lines }
             }
         }
-        To(Right, n, true) if x + n >= region.right  => {
-            let y = y + (x + n - region.right) / (region.right - region.left) + 1;
-            let x = region.left + (x + n - region.right) % (region.right - region.left);
+        To(Right, n, true) if n >= region.right.saturating_sub(x)   => {
+            let over = n - region.right.saturating_sub(x);
+            let width = region.right - region.left;
+            let y = y.saturating_add(over / width + 1);
+            let x = region.left + over % width;
             if y >= region.bottom {
                 Coords { x: region.right - 1, y: region.bottom - 1 }
             } else {
@@ -130,21 +135,41 @@ pub fn move_within(Coords {x, y}: Coords, movement: Movement, region: Region) ->
         To(Right, n, _) | IndexTo(Right, n)   => {
             Coords {x: cmp::min(x.saturating_add(n), region.right - 1), y: y}
         }
-        Tab(Left, n, true) if region.left + n > x => {
-            unimplemented!()
+        Tab(Left, n, true)                  => {
+            let tabs = TabStops::new(region, tab_stop);
+            let line = y as u64 * tabs.per_line;
+            match (line + tabs.before(x)).checked_sub(n as u64) {
+                Some(tab) if tab / tabs.per_line >= region.top as u64 => tabs.coords(tab),
+                _                                                     => {
+                    Coords { x: region.left, y: region.top }
+                }
+            }
         }
-        Tab(Right, n, true) if x + n >= region.right => {
-            unimplemented!()
+        Tab(Right, n, true)                 => {
+            let tabs = TabStops::new(region, tab_stop);
+            let tab = y as u64 * tabs.per_line + tabs.at_or_before(x) + n as u64;
+            if tab / tabs.per_line < region.bottom as u64 {
+                tabs.coords(tab)
+            } else {
+                Coords { x: region.right - 1, y: region.bottom - 1 }
+            }
         }
-        Tab(Left, n, _)                 => {
+        Tab(Left, n, false)                 => {
             let tab = ((x / tab_stop).saturating_sub(n)) * tab_stop;
             Coords {x: cmp::max(tab, region.left), y: y}
         }
-        Tab(Right, n, _)                => {
-            let tab = ((x / tab_stop) + n) * tab_stop;
+        Tab(Right, n, false)                => {
+            let tab = (x / tab_stop).saturating_add(n).saturating_mul(tab_stop);
             Coords {x: cmp::min(tab, region.right - 1), y: y}
         }
-        Tab(..)                             => unimplemented!(),
+        Tab(Up, n, _)                       => {
+            let tab = ((y / tab_stop).saturating_sub(n)) * tab_stop;
+            Coords {x: x, y: cmp::max(tab, region.top)}
+        }
+        Tab(Down, n, _)                     => {
+            let tab = (y / tab_stop).saturating_add(n).saturating_mul(tab_stop);
+            Coords {x: x, y: cmp::min(tab, region.bottom - 1)}
+        }
         PreviousLine(n)                     => {
             Coords {x: 0, y: cmp::max(y.saturating_sub(n), region.top)}
         }
@@ -154,12 +179,69 @@ pub fn move_within(Coords {x, y}: Coords, movement: Movement, region: Region) ->
     }
 }
 
+/// The tab stops of the lines of a region, which are numbered from the top left of the region
+/// onward as though the lines were one long line. The left edge of the region is a tab stop of
+/// every line, in addition to every `tab_stop`th column.
+struct TabStops {
+    left: u64,
+    tab_stop: u64,
+    per_line: u64,
+}
+
+impl TabStops {
+    fn new(region: Region, tab_stop: u32) -> TabStops {
+        let (left, tab_stop) = (region.left as u64, tab_stop as u64);
+        TabStops {
+            left: left,
+            tab_stop: tab_stop,
+            per_line: (region.right as u64 - 1) / tab_stop - left / tab_stop + 1,
+        }
+    }
+
+    /// The number of the last tab stop in its line at or before column `x`.
+    fn at_or_before(&self, x: u32) -> u64 {
+        (x as u64 / self.tab_stop).saturating_sub(self.left / self.tab_stop)
+    }
+
+    /// The number of tab stops in its line before column `x`.
+    fn before(&self, x: u32) -> u64 {
+        let x = x as u64;
+        if x > self.left { (x - 1) / self.tab_stop - self.left / self.tab_stop + 1 } else { 0 }
+    }
+
+    fn coords(&self, tab: u64) -> Coords {
+        let (y, n) = (tab / self.per_line, tab % self.per_line);
+        let x = if n == 0 { self.left } else { (self.left / self.tab_stop + n) * self.tab_stop };
+        Coords { x: x as u32, y: y as u32 }
+    }
+}
+
 #[derive(Copy, Clone)]
 pub struct GridSettings {
     pub width: u32,
     pub height: u32,
     pub retain_offscreen_state: bool,
+    pub scrollback: ScrollbackPolicy,
     pub flow: Flow,
+    pub tab_stop: u32,
+}
+
+/// How much offscreen state a grid which retains its offscreen state is allowed to keep. When
+/// the limit is reached, the oldest rows are discarded to make room for new ones.
+#[derive(Eq, PartialEq, Debug, Copy, Clone)]
+pub enum ScrollbackPolicy {
+    /// Never discard any rows.
+    Unlimited,
+    /// Keep at most this many rows in addition to the visible rows.
+    MaxRows(usize),
+    /// Keep as many rows in addition to the visible rows as fit in this many bytes.
+    MaxBytes(usize),
+}
+
+impl Default for ScrollbackPolicy {
+    fn default() -> ScrollbackPolicy {
+        ScrollbackPolicy::Unlimited
+    }
 }
 
 #[derive(Eq, PartialEq, Debug, Copy, Clone)]
@@ -168,3 +250,61 @@ pub enum Flow {
     Reflowable,
 }
 
+/// The size of the characters in a row of the grid, set with DECDWL and DECDHL. Only the left
+/// half of the columns of a row which is not of normal size are used.
+#[derive(Eq, PartialEq, Debug, Copy, Clone)]
+pub enum LineSize {
+    Normal,
+    DoubleWidth,
+    /// The top half of a line of characters which are double width and double height.
+    DoubleHeightTop,
+    /// The bottom half of a line of characters which are double width and double height.
+    DoubleHeightBottom,
+}
+
+impl Default for LineSize {
+    fn default() -> LineSize {
+        LineSize::Normal
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::{Coords, Direction, Movement, Region, move_within};
+    use super::Direction::*;
+    use super::Movement::*;
+
+    fn tab(x: u32, y: u32, direction: Direction, n: u32) -> Coords {
+        move_within(Coords { x: x, y: y }, Tab(direction, n, true), Region::new(0, 0, 20, 3), 8)
+    }
+
+    #[test]
+    fn tab_wraps_onto_next_line() {
+        assert_eq!(tab(3, 0, Right, 2), Coords { x: 16, y: 0 });
+        assert_eq!(tab(19, 0, Right, 1), Coords { x: 0, y: 1 });
+        assert_eq!(tab(17, 0, Right, 5), Coords { x: 8, y: 2 });
+        assert_eq!(tab(19, 2, Right, 1), Coords { x: 19, y: 2 });
+        assert_eq!(tab(0, 0, Right, u32::max_value()), Coords { x: 19, y: 2 });
+    }
+
+    #[test]
+    fn tab_wraps_onto_previous_line() {
+        assert_eq!(tab(10, 1, Left, 1), Coords { x: 8, y: 1 });
+        assert_eq!(tab(0, 1, Left, 1), Coords { x: 16, y: 0 });
+        assert_eq!(tab(8, 2, Left, 4), Coords { x: 0, y: 1 });
+        assert_eq!(tab(8, 0, Left, 2), Coords { x: 0, y: 0 });
+        assert_eq!(tab(19, 2, Left, u32::max_value()), Coords { x: 0, y: 0 });
+    }
+
+    #[test]
+    fn move_wraps_past_the_edges() {
+        let region = Region::new(0, 0, 4, 2);
+        let to = |x, y, movement: Movement| move_within(Coords { x: x, y: y }, movement, region, 8);
+        assert_eq!(to(3, 0, To(Left, 4, true)), Coords { x: 0, y: 0 });
+        assert_eq!(to(1, 1, To(Left, 4, true)), Coords { x: 1, y: 0 });
+        assert_eq!(to(0, 1, To(Left, 1, true)), Coords { x: 3, y: 0 });
+        assert_eq!(to(1, 0, To(Up, 2, true)), Coords { x: 0, y: 0 });
+        assert_eq!(to(3, 0, To(Right, u32::max_value(), true)), Coords { x: 3, y: 1 });
+    }
+}
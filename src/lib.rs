@@ -13,7 +13,7 @@
 //  
 //  You should have received a copy of the GNU Affero General Public License
 //  along with this program.  If not, see <http://www.gnu.org/licenses/>.
-#![feature(io, pub_restricted, associated_consts)]
+#![cfg_attr(all(test, feature = "bench"), feature(test))]
 
 extern crate base64;
 extern crate mime;
@@ -21,15 +21,22 @@ extern crate notty_encoding;
 extern crate unicode_width;
 extern crate uuid;
 
+#[cfg(all(test, feature = "bench"))]
+extern crate test;
+
+pub mod asciicast;
+pub mod cfg;
 mod command;
 pub mod datatypes;
 mod grapheme_tables;
 mod output;
 pub mod terminal;
 
-pub use output::Output;
+pub use output::{Output, Parsed, Parser};
+
+use std::fmt;
 
-use command::{KeyPress, KeyRelease, Paste, CommandTrait};
+use command::{KeyPress, KeyRelease, Paste, CommandKind};
 use datatypes::Key;
 
 /// A command to be applied to the terminal.
@@ -40,42 +47,43 @@ use datatypes::Key;
 /// constructors exist for creating `Command` objects with the correct internal representation for
 /// different kinds of user input.
 ///
-/// Commands are passed to the `Terminal` with the `apply` method.
+/// Commands are passed to the `Terminal` with the `apply` method, which consumes them. A command
+/// is stored by value, without a heap allocation of its own.
 pub struct Command {
-    inner: Box<CommandTrait>,
+    inner: CommandKind,
 }
 
 impl Command {
     /// Create a command representing a key press event.
     pub fn key_press(key: Key) -> Command {
         Command {
-            inner: Box::new(KeyPress(key)) as Box<CommandTrait>,
+            inner: CommandKind::KeyPress(KeyPress(key)),
         }
     }
     /// Create a command representing a key release event.
     pub fn key_release(key: Key) -> Command {
         Command {
-            inner: Box::new(KeyRelease(key)) as Box<CommandTrait>,
+            inner: CommandKind::KeyRelease(KeyRelease(key)),
         }
     }
 
     /// Create a command representing a paste from a clipboard.
     pub fn paste(data: String) -> Command {
         Command {
-            inner: Box::new(Paste(data)) as Box<CommandTrait>,
+            inner: CommandKind::Paste(Paste(data)),
         }
     }
 }
 
-pub mod cfg {
-    use std::sync::atomic::{AtomicIsize, AtomicUsize, ATOMIC_ISIZE_INIT, ATOMIC_USIZE_INIT};
-
-    /// The amount of scrollback to save in terminal grids which save their scrollback. None and
-    /// 0 mean that an infinite scrollback will be saved. If this is not set, it will be treated as
-    /// infinite scrollback.
-    pub static SCROLLBACK:  AtomicIsize = ATOMIC_ISIZE_INIT;
+/// Commands are displayed as a short description of what they do, such as `MOVE TO 6,6`.
+impl fmt::Display for Command {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.inner.repr())
+    }
+}
 
-    /// The distance between each tab stop. If you do not set this to a non-zero value, it will be
-    /// set to 4 when the terminal is initialized.
-    pub static TAB_STOP:    AtomicUsize = ATOMIC_USIZE_INIT;
+impl fmt::Debug for Command {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Command({:?})", self.inner.repr())
+    }
 }
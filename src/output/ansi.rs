@@ -13,7 +13,6 @@
 //  
 //  You should have received a copy of the GNU Affero General Public License
 //  along with this program.  If not, see <http://www.gnu.org/licenses/>.
-use std::cell::RefCell;
 use std::mem;
 
 use Command;
@@ -21,12 +20,21 @@ use command::*;
 use datatypes::Code;
 use datatypes::args::*;
 
+/// The most arguments a control sequence may have.
+pub const MAX_ARGS: usize = 32;
+/// The longest argument a control sequence may have, in bytes. This also limits the length of
+/// the strings passed by operating system commands.
+pub const MAX_ARG_LEN: usize = 4096;
+
 #[derive(Debug)]
 pub struct AnsiData {
     pub private_mode: char,
     pub preterminal: char,
     pub args: Vec<u32>,
     pub arg_buf: String,
+    /// Whether the sequence has more or longer arguments than are allowed. The rest of the
+    /// sequence is still parsed, but it is dropped when it ends.
+    pub overflowed: bool,
 }
 
 impl Default for AnsiData {
@@ -36,6 +44,7 @@ impl Default for AnsiData {
             preterminal: '\0',
             args: vec![],
             arg_buf: String::new(),
+            overflowed: false,
         }
     }
 }
@@ -46,6 +55,31 @@ impl AnsiData {
         self.private_mode = '\0';
         self.preterminal = '\0';
         self.args.clear();
+        self.arg_buf.clear();
+        self.overflowed = false;
+    }
+
+    /// Add a character to the argument in the buffer.
+    pub fn push_char(&mut self, ch: char) {
+        if self.arg_buf.len() + ch.len_utf8() > MAX_ARG_LEN {
+            self.overflowed = true;
+        } else {
+            self.arg_buf.push(ch);
+        }
+    }
+
+    /// Move the argument in the buffer onto the list of arguments. An empty argument is 0, and
+    /// an argument too large for a `u32` saturates.
+    pub fn push_arg(&mut self) {
+        let n = self.arg_buf.bytes().fold(0u32, |n, digit| {
+            n.saturating_mul(10).saturating_add((digit - b'0') as u32)
+        });
+        if self.args.len() < MAX_ARGS {
+            self.args.push(n);
+        } else {
+            self.overflowed = true;
+        }
+        self.arg_buf.clear();
     }
 
     pub fn csi(&self, terminal: char) -> Option<Command> {
@@ -60,17 +94,17 @@ impl AnsiData {
             ('D', '\0', '\0')        => wrap(Move::new(To(Left, self.arg(0,1), false))),
             ('E', '\0', '\0')        => wrap(Move::new(NextLine(self.arg(0,1)))),
             ('F', '\0', '\0')        => wrap(Move::new(PreviousLine(self.arg(0,1)))),
-            ('G', '\0', '\0')        => wrap(Move::new(Column(self.arg(0,1)-1))),
+            ('G', '\0', '\0')        => wrap(Move::new(Column(self.arg(0,1).saturating_sub(1)))),
             ('H', '\0', '\0')        => wrap(Move::new(Position(Coords {
-                x: self.arg(1,1)-1,
-                y: self.arg(0,1)-1,
+                x: self.arg(1,1).saturating_sub(1),
+                y: self.arg(0,1).saturating_sub(1),
             }))),
             ('I', '\0', '\0')        => wrap(Move::new(Tab(Right, self.arg(0,1), false))),
             ('J', '\0', '\0')        => match self.arg(0, 0) {
                 0   => wrap(Erase::new(CursorTo(ToEnd))),
                 1   => wrap(Erase::new(CursorTo(ToBeginning))),
                 2   => wrap(Erase::new(WholeScreen)),
-                3   => wrap(NoFeature(self.csi_code(terminal))),
+                3   => wrap(ClearScrollback),
                 _   => None
             },
             ('J', '?', '\0')     => wrap(NoFeature(self.csi_code(terminal))),
@@ -89,16 +123,16 @@ impl AnsiData {
             ('T', '>', '\0')     => wrap(NoFeature(self.csi_code(terminal))),
             ('X', '\0', '\0')        => wrap(Erase::new(CursorTo(To(Right, self.arg(0,1), false)))),
             ('Z', '\0', '\0')        => wrap(Move::new(Tab(Left, self.arg(0,1), false))),
-            ('`', '\0', '\0')        => wrap(Move::new(Column(self.arg(0,1)-1))),
+            ('`', '\0', '\0')        => wrap(Move::new(Column(self.arg(0,1).saturating_sub(1)))),
             ('a', '\0', '\0')        => wrap(Move::new(To(Right, self.arg(0,1), false))),
             ('b', '\0', '\0')        => wrap(NoFeature(self.csi_code(terminal))),
             ('c', '\0', '\0')        => wrap(NoFeature(self.csi_code(terminal))),
             ('c', '>', '\0')     => wrap(NoFeature(self.csi_code(terminal))),
-            ('d', '\0', '\0')        => wrap(Move::new(Row(self.arg(0,1)-1))),
+            ('d', '\0', '\0')        => wrap(Move::new(Row(self.arg(0,1).saturating_sub(1)))),
             ('e', '\0', '\0')        => wrap(Move::new(To(Down, self.arg(0,1), false))),
             ('f', '\0', '\0')        => wrap(Move::new(Position(Coords {
-                x: self.arg(1,1)-1,
-                y: self.arg(0,1)-1
+                x: self.arg(1,1).saturating_sub(1),
+                y: self.arg(0,1).saturating_sub(1)
             }))),
             ('g', '\0', '\0')        => wrap(NoFeature(self.csi_code(terminal))),
             ('h', '\0', '\0')        => command_series!(|x| match *x {
@@ -241,7 +275,7 @@ impl AnsiData {
             },
             ('n', '>', '\0')     => wrap(NoFeature(self.csi_code(terminal))),
             ('n', '?', '\0')     => wrap(NoFeature(self.csi_code(terminal))),
-            ('p', '\0', '!')     => wrap(NoFeature(self.csi_code(terminal))),
+            ('p', '\0', '!')     => wrap(SoftReset),
             ('p', '\0', '$')     => wrap(NoFeature(self.csi_code(terminal))),
             ('p', '\0', '"')     => wrap(NoFeature(self.csi_code(terminal))),
             ('p', '>', '\0')     => wrap(NoFeature(self.csi_code(terminal))),
@@ -309,34 +343,23 @@ impl AnsiData {
         }
     }
 
-    #[allow(unused, dead_code)]
-    pub fn dcs(&self, strarg: &str) -> Option<Command> {
-        match (self.private_mode, self.preterminal) {
-            ('|', '\0')   => unimplemented!(),
-            ('$', 'q')    => unimplemented!(),
-            ('+', 'p')    => unimplemented!(),
-            ('+', 'q')    => unimplemented!(),
-            _               => unreachable!(),
-        }
-    }
-
     pub fn osc(&mut self) -> Option<Command> {
         match self.arg(0, 0) {
             0...2   =>  {
                 let title = mem::replace(&mut self.arg_buf, String::new());
-                wrap(SetTitle(RefCell::new(Some(title))))
+                wrap(SetTitle(title))
             }
-            3   => unimplemented!(),
-            4   => unimplemented!(),
-            5   => unimplemented!(),
-            6   => unimplemented!(),
-            46  => unimplemented!(),
-            50  => unimplemented!(),
-            51  => unimplemented!(),
-            52  => unimplemented!(),
-            104 => unimplemented!(),
-            105 => unimplemented!(),
-            106 => unimplemented!(),
+            3   => wrap(NoFeature(self.osc_code())),
+            4   => wrap(NoFeature(self.osc_code())),
+            5   => wrap(NoFeature(self.osc_code())),
+            6   => wrap(NoFeature(self.osc_code())),
+            46  => wrap(NoFeature(self.osc_code())),
+            50  => wrap(NoFeature(self.osc_code())),
+            51  => wrap(NoFeature(self.osc_code())),
+            52  => wrap(NoFeature(self.osc_code())),
+            104 => wrap(NoFeature(self.osc_code())),
+            105 => wrap(NoFeature(self.osc_code())),
+            106 => wrap(NoFeature(self.osc_code())),
             _   => None
         }
     }
@@ -345,13 +368,22 @@ impl AnsiData {
         self.args.get(idx).map_or(default, |&x|x)
     }
 
+    fn osc_code(&self) -> String {
+        format!("^[]{}", self.arg(0, 0))
+    }
+
     fn csi_code(&self, terminal: char) -> String {
         let args = self.args.iter().map(ToString::to_string).collect::<Vec<_>>().join(";");
-        format!("^[[{}{}{}{}", self.private_mode, args, self.preterminal, terminal)
+        let mut code = String::from("^[[");
+        if self.private_mode != '\0' { code.push(self.private_mode); }
+        code.push_str(&args);
+        if self.preterminal != '\0' { code.push(self.preterminal); }
+        code.push(terminal);
+        code
     }
 
 }
 
-fn wrap<T: CommandTrait>(cmd: T) -> Option<Command> {
-    Some(Command { inner: Box::new(cmd) as Box<CommandTrait> })
+fn wrap<T: Into<CommandKind>>(cmd: T) -> Option<Command> {
+    Some(Command { inner: cmd.into() })
 }
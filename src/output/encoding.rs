@@ -0,0 +1,78 @@
+//  notty is a new kind of terminal emulator.
+//  Copyright (C) 2015 without boats
+//  
+//  This program is free software: you can redistribute it and/or modify
+//  it under the terms of the GNU Affero General Public License as published by
+//  the Free Software Foundation, either version 3 of the License, or
+//  (at your option) any later version.
+//  
+//  This program is distributed in the hope that it will be useful,
+//  but WITHOUT ANY WARRANTY; without even the implied warranty of
+//  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+//  GNU Affero General Public License for more details.
+//  
+//  You should have received a copy of the GNU Affero General Public License
+//  along with this program.  If not, see <http://www.gnu.org/licenses/>.
+use cfg::Encoding;
+
+/// Decode a byte of output which is in a single byte encoding. The lower half of every
+/// single byte encoding is ASCII.
+pub fn decode(encoding: Encoding, byte: u8) -> char {
+    match (encoding, byte) {
+        (_, 0x00...0x7f)                        => byte as char,
+        (Encoding::Cp437, _)                    => CP437[byte as usize - 0x80],
+        (Encoding::Windows1252, 0x80...0x9f)    => WINDOWS_1252[byte as usize - 0x80],
+        (_, _)                                  => byte as char,
+    }
+}
+
+/// The upper half of code page 437. The lower half is treated as ASCII rather than as the
+/// graphical characters of the original IBM PC, so that control characters can be used.
+static CP437: [char; 128] = [
+    '\u{c7}', '\u{fc}', '\u{e9}', '\u{e2}', '\u{e4}', '\u{e0}', '\u{e5}', '\u{e7}',
+    '\u{ea}', '\u{eb}', '\u{e8}', '\u{ef}', '\u{ee}', '\u{ec}', '\u{c4}', '\u{c5}',
+    '\u{c9}', '\u{e6}', '\u{c6}', '\u{f4}', '\u{f6}', '\u{f2}', '\u{fb}', '\u{f9}',
+    '\u{ff}', '\u{d6}', '\u{dc}', '\u{a2}', '\u{a3}', '\u{a5}', '\u{20a7}', '\u{192}',
+    '\u{e1}', '\u{ed}', '\u{f3}', '\u{fa}', '\u{f1}', '\u{d1}', '\u{aa}', '\u{ba}',
+    '\u{bf}', '\u{2310}', '\u{ac}', '\u{bd}', '\u{bc}', '\u{a1}', '\u{ab}', '\u{bb}',
+    '\u{2591}', '\u{2592}', '\u{2593}', '\u{2502}', '\u{2524}', '\u{2561}', '\u{2562}', '\u{2556}',
+    '\u{2555}', '\u{2563}', '\u{2551}', '\u{2557}', '\u{255d}', '\u{255c}', '\u{255b}', '\u{2510}',
+    '\u{2514}', '\u{2534}', '\u{252c}', '\u{251c}', '\u{2500}', '\u{253c}', '\u{255e}', '\u{255f}',
+    '\u{255a}', '\u{2554}', '\u{2569}', '\u{2566}', '\u{2560}', '\u{2550}', '\u{256c}', '\u{2567}',
+    '\u{2568}', '\u{2564}', '\u{2565}', '\u{2559}', '\u{2558}', '\u{2552}', '\u{2553}', '\u{256b}',
+    '\u{256a}', '\u{2518}', '\u{250c}', '\u{2588}', '\u{2584}', '\u{258c}', '\u{2590}', '\u{2580}',
+    '\u{3b1}', '\u{df}', '\u{393}', '\u{3c0}', '\u{3a3}', '\u{3c3}', '\u{b5}', '\u{3c4}',
+    '\u{3a6}', '\u{398}', '\u{3a9}', '\u{3b4}', '\u{221e}', '\u{3c6}', '\u{3b5}', '\u{2229}',
+    '\u{2261}', '\u{b1}', '\u{2265}', '\u{2264}', '\u{2320}', '\u{2321}', '\u{f7}', '\u{2248}',
+    '\u{b0}', '\u{2219}', '\u{b7}', '\u{221a}', '\u{207f}', '\u{b2}', '\u{25a0}', '\u{a0}',
+];
+
+/// The characters which Windows-1252 places among the C1 controls of Latin-1. The bytes which
+/// Windows-1252 leaves undefined are decoded as U+FFFD.
+static WINDOWS_1252: [char; 32] = [
+    '\u{20ac}', '\u{fffd}', '\u{201a}', '\u{192}', '\u{201e}', '\u{2026}', '\u{2020}', '\u{2021}',
+    '\u{2c6}', '\u{2030}', '\u{160}', '\u{2039}', '\u{152}', '\u{fffd}', '\u{17d}', '\u{fffd}',
+    '\u{fffd}', '\u{2018}', '\u{2019}', '\u{201c}', '\u{201d}', '\u{2022}', '\u{2013}', '\u{2014}',
+    '\u{2dc}', '\u{2122}', '\u{161}', '\u{203a}', '\u{153}', '\u{fffd}', '\u{17e}', '\u{178}',
+];
+
+#[cfg(test)]
+mod tests {
+
+    use cfg::Encoding;
+    use super::decode;
+
+    #[test]
+    fn single_byte_encodings() {
+        assert_eq!(decode(Encoding::Latin1, b'A'), 'A');
+        assert_eq!(decode(Encoding::Latin1, 0xe9), '\u{e9}');
+        assert_eq!(decode(Encoding::Latin1, 0x9b), '\u{9b}');
+        assert_eq!(decode(Encoding::Cp437, 0xc9), '\u{2554}');
+        assert_eq!(decode(Encoding::Cp437, 0x1b), '\x1b');
+        assert_eq!(decode(Encoding::Windows1252, 0x80), '\u{20ac}');
+        assert_eq!(decode(Encoding::Windows1252, 0xe9), '\u{e9}');
+        for &byte in &[0x81, 0x8d, 0x8f, 0x90, 0x9d] {
+            assert_eq!(decode(Encoding::Windows1252, byte), '\u{fffd}');
+        }
+    }
+}
@@ -0,0 +1,126 @@
+//  notty is a new kind of terminal emulator.
+//  Copyright (C) 2015 without boats
+//  
+//  This program is free software: you can redistribute it and/or modify
+//  it under the terms of the GNU Affero General Public License as published by
+//  the Free Software Foundation, either version 3 of the License, or
+//  (at your option) any later version.
+//  
+//  This program is distributed in the hope that it will be useful,
+//  but WITHOUT ANY WARRANTY; without even the implied warranty of
+//  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+//  GNU Affero General Public License for more details.
+//  
+//  You should have received a copy of the GNU Affero General Public License
+//  along with this program.  If not, see <http://www.gnu.org/licenses/>.
+use grapheme_tables::GraphemeCat;
+use grapheme_tables::GraphemeCat::*;
+
+/// Tracks the grapheme cluster most recently written, so that the parser can tell whether each
+/// character begins a new cluster or extends the last one. Boundaries are found by the extended
+/// grapheme cluster rules of UAX #29.
+#[derive(Default)]
+pub struct Segmenter {
+    prev: Option<GraphemeCat>,
+    width: u32,
+    emoji: bool,
+    pictographic: bool,
+    pictographic_zwj: bool,
+    lone_ri: bool,
+}
+
+impl Segmenter {
+
+    /// Forget the last cluster, so that the next character begins a new one.
+    pub fn reset(&mut self) {
+        *self = Segmenter::default();
+    }
+
+    /// Push the next character, which is in the category `cat` and is `width` cells wide on its
+    /// own. If the character extends the last cluster, returns the width of the extended
+    /// cluster.
+    pub fn push(&mut self, ch: char, cat: GraphemeCat, width: u32) -> Option<u32> {
+        let extends = match (self.prev, cat) {
+            (None, _) | (Some(GC_Control), _) | (_, GC_Control)         => false,
+            (Some(GC_L), GC_L) | (Some(GC_L), GC_V)
+                | (Some(GC_L), GC_LV) | (Some(GC_L), GC_LVT)            => true,
+            (Some(GC_LV), GC_V) | (Some(GC_LV), GC_T)
+                | (Some(GC_V), GC_V) | (Some(GC_V), GC_T)               => true,
+            (Some(GC_LVT), GC_T) | (Some(GC_T), GC_T)                   => true,
+            (_, GC_Extend) | (_, GC_ZWJ) | (_, GC_SpacingMark)          => true,
+            (Some(GC_Prepend), _)                                       => true,
+            (Some(GC_ZWJ), GC_Extended_Pictographic)                    => self.pictographic_zwj,
+            (Some(GC_Regional_Indicator), GC_Regional_Indicator)        => self.lone_ri,
+            _                                                           => false,
+        };
+        self.prev = Some(cat);
+        if extends {
+            self.pictographic_zwj = self.pictographic && cat == GC_ZWJ;
+            self.pictographic = cat == GC_Extended_Pictographic
+                || (self.pictographic && cat == GC_Extend);
+            if (ch == '\u{fe0f}' && self.emoji) || (cat == GC_Regional_Indicator && self.lone_ri) {
+                self.width = 2;
+            }
+            self.lone_ri = false;
+            Some(self.width)
+        } else {
+            self.width = width;
+            self.emoji = cat == GC_Extended_Pictographic || match ch {
+                '0'...'9' | '#' | '*'   => true,
+                _                       => false,
+            };
+            self.pictographic = cat == GC_Extended_Pictographic;
+            self.pictographic_zwj = false;
+            self.lone_ri = cat == GC_Regional_Indicator;
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use grapheme_tables::grapheme_category;
+    use super::*;
+
+    fn push(segmenter: &mut Segmenter, ch: char, width: u32) -> Option<u32> {
+        segmenter.push(ch, grapheme_category(ch), width)
+    }
+
+    #[test]
+    fn hangul() {
+        let mut segmenter = Segmenter::default();
+        assert_eq!(push(&mut segmenter, '\u{1100}', 2), None);
+        assert_eq!(push(&mut segmenter, '\u{1161}', 0), Some(2));
+        assert_eq!(push(&mut segmenter, '\u{11a8}', 0), Some(2));
+        assert_eq!(push(&mut segmenter, '\u{1100}', 2), None);
+    }
+
+    #[test]
+    fn regional_indicators() {
+        let mut segmenter = Segmenter::default();
+        assert_eq!(push(&mut segmenter, '\u{1f1e9}', 1), None);
+        assert_eq!(push(&mut segmenter, '\u{1f1ea}', 1), Some(2));
+        assert_eq!(push(&mut segmenter, '\u{1f1eb}', 1), None);
+    }
+
+    #[test]
+    fn zwj_sequences() {
+        let mut segmenter = Segmenter::default();
+        assert_eq!(push(&mut segmenter, '\u{1f468}', 2), None);
+        assert_eq!(push(&mut segmenter, '\u{200d}', 0), Some(2));
+        assert_eq!(push(&mut segmenter, '\u{1f469}', 2), Some(2));
+        assert_eq!(push(&mut segmenter, 'a', 1), None);
+        assert_eq!(push(&mut segmenter, '\u{200d}', 0), Some(1));
+        assert_eq!(push(&mut segmenter, '\u{1f469}', 2), None);
+    }
+
+    #[test]
+    fn emoji_presentation() {
+        let mut segmenter = Segmenter::default();
+        assert_eq!(push(&mut segmenter, '\u{263a}', 1), None);
+        assert_eq!(push(&mut segmenter, '\u{fe0f}', 0), Some(2));
+        assert_eq!(push(&mut segmenter, 'a', 1), None);
+        assert_eq!(push(&mut segmenter, '\u{fe0f}', 0), Some(1));
+    }
+}
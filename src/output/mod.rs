@@ -13,53 +13,344 @@
 //  
 //  You should have received a copy of the GNU Affero General Public License
 //  along with this program.  If not, see <http://www.gnu.org/licenses/>.
+use std::char;
+use std::collections::VecDeque;
 use std::io;
+use std::mem;
+use std::ops::Range;
+
 use unicode_width::UnicodeWidthChar;
 
+use cfg::{Encoding, TerminalConfig};
 use command::*;
+use datatypes::LineSize;
 use datatypes::args::*;
 use grapheme_tables as gr;
 use super::Command;
 
 mod ansi;
+mod encoding;
+mod grapheme;
 mod notty;
 
 use self::ansi::AnsiData;
+use self::grapheme::Segmenter;
 use self::notty::NottyData;
 use self::State::*;
 
-/// The `Output` struct processes data written to the terminal from the controlling process,
-/// parsing it into structured commands. It is implemented as an `Iterator`.
-pub struct Output<R: io::BufRead> {
-    tty: io::Chars<R>,
+/// The `Parser` turns the bytes written to the terminal by the controlling process into
+/// structured commands. Bytes are fed to the parser as they arrive; a command or a UTF-8
+/// sequence which is split between two calls to `feed` is completed by the later call. Invalid
+/// UTF-8 is replaced with U+FFFD.
+///
+/// The output is decoded in the `Encoding` of the config the parser was created with, until the
+/// controlling process switches to UTF-8 with `ESC % G` or back to a single byte encoding with
+/// `ESC % @`. A hard reset restores the encoding of the config.
+///
+/// Runs of printable characters of the same width are parsed into a single command, which is
+/// passed on when the run ends or when the data fed to the parser runs out. Characters which
+/// extend the grapheme cluster before them are parsed as extenders, which carry the width of the
+/// extended cluster. Characters of ambiguous width are parsed as narrow or wide according to
+/// the `WidthPolicy` of the config the parser was created with, or was last given with
+/// `set_config`.
+pub struct Parser {
     state: State,
+    wide_ambiguous: bool,
+    encoding: Encoding,
+    config_encoding: Encoding,
     ansi: AnsiData,
     notty: NottyData,
+    utf8: Utf8Decoder,
+    segmenter: Segmenter,
+    run: String,
+    run_width: u32,
+    run_end: u64,
+    read: u64,
+    offset: u64,
+    start: u64,
+}
+
+/// What was parsed from a span of the output.
+pub enum Parsed {
+    Command(Command),
+    /// A sequence which notty recognizes but does not support, described by its escape code.
+    /// Applying it records it in the terminal's list of unsupported sequences.
+    Unsupported(String),
+    /// Output which did not produce a command, such as control characters which are not
+    /// supported and escape codes which are deliberately ignored.
+    Dropped,
+}
+
+impl Parsed {
+    /// The command to apply to the terminal for this span, if there is one.
+    pub fn into_command(self) -> Option<Command> {
+        match self {
+            Parsed::Command(cmd)        => Some(cmd),
+            Parsed::Unsupported(code)   => Some(Command { inner: NoFeature(code).into() }),
+            Parsed::Dropped             => None,
+        }
+    }
+}
+
+/// The `Output` struct processes data written to the terminal from the controlling process,
+/// parsing it into structured commands. It is implemented as an `Iterator`, which feeds a
+/// `Parser` with everything it reads.
+pub struct Output<R: io::BufRead> {
+    tty: R,
+    parser: Parser,
+    parsed: VecDeque<(Range<u64>, Parsed)>,
 }
 
 impl<R: io::BufRead> Output<R> {
 
     /// Create a new output processor wrapping a buffered read interface to the tty.
     pub fn new(tty: R) -> Output<R> {
+        Output::with_config(tty, &TerminalConfig::default())
+    }
+
+    /// Create a new output processor which parses the output according to `config`.
+    pub fn with_config(tty: R, config: &TerminalConfig) -> Output<R> {
         Output {
-            tty: tty.chars(),
+            tty: tty,
+            parser: Parser::with_config(config),
+            parsed: VecDeque::new(),
+        }
+    }
+
+    /// The number of bytes of output which have been read.
+    pub fn offset(&self) -> u64 {
+        self.parser.offset()
+    }
+
+    /// Parse the output which has not been read yet according to `config`.
+    pub fn set_config(&mut self, config: &TerminalConfig) {
+        self.parser.set_config(config);
+    }
+
+    /// Parse the next span of the output, returning the offsets of the bytes it covers along with
+    /// what was parsed from them. Unlike iterating over the output, which only returns commands,
+    /// this also returns the spans of output which were dropped without producing a command.
+    pub fn next_span(&mut self) -> Option<io::Result<(Range<u64>, Parsed)>> {
+        loop {
+            if let Some(span) = self.parsed.pop_front() {
+                return Some(Ok(span));
+            }
+            let len = {
+                let data = match self.tty.fill_buf() {
+                    Ok(data)                                                    => data,
+                    Err(ref err) if err.kind() == io::ErrorKind::Interrupted    => continue,
+                    Err(err)                                                    => {
+                        return Some(Err(err))
+                    }
+                };
+                let parsed = &mut self.parsed;
+                if data.is_empty() {
+                    self.parser.finish_spans(&mut |range, span| parsed.push_back((range, span)));
+                    return parsed.pop_front().map(Ok);
+                }
+                self.parser.feed_spans(data, &mut |range, span| parsed.push_back((range, span)));
+                data.len()
+            };
+            self.tty.consume(len);
+        }
+    }
+}
+
+impl Default for Parser {
+    fn default() -> Parser {
+        Parser::new()
+    }
+}
+
+impl Parser {
+
+    pub fn new() -> Parser {
+        Parser::with_config(&TerminalConfig::default())
+    }
+
+    /// Create a parser which parses the output according to `config`.
+    pub fn with_config(config: &TerminalConfig) -> Parser {
+        Parser {
             state: Character,
+            wide_ambiguous: config.width.ambiguous_is_wide(),
+            encoding: config.encoding,
+            config_encoding: config.encoding,
             ansi: AnsiData::default(),
             notty: NottyData::default(),
+            utf8: Utf8Decoder::default(),
+            segmenter: Segmenter::default(),
+            run: String::new(),
+            run_width: 0,
+            run_end: 0,
+            read: 0,
+            offset: 0,
+            start: 0,
+        }
+    }
+
+    /// The number of bytes which have been fed to the parser.
+    pub fn offset(&self) -> u64 {
+        self.read
+    }
+
+    /// Parse the output fed after this point according to `config`, such as the config of a
+    /// terminal whose session has been restored. If the controlling process has not switched
+    /// encodings with `ESC %`, the output is decoded in the encoding of `config`.
+    pub fn set_config(&mut self, config: &TerminalConfig) {
+        self.wide_ambiguous = config.width.ambiguous_is_wide();
+        if self.encoding == self.config_encoding {
+            self.encoding = config.encoding;
+        }
+        self.config_encoding = config.encoding;
+    }
+
+    /// Parse `data`, passing each command parsed from it to `f`.
+    pub fn feed<F: FnMut(Command)>(&mut self, data: &[u8], f: &mut F) {
+        self.feed_spans(data, &mut |_, parsed| if let Some(cmd) = parsed.into_command() { f(cmd) });
+    }
+
+    /// Parse `data`, passing every span of it to `f` with the offsets of the bytes the span
+    /// covers, counted from the first byte fed to the parser. Unlike `feed`, this also passes
+    /// the spans which were dropped without producing a command.
+    pub fn feed_spans<F: FnMut(Range<u64>, Parsed)>(&mut self, data: &[u8], f: &mut F) {
+        for &byte in data {
+            if self.encoding != Encoding::Utf8 {
+                self.read += 1;
+                let end = self.read;
+                let ch = encoding::decode(self.encoding, byte);
+                self.parse_char(ch, end, f);
+                continue;
+            }
+            if self.utf8.interrupted_by(byte) {
+                self.utf8.reset();
+                let end = self.read;
+                self.parse_char('\u{fffd}', end, f);
+            }
+            self.read += 1;
+            if let Some(ch) = self.utf8.push(byte) {
+                let end = self.read;
+                self.parse_char(ch, end, f);
+            }
+        }
+        self.end_run(f);
+    }
+
+    /// Finish parsing the output when it has ended, passing each command parsed from what was
+    /// left of it to `f`.
+    pub fn finish<F: FnMut(Command)>(&mut self, f: &mut F) {
+        self.finish_spans(&mut |_, parsed| if let Some(cmd) = parsed.into_command() { f(cmd) });
+    }
+
+    /// Finish parsing the output when it has ended, passing every span of what was left of it
+    /// to `f`. A UTF-8 sequence which the output ended in the middle of is replaced with U+FFFD.
+    pub fn finish_spans<F: FnMut(Range<u64>, Parsed)>(&mut self, f: &mut F) {
+        if self.utf8.is_incomplete() {
+            self.utf8.reset();
+            let end = self.read;
+            self.parse_char('\u{fffd}', end, f);
+        }
+        self.end_run(f);
+    }
+
+    /// Parse a character, which was decoded from the bytes of the output up to `end`.
+    fn parse_char<F: FnMut(Range<u64>, Parsed)>(&mut self, ch: char, end: u64, f: &mut F) {
+        let begin = mem::replace(&mut self.offset, end);
+        // An ignored sequence ends at the first character which is not ignored, which is
+        // then parsed as usual.
+        if let Ignore(chars) = self.state {
+            if !chars.contains(&ch) {
+                self.state = Character;
+                let start = mem::replace(&mut self.start, begin);
+                f(start..begin, Parsed::Dropped);
+            }
+        }
+        if self.is_between_sequences() {
+            let cat = gr::grapheme_category(ch);
+            let own_width = char_width(ch, cat, self.wide_ambiguous).unwrap_or(0);
+            if cat == gr::GraphemeCat::GC_Control {
+                self.segmenter.reset();
+            } else if let Some(width) = self.segmenter.push(ch, cat, own_width) {
+                self.end_run(f);
+                let start = mem::replace(&mut self.start, end);
+                let cmd = Command { inner: Put::new_extender(ch, width).into() };
+                f(start..end, Parsed::Command(cmd));
+                return;
+            }
+            if let Some(width) = printable_width(ch, self.wide_ambiguous) {
+                if width != self.run_width {
+                    self.end_run(f);
+                }
+                self.run.push(ch);
+                self.run_width = width;
+                self.run_end = end;
+                return;
+            }
+        }
+        self.end_run(f);
+        let (state, cmd) = match self.state {
+            Character       => self.character(ch),
+            EscCode         => self.esc_code(ch),
+            EncodingCode    => self.encoding_code(ch),
+            LineCode        => self.line_code(ch),
+            CsiCode         => self.csi_code(ch),
+            DcsCode         => self.dcs_code(ch),
+            OscCode         => self.osc_code(ch),
+            ApcCode         => self.apc_code(ch),
+            PrivMsg         => self.privacy_message(ch),
+            NottyCode       => self.notty_code(ch),
+            NottyAttach     => {
+                match self.notty.attachments.append(ch) {
+                    Some(true)  => {
+                        let ret = (Character, self.notty.parse());
+                        self.notty.clear();
+                        ret
+                    }
+                    Some(false) => {
+                        self.notty.clear();
+                        (Character, None)
+                    }
+                    None        => (NottyAttach, None),
+                }
+            }
+            Ignore(_)       => (Character, None),
+        };
+        self.state = state;
+        let parsed = match cmd {
+            Some(Command { inner: CommandKind::NoFeature(NoFeature(code)) })   => {
+                Parsed::Unsupported(code)
+            }
+            Some(cmd)                                                           => {
+                Parsed::Command(cmd)
+            }
+            None if self.is_between_sequences()                                 => {
+                Parsed::Dropped
+            }
+            None                                                                => return,
+        };
+        let start = mem::replace(&mut self.start, end);
+        f(start..end, parsed);
+    }
+
+    /// Pass on the run of printable characters parsed so far, if there is one.
+    fn end_run<F: FnMut(Range<u64>, Parsed)>(&mut self, f: &mut F) {
+        if self.run.is_empty() { return; }
+        let text = mem::replace(&mut self.run, String::new());
+        let width = mem::replace(&mut self.run_width, 0);
+        let end = self.run_end;
+        let start = mem::replace(&mut self.start, end);
+        f(start..end, Parsed::Command(Command { inner: PutStr::new(text, width).into() }));
+    }
+
+    fn is_between_sequences(&self) -> bool {
+        match self.state {
+            Character   => true,
+            _           => false,
         }
     }
 
     fn character(&mut self, ch: char) -> (State, Option<Command>) {
         use grapheme_tables::GraphemeCat::*;
         match gr::grapheme_category(ch) {
-            GC_Any                      => {
-                let width = ch.width().unwrap() as u32;
-                if width == 1 {
-                    (Character, wrap(Put::new_char(ch)))
-                } else {
-                    (Character, wrap(Put::new_wide_char(ch, width)))
-                }
-            }
             GC_Control                  => match ch {
                 '\x07'      => (Character, wrap(Bell)),
                 '\x08'      => (Character, wrap(Move::new(To(Left, 1, true)))),
@@ -75,8 +366,15 @@ impl<R: io::BufRead> Output<R> {
                 '\u{9f}'    => (ApcCode, None),
                 _           => (Character, None),
             },
-            GC_Extend | GC_SpacingMark  => (Character, wrap(Put::new_extender(ch))),
-            _                           => unimplemented!(),
+            GC_Extend | GC_SpacingMark  => (Character, wrap(Put::new_extender(ch, 1))),
+            _                           => match printable_width(ch, self.wide_ambiguous) {
+                Some(1)     => (Character, wrap(Put::new_char(ch))),
+                Some(width) => (Character, wrap(Put::new_wide_char(ch, width))),
+                None        => match ch.width() {
+                    Some(0) => (Character, wrap(Put::new_extender(ch, 1))),
+                    _       => (Character, None),
+                },
+            },
         }
     }
 
@@ -86,14 +384,8 @@ impl<R: io::BufRead> Output<R> {
                 static IGNORE: &'static [char] = &['F', 'G', 'L', 'N'];
                 (Ignore(IGNORE), None)
             }
-            '#' => {
-                static IGNORE: &'static [char] = &['3', '4', '5', '6', '8'];
-                (Ignore(IGNORE), None)
-            }
-            '%' => {
-                static IGNORE: &'static [char] = &['@', 'G'];
-                (Ignore(IGNORE), None)
-            }
+            '#' => (LineCode, None),
+            '%' => (EncodingCode, None),
             '('...'/'   => {
                 static IGNORE: &'static [char] = &[
                     '0', '<', '>', '%', 'A', 'B', '4', 'C', '5', 'R', 'f', 'Q', '9', 'K', 'Y',
@@ -102,6 +394,10 @@ impl<R: io::BufRead> Output<R> {
                 (Ignore(IGNORE), None)
             }
             'E' => (Character, wrap(Move::new(NextLine(1)))),
+            'c' => {
+                self.encoding = self.config_encoding;
+                (Character, wrap(HardReset))
+            }
             'P' => (DcsCode, None),
             '[' => (CsiCode, None),
             ']' => (OscCode, None),
@@ -111,6 +407,34 @@ impl<R: io::BufRead> Output<R> {
         }
     }
 
+    fn encoding_code(&mut self, ch: char) -> (State, Option<Command>) {
+        match ch {
+            '@' => {
+                self.encoding = match self.config_encoding {
+                    Encoding::Utf8  => Encoding::Latin1,
+                    encoding        => encoding,
+                };
+                (Character, None)
+            }
+            'G' => {
+                self.encoding = Encoding::Utf8;
+                (Character, None)
+            }
+            _   => self.character(ch),
+        }
+    }
+
+    fn line_code(&mut self, ch: char) -> (State, Option<Command>) {
+        match ch {
+            '3' => (Character, wrap(SetLineSize(LineSize::DoubleHeightTop))),
+            '4' => (Character, wrap(SetLineSize(LineSize::DoubleHeightBottom))),
+            '5' => (Character, wrap(SetLineSize(LineSize::Normal))),
+            '6' => (Character, wrap(SetLineSize(LineSize::DoubleWidth))),
+            '8' => (Character, wrap(AlignmentTest)),
+            _   => self.character(ch),
+        }
+    }
+
     fn csi_code(&mut self, ch: char) -> (State, Option<Command>) {
         static CSI_PRIVATE_MODES:   &'static [char] = &['>', '?'];
         static CSI_PRETERMINALS:    &'static [char] = &[' ', '!', '"', '$', '\'', '*'];
@@ -128,22 +452,18 @@ impl<R: io::BufRead> Output<R> {
         }
         // Digit.
         else if self.ansi.preterminal == '\0' && ch.is_digit(10) {
-            self.ansi.arg_buf.push(ch);
+            self.ansi.push_char(ch);
             (CsiCode, None)
         }
         // Arg separator.
         else if self.ansi.preterminal == '\0' && ch == ';' {
-            let n = u32::from_str_radix(&self.ansi.arg_buf, 10).unwrap();
-            self.ansi.args.push(n);
-            self.ansi.arg_buf.clear();
+            self.ansi.push_arg();
             (CsiCode, None)
         }
         // Preterminal.
         else if self.ansi.preterminal == '\0' && CSI_PRETERMINALS.contains(&ch) {
             if self.ansi.arg_buf.len() > 0 {
-                let n = u32::from_str_radix(&self.ansi.arg_buf, 10).unwrap();
-                self.ansi.args.push(n);
-                self.ansi.arg_buf.clear();
+                self.ansi.push_arg();
             }
             self.ansi.preterminal = ch;
             (CsiCode, None)
@@ -151,13 +471,11 @@ impl<R: io::BufRead> Output<R> {
         // Terminal.
         else if CSI_TERMINALS.contains(&ch) {
             if self.ansi.arg_buf.len() > 0 {
-                let n = u32::from_str_radix(&self.ansi.arg_buf, 10).unwrap();
-                self.ansi.args.push(n);
-                self.ansi.arg_buf.clear();
+                self.ansi.push_arg();
             }
-            let ret = (Character, self.ansi.csi(ch));
+            let cmd = if self.ansi.overflowed { None } else { self.ansi.csi(ch) };
             self.ansi.clear();
-            ret
+            (Character, cmd)
         }
         // Invalid.
         else {
@@ -166,38 +484,50 @@ impl<R: io::BufRead> Output<R> {
         }
     }
 
-    #[allow(unused)]
     fn dcs_code(&mut self, ch: char) -> (State, Option<Command>) {
-        unimplemented!()
+        // Device control strings are not supported; the string is ignored up to its terminator.
+        match (self.ansi.preterminal, ch) {
+            ('\0', '\x1b')                                  => {
+                self.ansi.preterminal = ch;
+                (DcsCode, None)
+            }
+            (_, '\u{9c}') | ('\x1b', '\\')                  => {
+                self.ansi.clear();
+                (Character, None)
+            }
+            ('\0', _)                                       => (DcsCode, None),
+            (_, _)                                          => {
+                self.ansi.clear();
+                self.esc_code(ch)
+            }
+        }
     }
 
     fn osc_code(&mut self, ch: char) -> (State, Option<Command>) {
         if ch.is_digit(10) && self.ansi.private_mode == '\0' {
-            self.ansi.arg_buf.push(ch);
+            self.ansi.push_char(ch);
             (OscCode, None)
         }
         else if ch == ';' && self.ansi.private_mode == '\0' {
-            let n = u32::from_str_radix(&self.ansi.arg_buf, 10).unwrap();
-            self.ansi.args.push(n);
-            self.ansi.arg_buf.clear();
+            self.ansi.push_arg();
             self.ansi.private_mode = ';';
             (OscCode, None)
         }
         else if ch == '\x1b' && self.ansi.preterminal == '\0' {
-            self.ansi.preterminal == '\x1b';
+            self.ansi.preterminal = '\x1b';
             (OscCode, None)
         }
         else if ch == '\u{9c}' || ch == '\x07' || (ch == '\\' && self.ansi.preterminal == '\x1b') {
-            let ret = (Character, self.ansi.osc());
+            let cmd = if self.ansi.overflowed { None } else { self.ansi.osc() };
             self.ansi.clear();
-            ret
+            (Character, cmd)
         }
         else if self.ansi.private_mode == ';' {
             if self.ansi.preterminal == '\x1b' {
-                self.ansi.arg_buf.push('\x1b');
+                self.ansi.push_char('\x1b');
                 self.ansi.preterminal = '\0';
             }
-            self.ansi.arg_buf.push(ch);
+            self.ansi.push_char(ch);
             (OscCode, None)
         }
         else {
@@ -221,7 +551,10 @@ impl<R: io::BufRead> Output<R> {
             }
             (_, '\u{9c}') | (_, '\x07') | ('\x1b', '\\')    => (Character, None),
             ('\0', _)                                       => (PrivMsg, None),
-            (_, _)                                          => self.character(ch),
+            (_, _)                                          => {
+                self.ansi.clear();
+                self.esc_code(ch)
+            }
         }
     }
 
@@ -250,50 +583,81 @@ impl<R: io::BufRead> Iterator for super::Output<R> {
     type Item = io::Result<Command>;
     fn next(&mut self) -> Option<io::Result<Command>> {
         loop {
-            match self.tty.next() {
-                Some(Ok(ch))                            => {
-                    let (state, cmd) = match self.state {
-                        Character       => self.character(ch),
-                        EscCode         => self.esc_code(ch),
-                        CsiCode         => self.csi_code(ch),
-                        DcsCode         => self.dcs_code(ch),
-                        OscCode         => self.osc_code(ch),
-                        ApcCode         => self.apc_code(ch),
-                        PrivMsg         => self.privacy_message(ch),
-                        NottyCode       => self.notty_code(ch),
-                        NottyAttach     => {
-                            match self.notty.attachments.append(ch) {
-                                Some(true)  => {
-                                    let ret = (Character, self.notty.parse());
-                                    self.notty.clear();
-                                    ret
-                                }
-                                Some(false) => (Character, None),
-                                None        => (NottyAttach, None),
-                            }
-                        }
-                        Ignore(chars)   => {
-                            if chars.contains(&ch) { continue }
-                            else { self.character(ch) }
-                        }
-                    };
-                    self.state = state;
-                    match cmd {
-                        Some(cmd)   => return Some(Ok(cmd)),
-                        None        => continue
-                    }
+            match self.next_span() {
+                Some(Ok((_, parsed)))   => match parsed.into_command() {
+                    Some(cmd)   => return Some(Ok(cmd)),
+                    None        => continue,
                 },
-                Some(Err(io::CharsError::NotUtf8))      => continue,
-                Some(Err(io::CharsError::Other(err)))   => return Some(Err(err)),
-                None                                    => return None,
+                Some(Err(err))          => return Some(Err(err)),
+                None                    => return None,
             }
         }
     }
 }
 
+/// An incremental UTF-8 decoder. Each maximal prefix of a valid sequence which is not followed
+/// by the rest of the sequence is decoded as U+FFFD.
+#[derive(Default)]
+struct Utf8Decoder {
+    code: u32,
+    /// The number of continuation bytes needed to finish the character being decoded.
+    needed: u8,
+    /// The range of values which the next continuation byte may take.
+    lower: u8,
+    upper: u8,
+}
+
+impl Utf8Decoder {
+    /// Whether a character is being decoded which `byte` does not continue.
+    fn interrupted_by(&self, byte: u8) -> bool {
+        self.needed > 0 && (byte < self.lower || byte > self.upper)
+    }
+
+    /// Whether the bytes decoded so far end in the middle of a character.
+    fn is_incomplete(&self) -> bool {
+        self.needed > 0
+    }
+
+    fn reset(&mut self) {
+        self.needed = 0;
+    }
+
+    /// Decode the next byte, which must not interrupt the character being decoded.
+    fn push(&mut self, byte: u8) -> Option<char> {
+        if self.needed == 0 {
+            let (needed, lower, upper) = match byte {
+                0x00...0x7f => return Some(byte as char),
+                0xc2...0xdf => (1, 0x80, 0xbf),
+                0xe0        => (2, 0xa0, 0xbf),
+                0xed        => (2, 0x80, 0x9f),
+                0xe1...0xef => (2, 0x80, 0xbf),
+                0xf0        => (3, 0x90, 0xbf),
+                0xf1...0xf3 => (3, 0x80, 0xbf),
+                0xf4        => (3, 0x80, 0x8f),
+                _           => return Some('\u{fffd}'),
+            };
+            self.code = (byte & (0x3f >> needed)) as u32;
+            self.needed = needed;
+            self.lower = lower;
+            self.upper = upper;
+            None
+        } else {
+            self.code = (self.code << 6) | (byte & 0x3f) as u32;
+            self.needed -= 1;
+            self.lower = 0x80;
+            self.upper = 0xbf;
+            if self.needed == 0 {
+                Some(char::from_u32(self.code).unwrap_or('\u{fffd}'))
+            } else { None }
+        }
+    }
+}
+
 enum State {
     Character,
     EscCode,
+    EncodingCode,
+    LineCode,
     CsiCode,
     #[allow(dead_code)]
     DcsCode,
@@ -305,16 +669,49 @@ enum State {
     Ignore(&'static [char]),
 }
 
-fn wrap<T: CommandTrait>(cmd: T) -> Option<Command> {
-    Some(Command { inner: Box::new(cmd) as Box<CommandTrait>})
+/// The width of a character which occupies cells of its own when it is written, or `None` for
+/// controls and for characters which extend the grapheme before them.
+fn printable_width(ch: char, wide_ambiguous: bool) -> Option<u32> {
+    use grapheme_tables::GraphemeCat::*;
+    let cat = gr::grapheme_category(ch);
+    match (cat, char_width(ch, cat, wide_ambiguous)) {
+        (GC_Control, _) | (GC_Extend, _) | (GC_SpacingMark, _) | (GC_ZWJ, _)  => None,
+        (_, Some(0)) | (_, None)                                                => None,
+        (_, Some(width))                                                        => Some(width),
+    }
+}
+
+/// The number of cells `ch` occupies on its own. Emoji which are presented as emoji by default
+/// are two cells wide, and characters of ambiguous width are two cells wide if
+/// `wide_ambiguous` is set. Control characters have no width.
+fn char_width(ch: char, cat: gr::GraphemeCat, wide_ambiguous: bool) -> Option<u32> {
+    match cat {
+        gr::GraphemeCat::GC_Extended_Pictographic if gr::is_wide_emoji(ch) => Some(2),
+        _ if wide_ambiguous                                                 => {
+            ch.width_cjk().map(|width| width as u32)
+        }
+        _                                                                   => {
+            ch.width().map(|width| width as u32)
+        }
+    }
+}
+
+fn wrap<T: Into<CommandKind>>(cmd: T) -> Option<Command> {
+    Some(Command { inner: cmd.into() })
 }
 
 #[cfg(test)]
 mod tests {
 
-    use std::io::BufReader;
+    use std::io::{self, BufReader};
 
+    #[cfg(feature = "bench")]
+    use test::Bencher;
+
+    use cfg::{Encoding, TerminalConfig, WidthPolicy};
     use command::*;
+    use datatypes::{Coords, LineSize};
+    use terminal::{CellData, Terminal};
     use super::*;
 
     fn setup(data: &[u8]) -> Output<BufReader<&[u8]>> {
@@ -333,8 +730,7 @@ mod tests {
     #[test]
     fn ctrl_codes() {
         let mut output = setup(b"AB\x07C\n");
-        assert_eq!(&output.next().unwrap().unwrap().inner.repr(), "A");
-        assert_eq!(&output.next().unwrap().unwrap().inner.repr(), "B");
+        assert_eq!(&output.next().unwrap().unwrap().inner.repr(), "AB");
         assert_eq!(&output.next().unwrap().unwrap().inner.repr(), "BELL");
         assert_eq!(&output.next().unwrap().unwrap().inner.repr(), "C");
         assert_eq!(&output.next().unwrap().unwrap().inner.repr(), "MOVE NEXT LINE 1");
@@ -351,6 +747,279 @@ mod tests {
         assert_eq!(&output.next().unwrap().unwrap().inner.repr(), "SERIES: SET CURSOR STYLE");
     }
 
+    #[test]
+    fn clear_scrollback() {
+        let mut output = setup("\x1b[3J\x1b_[23\u{9c}".as_bytes());
+        assert_eq!(&output.next().unwrap().unwrap().inner.repr(), "CLEAR SCROLLBACK");
+        assert_eq!(&output.next().unwrap().unwrap().inner.repr(), "CLEAR SCROLLBACK");
+    }
+
+    #[test]
+    fn reset() {
+        let mut output = setup(b"A\x1bc\x1b[!pB");
+        assert_eq!(&output.next().unwrap().unwrap().inner.repr(), "A");
+        assert_eq!(&output.next().unwrap().unwrap().inner.repr(), "HARD RESET");
+        assert_eq!(&output.next().unwrap().unwrap().inner.repr(), "SOFT RESET");
+        assert_eq!(&output.next().unwrap().unwrap().inner.repr(), "B");
+    }
+
+    #[test]
+    fn spans() {
+        let mut output = setup(b"A\x1b(BB\x01\x1b[5n");
+        let mut spans = vec![];
+        while let Some(span) = output.next_span() {
+            spans.push(match span.unwrap() {
+                (range, Parsed::Command(cmd))       => (range, cmd.inner.repr()),
+                (range, Parsed::Unsupported(code))  => (range, format!("UNSUPPORTED {}", code)),
+                (range, Parsed::Dropped)            => (range, String::from("DROPPED")),
+            });
+        }
+        assert_eq!(spans, vec![
+            (0..1, String::from("A")),
+            (1..4, String::from("DROPPED")),
+            (4..5, String::from("B")),
+            (5..6, String::from("DROPPED")),
+            (6..10, String::from("RESPOND \x1b[0n")),
+        ]);
+        assert_eq!(output.offset(), 10);
+        let mut output = setup(b"\x1b[c\x1b[>1c");
+        match output.next_span().unwrap().unwrap() {
+            (range, Parsed::Unsupported(code))  => assert_eq!((range, &code[..]), (0..3, "^[[c")),
+            _                                   => panic!("CSI c is not supported"),
+        }
+        match output.next_span().unwrap().unwrap() {
+            (_, Parsed::Unsupported(code))  => assert_eq!(code, "^[[>1c"),
+            _                               => panic!("CSI >c is not supported"),
+        }
+    }
+
+    fn feed(parser: &mut Parser, data: &[u8]) -> Vec<String> {
+        let mut cmds = vec![];
+        parser.feed(data, &mut |cmd| cmds.push(cmd.inner.repr()));
+        cmds
+    }
+
+    #[test]
+    fn split_feeds() {
+        let mut parser = Parser::new();
+        assert_eq!(feed(&mut parser, b"A\xf0\x9f"), vec![String::from("A")]);
+        assert_eq!(feed(&mut parser, b"\x92\xa9\x1b[7"), vec![String::from("\u{1f4a9}")]);
+        assert_eq!(feed(&mut parser, b"A"), vec![String::from("MOVE UP 7")]);
+        assert_eq!(parser.offset(), 8);
+    }
+
+    #[test]
+    fn incomplete_utf8_at_end() {
+        let mut parser = Parser::new();
+        assert_eq!(feed(&mut parser, b"A\xe2\x82"), vec![String::from("A")]);
+        let mut cmds = vec![];
+        parser.finish(&mut |cmd| cmds.push(cmd.inner.repr()));
+        assert_eq!(cmds, vec![String::from("\u{fffd}")]);
+        let mut output = setup(b"A\xf0\x9f");
+        assert_eq!(&output.next().unwrap().unwrap().inner.repr(), "A");
+        match output.next_span().unwrap().unwrap() {
+            (range, Parsed::Command(cmd))   => {
+                assert_eq!((range, cmd.inner.repr()), (1..3, String::from("\u{fffd}")));
+            }
+            _                               => panic!("incomplete UTF-8 was not replaced"),
+        }
+        assert!(output.next().is_none());
+    }
+
+    #[test]
+    fn invalid_utf8() {
+        let mut parser = Parser::new();
+        assert_eq!(feed(&mut parser, b"\x80A\xe2\x82B\xed\xa0\x80"), vec![
+            String::from("\u{fffd}A\u{fffd}B\u{fffd}\u{fffd}\u{fffd}"),
+        ]);
+    }
+
+    #[test]
+    fn untrusted_input() {
+        let mut parser = Parser::new();
+        assert_eq!(feed(&mut parser, b"\x1b[99999999999999999999;0H"), vec![
+            String::from("MOVE TO 0,4294967294"),
+        ]);
+        assert_eq!(feed(&mut parser, b"\x1bPq#0;2;0;0;0\x1b\\A"), vec![String::from("A")]);
+        assert_eq!(feed(&mut parser, b"\x1b]52;c;YQ==\x07"), vec![
+            String::from("NO FEATURE: ^[]52"),
+        ]);
+        assert_eq!(feed(&mut parser, "\x1b_[50\u{9c}\x1b_[15;1;1\u{9c}B".as_bytes()), vec![
+            String::from("B"),
+        ]);
+        assert!(!feed(&mut parser, "\u{1100}\u{1f1e6}".as_bytes()).is_empty());
+    }
+
+    #[test]
+    fn escape_ends_string() {
+        let mut parser = Parser::new();
+        assert_eq!(feed(&mut parser, b"\x1bPq\x1b[2AB"), vec![
+            String::from("MOVE UP 2"),
+            String::from("B"),
+        ]);
+        assert_eq!(feed(&mut parser, b"\x1b^q\x1b[2AB"), vec![
+            String::from("MOVE UP 2"),
+            String::from("B"),
+        ]);
+    }
+
+    #[test]
+    fn overlong_sequences() {
+        let mut parser = Parser::new();
+        let mut data = b"\x1b[".to_vec();
+        data.extend(b"1;".iter().cycle().take(2 * ansi::MAX_ARGS + 2));
+        data.extend(b"AB");
+        assert_eq!(feed(&mut parser, &data), vec![String::from("B")]);
+        let mut data = b"\x1b[".to_vec();
+        data.extend(b"0".iter().cycle().take(ansi::MAX_ARG_LEN + 1));
+        data.extend(b"AB");
+        assert_eq!(feed(&mut parser, &data), vec![String::from("B")]);
+        let mut data = b"\x1b]2;".to_vec();
+        data.extend(b"x".iter().cycle().take(ansi::MAX_ARG_LEN + 1));
+        data.extend(b"\x07B\x1b]2;title\x07");
+        assert_eq!(feed(&mut parser, &data), vec![
+            String::from("B"),
+            String::from("SET TITLE"),
+        ]);
+    }
+
+    #[test]
+    fn printable_runs() {
+        let mut parser = Parser::new();
+        assert_eq!(feed(&mut parser, "ab\u{4e00}\u{4e01}c\u{301}d\ne".as_bytes()), vec![
+            String::from("ab"),
+            String::from("\u{4e00}\u{4e01}"),
+            String::from("c"),
+            String::from("\u{301}"),
+            String::from("d"),
+            String::from("MOVE NEXT LINE 1"),
+            String::from("e"),
+        ]);
+    }
+
+    #[test]
+    fn grapheme_clusters() {
+        let mut parser = Parser::new();
+        let data = "\u{1f468}\u{200d}\u{1f469}\u{1f1e9}\u{1f1ea}\u{263a}\u{fe0f}";
+        assert_eq!(feed(&mut parser, data.as_bytes()), vec![
+            String::from("\u{1f468}"),
+            String::from("\u{200d}"),
+            String::from("\u{1f469}"),
+            String::from("\u{1f1e9}"),
+            String::from("\u{1f1ea}"),
+            String::from("\u{263a}"),
+            String::from("\u{fe0f}"),
+        ]);
+    }
+
+    #[test]
+    fn grapheme_widths() {
+        let mut terminal = Terminal::new(8, 2, io::sink(), TerminalConfig::default());
+        cat(&mut terminal, Some("\u{4e00}\u{301}a\r\n\u{263a}\u{fe0f}b".as_bytes()).into_iter());
+        assert_eq!(terminal.cursor().position(), Coords { x: 3, y: 1 });
+        assert_eq!(terminal.cell_at(Coords { x: 2, y: 0 }).unwrap().content(),
+                   &CellData::Char('a'));
+        assert_eq!(terminal.cell_at(Coords { x: 1, y: 1 }).unwrap().content(),
+                   &CellData::Extension(Coords { x: 0, y: 1 }));
+    }
+
+    #[test]
+    fn ambiguous_width() {
+        for &(policy, x) in &[(WidthPolicy::Narrow, 3), (WidthPolicy::Wide, 5)] {
+            let config = TerminalConfig::default().width_policy(policy);
+            let mut terminal = Terminal::new(8, 2, io::sink(), config);
+            let mut parser = Parser::with_config(&config);
+            let data = "\u{3b1}\u{0}\u{85}\u{ad}\u{2460}a".as_bytes();
+            parser.feed(data, &mut |cmd| cmd.inner.apply(&mut terminal).unwrap());
+            assert_eq!(terminal.cursor().position(), Coords { x: x, y: 0 });
+        }
+        let mut parser = Parser::new();
+        parser.set_config(&TerminalConfig::default().width_policy(WidthPolicy::Wide));
+        assert_eq!(feed(&mut parser, "\u{3b1}".as_bytes()), vec![String::from("\u{3b1}")]);
+        let mut terminal = Terminal::new(8, 2, io::sink(), TerminalConfig::default());
+        parser.feed("\u{3b1}".as_bytes(), &mut |cmd| cmd.inner.apply(&mut terminal).unwrap());
+        assert_eq!(terminal.cursor().position(), Coords { x: 2, y: 0 });
+    }
+
+    #[test]
+    fn legacy_encodings() {
+        let mut parser = Parser::with_config(&TerminalConfig::default().encoding(Encoding::Cp437));
+        assert_eq!(feed(&mut parser, b"\xc9\xcd\xbb\x1b%G\xe2\x94\x80\x1b%@\xb3"), vec![
+            String::from("\u{2554}\u{2550}\u{2557}"),
+            String::from("\u{2500}"),
+            String::from("\u{2502}"),
+        ]);
+        let mut parser = Parser::new();
+        assert_eq!(feed(&mut parser, b"\x1b%@caf\xe9\x1bc\xc3\xa9"), vec![
+            String::from("caf\u{e9}"),
+            String::from("HARD RESET"),
+            String::from("\u{e9}"),
+        ]);
+    }
+
+    #[test]
+    fn line_codes() {
+        let mut parser = Parser::new();
+        assert_eq!(feed(&mut parser, b"\x1b#3\x1b#4\x1b#5\x1b#6\x1b#9"), vec![
+            String::from("SET LINE SIZE DOUBLE HEIGHT TOP"),
+            String::from("SET LINE SIZE DOUBLE HEIGHT BOTTOM"),
+            String::from("SET LINE SIZE NORMAL"),
+            String::from("SET LINE SIZE DOUBLE WIDTH"),
+            String::from("9"),
+        ]);
+        let mut terminal = Terminal::new(4, 2, io::sink(), TerminalConfig::default());
+        cat(&mut terminal, Some(&b"ab\x1b#6\x1b#8"[..]).into_iter());
+        assert_eq!(terminal.cursor().position(), Coords { x: 0, y: 0 });
+        assert_eq!(terminal.line_size_in_view(0), LineSize::Normal);
+        for y in 0..2 {
+            for x in 0..4 {
+                assert_eq!(terminal.cell_at(Coords { x: x, y: y }).unwrap().content(),
+                           &CellData::Char('E'));
+            }
+        }
+    }
+
+    #[cfg(feature = "bench")]
+    fn text() -> Vec<u8> {
+        (0..1000).map(|n| format!("{:>5} The quick brown fox jumps over the lazy dog.\r\n", n))
+                 .collect::<String>().into_bytes()
+    }
+
+    fn cat<'a, I: Iterator<Item=&'a [u8]>>(terminal: &mut Terminal, chunks: I) {
+        let mut parser = Parser::new();
+        for chunk in chunks {
+            parser.feed(chunk, &mut |cmd| cmd.inner.apply(terminal).unwrap());
+        }
+    }
+
+    #[cfg(feature = "bench")]
+    #[bench]
+    fn cat_text(b: &mut Bencher) {
+        let text = text();
+        let mut terminal = Terminal::new(80, 24, io::sink(), TerminalConfig::default());
+        b.iter(|| cat(&mut terminal, text.chunks(4096)));
+    }
+
+    // Applies a command for each printable character, which is how the parser behaved before it
+    // parsed runs of printable characters into one command.
+    #[cfg(feature = "bench")]
+    #[bench]
+    fn cat_text_by_char(b: &mut Bencher) {
+        let text = text();
+        let mut terminal = Terminal::new(80, 24, io::sink(), TerminalConfig::default());
+        b.iter(|| {
+            let mut parser = Parser::new();
+            for &byte in &text {
+                match byte {
+                    b' '...b'~' => Put::new_char(byte as char).apply(&mut terminal).unwrap(),
+                    _           => {
+                        parser.feed(&[byte], &mut |cmd| cmd.inner.apply(&mut terminal).unwrap())
+                    }
+                }
+            }
+        });
+    }
+
     #[test]
     fn osc_code() {
         let mut output = setup(b"A\x1b]0;Hello, world!\x07B");
@@ -15,13 +15,18 @@
 //  along with this program.  If not, see <http://www.gnu.org/licenses/>.
 use base64;
 
+/// The most data all of the attachments to one command may hold, in bytes of base64.
+pub const MAX_LEN: usize = 16 << 20;
+
 pub struct Attachments {
     data: Vec<String>,
+    len: usize,
+    overflowed: bool,
 }
 
 impl Default for Attachments {
     fn default() -> Attachments {
-        Attachments { data: vec![String::new()] }
+        Attachments { data: vec![String::new()], len: 0, overflowed: false }
     }
 }
 
@@ -30,13 +35,29 @@ impl Attachments {
     pub fn clear(&mut self) {
         self.data.truncate(1);
         self.data.last_mut().unwrap().clear();
+        self.len = 0;
+        self.overflowed = false;
     }
 
     pub fn iter(&self) -> AttachmentIter {
         self.into_iter()
     }
 
+    /// Add a character to the attachments. Returns `Some(true)` when the command they are
+    /// attached to ends and `Some(false)` when it is invalid. Attachments which hold more than
+    /// `MAX_LEN` bytes are dropped, and the command is invalid when it ends.
     pub fn append(&mut self, ch: char) -> Option<bool> {
+        if self.len >= MAX_LEN && ch != '\u{9c}' {
+            self.clear();
+            self.overflowed = true;
+        }
+        if self.overflowed {
+            return match ch {
+                '0'...'9' | 'A'...'Z' | 'a'...'z' | '+' | '/' | '=' | '#'   => None,
+                _                                                           => Some(false),
+            }
+        }
+        self.len += 1;
         match ch {
             '0'...'9' | 'A'...'Z' | 'a'...'z' | '+' | '/' | '=' => {
                 self.data.last_mut().unwrap().push(ch);
@@ -114,6 +135,20 @@ mod tests {
         }
     }
 
+    #[test]
+    fn drops_overlong_attachments() {
+        let mut attachments = Attachments::default();
+        for _ in 0..MAX_LEN + 1 {
+            assert_eq!(attachments.append('A'), None);
+        }
+        assert_eq!(attachments.append('#'), None);
+        assert_eq!(attachments.data.len(), 1);
+        assert_eq!(attachments.append('\u{9c}'), Some(false));
+        attachments.clear();
+        assert_eq!(attachments.append('A'), None);
+        assert_eq!(attachments.append('\u{9c}'), Some(true));
+    }
+
     #[test]
     fn wont_append_invalid_chars() {
         let mut attachments = Attachments::default();
@@ -13,7 +13,6 @@
 //  
 //  You should have received a copy of the GNU Affero General Public License
 //  along with this program.  If not, see <http://www.gnu.org/licenses/>.
-use std::cell::RefCell;
 use std::str::FromStr;
 
 use mime::{Mime, SubLevel};
@@ -40,7 +39,10 @@ impl NottyData {
             Some(0x14)  => {
                 let w = match u32::decode(args.next(), None) { Some(w) => w, None => return None };
                 let h = match u32::decode(args.next(), None) { Some(h) => h, None => return None };
-                let p = MediaPosition::decode(args.next(), Some(MediaPosition::default())).unwrap();
+                let p = match MediaPosition::decode(args.next(), Some(MediaPosition::default())) {
+                    Some(p) => p,
+                    None    => return None,
+                };
                 if let Some((mime, data)) = image(self.attachments.iter()) {
                     wrap(Some(Put::new_image(data, mime, p, w, h)))
                 } else { None }
@@ -48,8 +50,14 @@ impl NottyData {
             Some(0x15)  => {
                 let w = match u32::decode(args.next(), None) { Some(w) => w, None => return None };
                 let h = match u32::decode(args.next(), None) { Some(h) => h, None => return None };
-                let p = MediaPosition::decode(args.next(), Some(MediaPosition::default())).unwrap();
-                let coords = Coords::decode(args.next(), Some(Coords {x: 0, y: 0})).unwrap();
+                let p = match MediaPosition::decode(args.next(), Some(MediaPosition::default())) {
+                    Some(p) => p,
+                    None    => return None,
+                };
+                let coords = match Coords::decode(args.next(), Some(Coords {x: 0, y: 0})) {
+                    Some(coords) => coords,
+                    None         => return None,
+                };
                 if let Some((mime, data)) = image(self.attachments.iter()) {
                     wrap(Some(PutAt::new_image(data, mime, p, w, h, coords)))
                 } else { None }
@@ -58,8 +66,14 @@ impl NottyData {
                 wrap(Movement::decode(args.next(), Some(To(Right, 1, true))).map(Move::new))
             }
             Some(0x19)  => {
-                let dir = Direction::decode(args.next(), Some(Down)).unwrap();
-                let n = u32::decode(args.next(), Some(1)).unwrap();
+                let dir = match Direction::decode(args.next(), Some(Down)) {
+                    Some(dir) => dir,
+                    None      => return None,
+                };
+                let n = match u32::decode(args.next(), Some(1)) {
+                    Some(n) => n,
+                    None    => return None,
+                };
                 wrap(Some(ScrollScreen::new(dir, n)))
             }
             Some(0x20)  => {
@@ -69,14 +83,21 @@ impl NottyData {
                 wrap(u32::decode(args.next(), Some(1)).map(RemoveChars::new))
             }
             Some(0x22)  => {
-                let n = u32::decode(args.next(), Some(1)).unwrap();
+                let n = match u32::decode(args.next(), Some(1)) {
+                    Some(n) => n,
+                    None    => return None,
+                };
                 wrap(bool::decode(args.next(), Some(true)).map(|f| RemoveRows::new(n, f)))
             }
+            Some(0x23)  => wrap(Some(ClearScrollback)),
             Some(0x26)  => {
                 wrap(u32::decode(args.next(), Some(1)).map(InsertBlank::new))
             }
             Some(0x27)  => {
-                let n = u32::decode(args.next(), Some(1)).unwrap();
+                let n = match u32::decode(args.next(), Some(1)) {
+                    Some(n) => n,
+                    None    => return None,
+                };
                 wrap(bool::decode(args.next(), Some(true)).map(|f| InsertRows::new(n, f)))
             }
             Some(0x30)  => {
@@ -92,7 +113,10 @@ impl NottyData {
                 }
             }
             Some(0x32)  => {
-                let area = Area::decode(args.next(), Some(WholeScreen)).unwrap();
+                let area = match Area::decode(args.next(), Some(WholeScreen)) {
+                    Some(area) => area,
+                    None       => return None,
+                };
                 match Style::decode(args.next(), None) {
                     Some(style) => wrap(Some(SetStyleInArea(area, style))),
                     None        => wrap(Some(DefaultStyleInArea(area))),
@@ -101,22 +125,28 @@ impl NottyData {
             Some(0x40)  => {
                 self.attachments.iter().next().and_then(|data| String::from_utf8(data).ok())
                 .and_then(|title| {
-                    wrap(Some(SetTitle(RefCell::new(Some(title)))))
+                    wrap(Some(SetTitle(title)))
                 })
             }
             Some(0x50)  => {
-                let coords = Coords::decode(args.next(), None).unwrap();
+                let coords = match Coords::decode(args.next(), None) {
+                    Some(coords) => coords,
+                    None         => return None,
+                };
                 self.attachments.iter().next().and_then(|data| String::from_utf8(data).ok())
                 .and_then(|string| {
-                    wrap(Some(AddToolTip(coords, RefCell::new(Some(String::from(string))))))
+                    wrap(Some(AddToolTip(coords, string)))
                 })
             }
             Some(0x51)  => {
-                let coords = Coords::decode(args.next(), None).unwrap();
+                let coords = match Coords::decode(args.next(), None) {
+                    Some(coords) => coords,
+                    None         => return None,
+                };
                 self.attachments.iter().map(|data| String::from_utf8(data).ok())
                 .collect::<Option<_>>().and_then(|data| wrap(Some(AddDropDown {
                     coords: coords,
-                    options: RefCell::new(Some(data)),
+                    options: data,
                 })))
             }
             Some(0x54)  => wrap(Coords::decode(args.next(), None).map(RemoveToolTip)),
@@ -185,6 +215,6 @@ fn image<I: Iterator<Item=Vec<u8>>>(mut attachments: I) -> Option<(Mime, Vec<u8>
     })
 }
 
-fn wrap<T: CommandTrait>(cmd: Option<T>) -> Option<Command> {
-    cmd.map(|cmd| Command { inner: Box::new(cmd) as Box<CommandTrait> })
+fn wrap<T: Into<CommandKind>>(cmd: Option<T>) -> Option<Command> {
+    cmd.map(|cmd| Command { inner: cmd.into() })
 }
@@ -17,9 +17,11 @@ use std::sync::Arc;
 
 use mime::Mime;
 
-use datatypes::{Coords, MediaPosition};
-use terminal::{UseStyles, DEFAULT_STYLES};
-use terminal::interfaces::{Cell, Styleable, WriteableCell};
+use datatypes::{Color, ConfigStyle, Coords, MediaPosition};
+use datatypes::args::Argument;
+use terminal::{PackedRow, RowReader, RowWriter, Styles, UseStyles, DEFAULT_STYLES, MAX_ROW_WIDTH};
+use terminal::interfaces::{Cell, Pack, Styleable, WriteableCell};
+use terminal::session::{Persist, SessionReader, SessionWriter};
 
 use self::CellData::*;
 
@@ -111,7 +113,65 @@ impl WriteableCell for CharCell {
     }
 }
 
-#[cfg(any(test, debug_assertions))]
+impl Pack for CharCell {
+    /// Cells are packed in runs which share the same styles. Empty cells at the end of the row
+    /// are not packed at all; only the width of the row is recorded.
+    fn pack(cells: &[CharCell]) -> PackedRow {
+        let mut writer = RowWriter::new();
+        let len = cells.iter().rposition(|cell| *cell != EMPTY_CELL).map_or(0, |n| n + 1);
+        writer.write_uint(cells.len() as u64);
+        writer.write_uint(len as u64);
+        let mut start = 0;
+        while start < len {
+            let styles = cells[start].styles;
+            let end = cells[start..len].iter().position(|cell| cell.styles != styles)
+                                       .map_or(len, |n| start + n);
+            writer.write_uint((end - start) as u64);
+            pack_styles(&mut writer, styles);
+            for cell in &cells[start..end] {
+                pack_content(&mut writer, &cell.content);
+            }
+            start = end;
+        }
+        writer.finish()
+    }
+
+    fn unpack(row: &PackedRow) -> Option<Vec<CharCell>> {
+        let mut reader = row.reader();
+        let width = match reader.read_uint() { Some(n) => n as usize, None => return None };
+        let len = match reader.read_uint() { Some(n) => n as usize, None => return None };
+        if len > width || width > MAX_ROW_WIDTH { return None; }
+        let mut cells = Vec::with_capacity(len);
+        while cells.len() < len {
+            let run = match reader.read_uint() { Some(n) => n as usize, None => return None };
+            if run == 0 || run > len - cells.len() { return None; }
+            let styles = match unpack_styles(&mut reader) { Some(s) => s, None => return None };
+            for _ in 0..run {
+                match unpack_content(&mut reader) {
+                    Some(content)   => cells.push(CharCell { styles: styles, content: content }),
+                    None            => return None,
+                }
+            }
+        }
+        cells.resize(width, EMPTY_CELL);
+        if reader.is_empty() { Some(cells) } else { None }
+    }
+
+    fn heap_size(&self) -> usize {
+        match self.content {
+            Grapheme(ref s) => s.capacity(),
+            _               => 0,
+        }
+    }
+
+    fn image(&self) -> Option<&Arc<ImageData>> {
+        match self.content {
+            Image { ref data, .. }  => Some(data),
+            _                       => None,
+        }
+    }
+}
+
 impl CharCell {
     pub fn repr(&self) -> String {
         match self.content {
@@ -146,11 +206,157 @@ pub struct ImageData {
     pub coords: Coords,
 }
 
+impl Persist for UseStyles {
+    fn persist(&self, session: &mut SessionWriter) {
+        pack_styles(session, *self);
+    }
+
+    fn restore(session: &mut SessionReader) -> Option<UseStyles> {
+        unpack_styles(session)
+    }
+}
+
+fn pack_styles(writer: &mut RowWriter, styles: UseStyles) {
+    match styles {
+        UseStyles::Config(style)    => {
+            writer.write_u8(0);
+            writer.write_str(&style.encode());
+        }
+        UseStyles::Custom(styles)   => {
+            writer.write_u8(1);
+            pack_color(writer, styles.fg_color);
+            pack_color(writer, styles.bg_color);
+            writer.write_u8(styles.opacity);
+            writer.write_u8(styles.underline as u8
+                            | (styles.double_underline as u8) << 1
+                            | (styles.bold as u8) << 2
+                            | (styles.italic as u8) << 3
+                            | (styles.strikethrough as u8) << 4
+                            | (styles.inverted as u8) << 5
+                            | (styles.blink as u8) << 6);
+        }
+    }
+}
+
+fn unpack_styles(reader: &mut RowReader) -> Option<UseStyles> {
+    match reader.read_u8() {
+        Some(0) => reader.read_str().and_then(|s| ConfigStyle::decode(Some(s), None))
+                                    .map(UseStyles::Config),
+        Some(1) => {
+            let fg_color = match unpack_color(reader) { Some(c) => c, None => return None };
+            let bg_color = match unpack_color(reader) { Some(c) => c, None => return None };
+            let opacity = match reader.read_u8() { Some(n) => n, None => return None };
+            reader.read_u8().map(|flags| UseStyles::Custom(Styles {
+                fg_color: fg_color,
+                bg_color: bg_color,
+                opacity: opacity,
+                underline: flags & 1 != 0,
+                double_underline: flags & 1 << 1 != 0,
+                bold: flags & 1 << 2 != 0,
+                italic: flags & 1 << 3 != 0,
+                strikethrough: flags & 1 << 4 != 0,
+                inverted: flags & 1 << 5 != 0,
+                blink: flags & 1 << 6 != 0,
+            }))
+        }
+        _       => None,
+    }
+}
+
+fn pack_color(writer: &mut RowWriter, color: Color) {
+    match color {
+        Color::Default          => writer.write_u8(0),
+        Color::Palette(n)       => {
+            writer.write_u8(1);
+            writer.write_u8(n);
+        }
+        Color::True(r, g, b)    => {
+            writer.write_u8(2);
+            writer.write_u8(r);
+            writer.write_u8(g);
+            writer.write_u8(b);
+        }
+    }
+}
+
+fn unpack_color(reader: &mut RowReader) -> Option<Color> {
+    match reader.read_u8() {
+        Some(0) => Some(Color::Default),
+        Some(1) => reader.read_u8().map(Color::Palette),
+        Some(2) => match (reader.read_u8(), reader.read_u8(), reader.read_u8()) {
+            (Some(r), Some(g), Some(b)) => Some(Color::True(r, g, b)),
+            _                           => None,
+        },
+        _       => None,
+    }
+}
+
+fn pack_content(writer: &mut RowWriter, content: &CellData) {
+    match *content {
+        Empty                   => writer.write_u8(0),
+        Char(c)                 => {
+            writer.write_u8(1);
+            writer.write_uint(c as u64);
+        }
+        Grapheme(ref s)         => {
+            writer.write_u8(2);
+            writer.write_str(s);
+        }
+        Extension(coords)       => {
+            writer.write_u8(3);
+            writer.write_uint(coords.x as u64);
+            writer.write_uint(coords.y as u64);
+        }
+        Image { ref data, ref mime, ref pos, width, height } => {
+            writer.write_u8(4);
+            writer.write_image(data);
+            writer.write_str(&mime.to_string());
+            writer.write_str(&pos.encode());
+            writer.write_uint(width as u64);
+            writer.write_uint(height as u64);
+        }
+    }
+}
+
+fn unpack_content(reader: &mut RowReader) -> Option<CellData> {
+    match reader.read_u8() {
+        Some(0) => Some(Empty),
+        Some(1) => reader.read_uint().and_then(|n| char::from_u32(n as u32)).map(Char),
+        Some(2) => reader.read_str().map(|s| Grapheme(String::from(s))),
+        Some(3) => match (reader.read_uint(), reader.read_uint()) {
+            (Some(x), Some(y))  => Some(Extension(Coords { x: x as u32, y: y as u32 })),
+            _                   => None,
+        },
+        Some(4) => {
+            let data = match reader.read_image() { Some(data) => data, None => return None };
+            let mime = match reader.read_str().and_then(|s| s.parse().ok()) {
+                Some(mime)  => mime,
+                None        => return None,
+            };
+            let pos = match reader.read_str().and_then(|s| MediaPosition::decode(Some(s), None)) {
+                Some(pos)   => pos,
+                None        => return None,
+            };
+            match (reader.read_uint(), reader.read_uint()) {
+                (Some(width), Some(height)) => Some(Image {
+                    data: data,
+                    mime: mime,
+                    pos: pos,
+                    width: width as u32,
+                    height: height as u32,
+                }),
+                _                           => None,
+            }
+        }
+        _       => None,
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use datatypes::Coords;
+    use datatypes::{Color, Coords};
     use terminal::interfaces::*;
-    use terminal::UseStyles;
+    use terminal::{RowWriter, Styles, UseStyles};
     use super::*;
 
     fn character() -> CharCell {
@@ -174,6 +380,35 @@ mod tests {
         assert!(extension().is_extension());
     }
 
+    #[test]
+    fn pack_and_unpack() {
+        let mut styles = Styles::new();
+        styles.bold = true;
+        styles.fg_color = Color::True(0x10, 0x20, 0x30);
+        let styles = UseStyles::Custom(styles);
+        let row = vec![
+            character(),
+            CharCell { content: CellData::Grapheme(String::from("e\u{301}")), styles: styles },
+            CharCell { content: CellData::Char('z'), styles: styles },
+            extension(),
+            CharCell::default(),
+            CharCell::default(),
+        ];
+        assert_eq!(CharCell::unpack(&CharCell::pack(&row)), Some(row));
+    }
+
+    #[test]
+    fn unpack_malformed() {
+        let mut writer = RowWriter::new();
+        writer.write_uint(4);
+        writer.write_uint(5);
+        assert_eq!(CharCell::unpack(&writer.finish()), None);
+        let mut writer = RowWriter::new();
+        writer.write_uint(u64::max_value());
+        writer.write_uint(0);
+        assert_eq!(CharCell::unpack(&writer.finish()), None);
+    }
+
     #[test]
     fn erase() {
         let mut cell = character();
@@ -16,6 +16,7 @@
 use datatypes::Coords;
 use terminal::UseStyles;
 use terminal::interfaces::Styleable;
+use terminal::session::{Persist, SessionReader, SessionWriter};
 
 #[derive(Clone, Debug, PartialEq, Eq, Default)]
 pub struct Cursor {
@@ -38,3 +39,15 @@ impl Styleable for Cursor {
         &mut self.styles
     }
 }
+
+impl Persist for Cursor {
+    fn persist(&self, session: &mut SessionWriter) {
+        self.coords.persist(session);
+        self.styles.persist(session);
+    }
+
+    fn restore(session: &mut SessionReader) -> Option<Cursor> {
+        let coords = match Coords::restore(session) { Some(coords) => coords, None => return None };
+        UseStyles::restore(session).map(|styles| Cursor { coords: coords, styles: styles })
+    }
+}
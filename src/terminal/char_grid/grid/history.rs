@@ -0,0 +1,379 @@
+//  notty is a new kind of terminal emulator.
+//  Copyright (C) 2015 without boats
+//  
+//  This program is free software: you can redistribute it and/or modify
+//  it under the terms of the GNU Affero General Public License as published by
+//  the Free Software Foundation, either version 3 of the License, or
+//  (at your option) any later version.
+//  
+//  This program is distributed in the hope that it will be useful,
+//  but WITHOUT ANY WARRANTY; without even the implied warranty of
+//  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+//  GNU Affero General Public License for more details.
+//  
+//  You should have received a copy of the GNU Affero General Public License
+//  along with this program.  If not, see <http://www.gnu.org/licenses/>.
+//! Saving the scrollback of a grid to a file, so that it survives the terminal closing.
+//!
+//! A history file begins with the line `notty scrollback 1`, followed by a record for each row,
+//! oldest first. Integers are LEB128 varints, and byte strings are an integer length followed by
+//! the bytes, exactly as written by a `RowWriter`:
+//!
+//! ```text
+//! record = width:integer  cells:bytes  image-count:integer  image-name:bytes*
+//! ```
+//!
+//! `cells` is the packed encoding of the row. Images are stored by reference: each image in the
+//! row is a separate file in the `images` directory beside the history file, holding the
+//! coordinates of the image as two integers followed by the image data. The file is named by the
+//! 64-bit FNV-1a hash of its contents in hexadecimal; if another image already has that name,
+//! the hash is taken again with the attempt number appended to the contents. An incomplete
+//! record at the end of the file, as left by a crash, is discarded when the file is opened.
+//!
+//! Records are buffered, and written to the file every `FLUSH_ROWS` rows, or on the first
+//! append once `FLUSH_SECS` seconds have passed since the last write. Rows which are no longer
+//! held in memory stay in the file until there are more than `COMPACT_ROWS` of them, and more of
+//! them than of the rows which are held; the file is then rewritten without them. Whenever the
+//! file is rewritten or cleared, the image files which no row in it refers to are removed.
+use std::cmp;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, BufWriter, Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use datatypes::Coords;
+use terminal::ImageData;
+
+use super::packed::{PackedRow, RowReader, RowWriter};
+
+const HEADER: &'static [u8] = b"notty scrollback 1\n";
+
+/// The number of rows which are buffered before they are written to the file.
+const FLUSH_ROWS: usize = 64;
+
+/// The number of seconds for which appended rows are buffered while more rows are appended.
+const FLUSH_SECS: u64 = 1;
+
+/// The number of rows at the front of the file which are no longer held in memory before the
+/// file may be rewritten without them.
+pub const COMPACT_ROWS: usize = 4096;
+
+/// A history file, and the offset within it of each saved row which is still held in memory.
+/// `len` is the length the file has once every buffered record has been written, and
+/// `forgotten` is the number of rows before the first held row.
+pub struct History {
+    file: BufWriter<File>,
+    path: PathBuf,
+    images: PathBuf,
+    len: u64,
+    offsets: VecDeque<u64>,
+    forgotten: usize,
+    synced: Option<u64>,
+    buffered: usize,
+    flushed_at: Instant,
+}
+
+impl History {
+    /// Open the history file at `path`, creating it if it does not exist, and read every row
+    /// which has been saved in it. Every row read counts as saved and held in memory.
+    pub fn open(path: &Path) -> io::Result<(History, Vec<(usize, PackedRow)>)> {
+        let dir = path.parent().unwrap_or(Path::new("."));
+        try!(fs::create_dir_all(dir));
+        let mut file = try!(OpenOptions::new().read(true).write(true).create(true).open(path));
+        let mut data = vec![];
+        try!(file.read_to_end(&mut data));
+        if data.is_empty() {
+            try!(file.write_all(HEADER));
+            data.extend_from_slice(HEADER);
+        } else if !data.starts_with(HEADER) {
+            return Err(invalid_data("not a notty scrollback file"));
+        }
+
+        let images = dir.join("images");
+        let mut loaded = HashMap::new();
+        let mut rows = vec![];
+        let mut offsets = VecDeque::new();
+        let mut len = data.len() as u64;
+        {
+            let mut reader = RowReader::new(&data[HEADER.len()..], &[]);
+            while !reader.is_empty() {
+                let offset = (HEADER.len() + reader.position()) as u64;
+                match try!(read_record(&mut reader, &images, &mut loaded)) {
+                    Some(row)   => {
+                        rows.push(row);
+                        offsets.push_back(offset);
+                    }
+                    None        => {
+                        try!(file.set_len(offset));
+                        len = offset;
+                        break;
+                    }
+                }
+            }
+        }
+
+        try!(file.seek(SeekFrom::Start(len)));
+        let history = History {
+            file: BufWriter::new(file),
+            path: path.to_path_buf(),
+            images: images,
+            len: len,
+            offsets: offsets,
+            forgotten: 0,
+            synced: None,
+            buffered: 0,
+            flushed_at: Instant::now(),
+        };
+        Ok((history, rows))
+    }
+
+    /// The number of rows held in memory which have been saved.
+    pub fn saved(&self) -> usize {
+        self.offsets.len()
+    }
+
+    /// Save a row at the end of the file. The row is buffered, and written along with the rows
+    /// which follow it.
+    pub fn append(&mut self, width: usize, row: &PackedRow) -> io::Result<()> {
+        try!(self.unsync());
+        let record = try!(self.record(width, row));
+        try!(self.file.write_all(&record));
+        self.offsets.push_back(self.len);
+        self.len += record.len() as u64;
+        self.buffered += 1;
+        let interval = Duration::from_secs(FLUSH_SECS);
+        if self.buffered >= FLUSH_ROWS || self.flushed_at.elapsed() >= interval {
+            try!(self.flush());
+        }
+        Ok(())
+    }
+
+    /// Write every buffered row to the file.
+    pub fn flush(&mut self) -> io::Result<()> {
+        try!(self.file.flush());
+        self.buffered = 0;
+        self.flushed_at = Instant::now();
+        Ok(())
+    }
+
+    /// The first `n` saved rows are no longer held in memory. They stay in the file until it is
+    /// next compacted.
+    pub fn forget_front(&mut self, n: usize) -> io::Result<()> {
+        let n = cmp::min(n, self.offsets.len());
+        self.offsets.drain(..n);
+        self.forgotten += n;
+        if self.forgotten > COMPACT_ROWS && self.forgotten > self.offsets.len() {
+            self.compact()
+        } else { Ok(()) }
+    }
+
+    /// Rewrite the file without the rows which are no longer held in memory, and remove the
+    /// images which only they referred to.
+    fn compact(&mut self) -> io::Result<()> {
+        try!(self.unsync());
+        try!(self.flush());
+        let start = self.offsets.front().cloned().unwrap_or(self.len);
+        let mut records = vec![];
+        try!(self.file.get_mut().seek(SeekFrom::Start(start)));
+        try!(self.file.get_mut().read_to_end(&mut records));
+        let temp = self.path.with_extension("compact");
+        {
+            let mut file = try!(File::create(&temp));
+            try!(file.write_all(HEADER));
+            try!(file.write_all(&records));
+            try!(file.sync_data());
+        }
+        try!(fs::rename(&temp, &self.path));
+        let mut file = try!(OpenOptions::new().read(true).write(true).open(&self.path));
+        let len = (HEADER.len() + records.len()) as u64;
+        try!(file.seek(SeekFrom::Start(len)));
+        self.file = BufWriter::new(file);
+        let removed = start - HEADER.len() as u64;
+        for offset in &mut self.offsets {
+            *offset -= removed;
+        }
+        self.len = len;
+        self.forgotten = 0;
+        self.remove_unused_images(&records)
+    }
+
+    /// Remove the saved rows from the `n`th row held in memory onward from the file.
+    pub fn truncate(&mut self, n: usize) -> io::Result<()> {
+        try!(self.unsync());
+        match self.offsets.get(n).cloned() {
+            Some(offset)    => {
+                self.offsets.truncate(n);
+                self.set_len(offset)
+            }
+            None            => Ok(())
+        }
+    }
+
+    /// Remove every row from the file, and every image.
+    pub fn clear(&mut self) -> io::Result<()> {
+        self.synced = None;
+        self.offsets.clear();
+        self.forgotten = 0;
+        try!(self.set_len(HEADER.len() as u64));
+        self.remove_unused_images(&[])
+    }
+
+    /// Save rows which are not held in cold storage, and flush the file to disk. These rows
+    /// are removed from the file again before it is next changed.
+    pub fn sync(&mut self, width: usize, rows: &[PackedRow]) -> io::Result<()> {
+        try!(self.unsync());
+        let start = self.len;
+        for row in rows {
+            let record = try!(self.record(width, row));
+            try!(self.file.write_all(&record));
+            self.len += record.len() as u64;
+        }
+        self.synced = Some(start);
+        try!(self.flush());
+        self.file.get_ref().sync_data()
+    }
+
+    fn unsync(&mut self) -> io::Result<()> {
+        match self.synced.take() {
+            Some(offset)    => self.set_len(offset),
+            None            => Ok(()),
+        }
+    }
+
+    fn set_len(&mut self, len: u64) -> io::Result<()> {
+        try!(self.flush());
+        try!(self.file.get_ref().set_len(len));
+        try!(self.file.seek(SeekFrom::Start(len)));
+        self.len = len;
+        Ok(())
+    }
+
+    /// Remove every image file which none of `records` refers to.
+    fn remove_unused_images(&self, records: &[u8]) -> io::Result<()> {
+        let mut used = HashSet::new();
+        let mut reader = RowReader::new(records, &[]);
+        while let Some(names) = read_image_names(&mut reader) {
+            used.extend(names);
+        }
+        let entries = match fs::read_dir(&self.images) {
+            Ok(entries)                                             => entries,
+            Err(ref err) if err.kind() == io::ErrorKind::NotFound   => return Ok(()),
+            Err(err)                                                => return Err(err),
+        };
+        for entry in entries {
+            let entry = try!(entry);
+            let unused = entry.file_name().to_str().map_or(false, |name| {
+                is_image_name(name) && !used.contains(name)
+            });
+            if unused { try!(fs::remove_file(entry.path())); }
+        }
+        Ok(())
+    }
+
+    fn record(&self, width: usize, row: &PackedRow) -> io::Result<Vec<u8>> {
+        let mut writer = RowWriter::new();
+        writer.write_uint(width as u64);
+        writer.write_bytes(row.bytes());
+        writer.write_uint(row.images().len() as u64);
+        for image in row.images() {
+            let name = try!(self.save_image(image));
+            writer.write_str(&name);
+        }
+        Ok(writer.into_bytes())
+    }
+
+    fn save_image(&self, image: &ImageData) -> io::Result<String> {
+        let mut writer = RowWriter::new();
+        writer.write_uint(image.coords.x as u64);
+        writer.write_uint(image.coords.y as u64);
+        let mut data = writer.into_bytes();
+        data.extend_from_slice(&image.data);
+        try!(fs::create_dir_all(&self.images));
+        let mut attempt = 0u64;
+        loop {
+            let mut hash = fnv1a(FNV_OFFSET, &data);
+            if attempt > 0 { hash = fnv1a(hash, attempt.to_string().as_bytes()); }
+            let name = format!("{:016x}", hash);
+            let path = self.images.join(&name);
+            match OpenOptions::new().write(true).create_new(true).open(&path) {
+                Ok(mut file)    => {
+                    try!(file.write_all(&data));
+                    return Ok(name);
+                }
+                Err(ref err) if err.kind() == io::ErrorKind::AlreadyExists => {
+                    let mut saved = vec![];
+                    try!(try!(File::open(&path)).read_to_end(&mut saved));
+                    if saved == data { return Ok(name); }
+                    attempt += 1;
+                }
+                Err(err)        => return Err(err),
+            }
+        }
+    }
+}
+
+/// Read one record, returning `None` if the rest of the file does not hold a complete record.
+fn read_record(reader: &mut RowReader, images: &Path,
+               loaded: &mut HashMap<String, Arc<ImageData>>)
+        -> io::Result<Option<(usize, PackedRow)>> {
+    let width = match reader.read_uint() { Some(n) => n as usize, None => return Ok(None) };
+    let bytes = match reader.read_bytes() { Some(bytes) => bytes, None => return Ok(None) };
+    let count = match reader.read_uint() { Some(n) => n as usize, None => return Ok(None) };
+    let mut row_images = vec![];
+    for _ in 0..count {
+        let name = match reader.read_str() { Some(name) => name, None => return Ok(None) };
+        row_images.push(try!(load_image(images, name, loaded)));
+    }
+    Ok(Some((width, PackedRow::from_parts(bytes.to_vec(), row_images))))
+}
+
+/// Read the names of the images which one record refers to, returning `None` if the rest of the
+/// file does not hold a complete record.
+fn read_image_names<'a>(reader: &mut RowReader<'a>) -> Option<Vec<&'a str>> {
+    if reader.is_empty() { return None; }
+    let count = match (reader.read_uint(), reader.read_bytes(), reader.read_uint()) {
+        (Some(_), Some(_), Some(count)) => count,
+        _                               => return None,
+    };
+    (0..count).map(|_| reader.read_str()).collect()
+}
+
+fn is_image_name(name: &str) -> bool {
+    !name.is_empty() && name.chars().all(|c| c.is_digit(16))
+}
+
+fn load_image(images: &Path, name: &str, loaded: &mut HashMap<String, Arc<ImageData>>)
+        -> io::Result<Arc<ImageData>> {
+    if let Some(image) = loaded.get(name) {
+        return Ok(image.clone());
+    }
+    if !is_image_name(name) {
+        return Err(invalid_data("invalid image name in scrollback file"));
+    }
+    let mut data = vec![];
+    try!(try!(File::open(images.join(name))).read_to_end(&mut data));
+    let (coords, start) = {
+        let mut reader = RowReader::new(&data, &[]);
+        match (reader.read_uint(), reader.read_uint()) {
+            (Some(x), Some(y))  => (Coords { x: x as u32, y: y as u32 }, reader.position()),
+            _                   => return Err(invalid_data("invalid image in scrollback file")),
+        }
+    };
+    let image = Arc::new(ImageData { data: data.split_off(start), coords: coords });
+    loaded.insert(String::from(name), image.clone());
+    Ok(image)
+}
+
+const FNV_OFFSET: u64 = 0xcbf29ce484222325;
+const FNV_PRIME: u64 = 0x100000001b3;
+
+/// Continue the 64-bit FNV-1a hash `hash` over `bytes`.
+fn fnv1a(hash: u64, bytes: &[u8]) -> u64 {
+    bytes.iter().fold(hash, |hash, &byte| (hash ^ byte as u64).wrapping_mul(FNV_PRIME))
+}
+
+fn invalid_data(msg: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, msg)
+}
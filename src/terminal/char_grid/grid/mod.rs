@@ -13,54 +13,126 @@
 //  
 //  You should have received a copy of the GNU Affero General Public License
 //  along with this program.  If not, see <http://www.gnu.org/licenses/>.
+use std::borrow::Cow;
+use std::cell::{OnceCell, RefCell};
 use std::cmp;
-use std::collections::VecDeque;
+use std::collections::{HashSet, VecDeque};
+use std::collections::vec_deque;
+use std::io;
+use std::iter;
 use std::mem;
-use std::sync::atomic::Ordering::Relaxed;
+use std::path::Path;
+use std::slice;
 
-use cfg::SCROLLBACK;
-use datatypes::{Coords, Direction, Region, GridSettings};
-use terminal::interfaces::{ConstructGrid, CellGrid, Resizeable, WriteableCell, WriteableGrid, Cell};
+use datatypes::{Coords, Direction, LineSize, Region, GridSettings, ScrollbackPolicy};
+use terminal::CellData;
+use terminal::interfaces::{ConstructGrid, CellGrid, Resizeable, WriteableCell, WriteableGrid, Cell,
+                           Pack};
+use terminal::session::{Persist, SessionReader, SessionWriter};
 
+mod history;
+mod packed;
+mod usage;
+
+pub use self::packed::{PackedRow, RowReader, RowWriter, MAX_ROW_WIDTH};
+
+use self::history::History;
+use self::usage::{RowUsage, Usage};
+
+/// The number of offscreen rows which a grid that retains offscreen state keeps unpacked. Rows
+/// further offscreen than this are packed into cold storage.
+pub const HOT_ROWS: usize = 1024;
+
+const E_PACKED: &'static str = "Rows packed by a grid must unpack.";
+
+/// A grid of cells, stored as a deque of rows so that scrolling vertically only moves rows
+/// rather than cells. Every row is `width` cells long. The oldest rows of the grid may be held
+/// in cold storage as `PackedRow`s; they are decoded when their cells are read, and unpacked for
+/// good when they are mutated.
 pub struct Grid<T> {
     pub width: usize,
     pub height: usize,
-    data: VecDeque<T>,
+    cold: VecDeque<ColdRow<T>>,
+    /// The rows in cold storage whose cells have been decoded since the grid last changed.
+    decoded: RefCell<Vec<usize>>,
+    data: VecDeque<Vec<T>>,
+    lines: VecDeque<LineAttrs>,
+    hot_rows: Option<usize>,
+    history: Option<History>,
+    history_error: Option<io::Error>,
+    /// The memory used by the offscreen rows, which is only counted under a byte limit.
+    usage: Usage,
     rem_x: Option<usize>,
     rem_y: Option<usize>,
+    view_height: usize,
+    retain_offscreen_state: bool,
+    scrollback: ScrollbackPolicy,
+}
+
+/// A row in cold storage. Its cells are decoded the first time one of them is borrowed, and the
+/// decoded cells are dropped again the next time the grid scrolls or grows.
+struct ColdRow<T> {
+    packed: PackedRow,
+    cells: OnceCell<Box<[T]>>,
+}
+
+impl<T> ColdRow<T> {
+    fn new(packed: PackedRow) -> ColdRow<T> {
+        ColdRow { packed: packed, cells: OnceCell::new() }
+    }
+
+    /// The approximate amount of memory used by this row while its cells are not decoded.
+    fn size(&self) -> usize {
+        mem::size_of::<OnceCell<Box<[T]>>>() + self.packed.size()
+    }
 }
 
-impl<T> ConstructGrid for Grid<T> {
+/// The attributes of a whole row of a grid, which are kept for every row, including the rows in
+/// cold storage.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+struct LineAttrs {
+    /// Whether text written at the end of this row continued onto the row below it.
+    wrapped: bool,
+    size: LineSize,
+}
+
+impl<T: Pack> ConstructGrid for Grid<T> {
     fn new(settings: GridSettings) -> Grid<T> {
-        match (settings.retain_offscreen_state, SCROLLBACK.load(Relaxed)) {
-            (false, _)          => Grid::with_caps(settings.width as usize, settings.height as usize),
-            (_, n) if n > 0     => Grid::with_caps(cmp::min(n as usize, settings.width as usize),
-                                                   cmp::min(n as usize, settings.height as usize)),
-            _                   => Grid::with_infinite_scroll(),
+        if settings.retain_offscreen_state {
+            Grid::new(None, settings.height as usize, settings.scrollback)
+        } else {
+            Grid::with_caps(settings.width as usize, settings.height as usize)
         }
     }
 }
 
-impl<T: Cell + Default> CellGrid for Grid<T> {
+impl<T: Cell + WriteableCell + Default + Pack> CellGrid for Grid<T> {
     type Cell = T;
 
     fn get(&self, coords: Coords) -> Option<&T> {
-        self.bounds().and_then(move |bounds| if bounds.contains(coords) { 
-            Some(&self.data[linearize(self.width, coords)])
-        } else { None })
+        Grid::get(self, coords)
     }
 
     fn get_mut(&mut self, coords: Coords) -> Option<&mut T> {
-        self.bounds().and_then(move |bounds| if bounds.contains(coords) { 
-            Some(&mut self.data[linearize(self.width, coords)])
-        } else { None })
+        Grid::get_mut(self, coords)
     }
 
-    fn moveover(&mut self, from: Coords, to: Coords) {
-        if let Some(from) = self.get_mut(from).map(|cell| mem::replace(cell, T::default())) {
-            self.fill_to(to);
-            *self.get_mut(to).unwrap() = from;
-        }
+    fn insert_blank_at(&mut self, coords: Coords, n: u32, right: u32) {
+        Grid::insert_blank_at(self, coords, n, right);
+        self.move_sources(coords, right, |x| x + n);
+    }
+
+    fn remove_at(&mut self, coords: Coords, n: u32, right: u32) {
+        Grid::remove_at(self, coords, n, right);
+        self.move_sources(coords, right, |x| x.saturating_sub(n));
+    }
+
+    fn insert_rows_at(&mut self, y: u32, n: u32, bottom: u32) {
+        Grid::insert_rows_at(self, y, n, bottom)
+    }
+
+    fn remove_rows_at(&mut self, y: u32, n: u32, bottom: u32) {
+        Grid::remove_rows_at(self, y, n, bottom)
     }
 
     fn move_out_of_extension(&self, mut coords: Coords, direction: Direction) -> Coords {
@@ -84,9 +156,49 @@ impl<T: Cell + Default> CellGrid for Grid<T> {
             }
         }
     }
+
+    fn set_wrapped(&mut self, y: u32, wrapped: bool) {
+        if let Some(line) = self.lines.get_mut(y as usize) {
+            line.wrapped = wrapped;
+        }
+    }
+
+    fn is_wrapped(&self, y: u32) -> bool {
+        self.lines.get(y as usize).map_or(false, |line| line.wrapped)
+    }
+
+    fn set_line_size(&mut self, y: u32, size: LineSize) {
+        if let Some(line) = self.lines.get_mut(y as usize) {
+            line.size = size;
+        }
+    }
+
+    fn line_size(&self, y: u32) -> LineSize {
+        self.lines.get(y as usize).map_or(LineSize::Normal, |line| line.size)
+    }
+}
+
+impl<T: Cell + WriteableCell + Default + Pack> Grid<T> {
+    /// Point the extension cells in row `coords.y`, from column `coords.x` up to `right`, at the
+    /// cells they extend after those cells have moved along the row. Extension cells which
+    /// extend a cell before `coords.x` are left alone, since that cell has not moved.
+    fn move_sources<F: Fn(u32) -> u32>(&mut self, coords: Coords, right: u32, moved: F) {
+        for x in coords.x..cmp::min(right, self.width as u32) {
+            if let Some(cell) = self.get_mut(Coords { x: x, y: coords.y }) {
+                match cell.source() {
+                    Some(source) if source.x >= coords.x => {
+                        let styles = *cell.styles();
+                        let source = Coords { x: moved(source.x), ..source };
+                        cell.write(CellData::Extension(source), styles);
+                    }
+                    _                                   => { }
+                }
+            }
+        }
+    }
 }
 
-impl<T> WriteableGrid for Grid<T> where T: WriteableCell + Default {
+impl<T> WriteableGrid for Grid<T> where T: WriteableCell + Default + Pack {
     type Cell = T;
 
     fn writeable(&mut self, coords: Coords) -> Option<&mut Self::Cell> {
@@ -109,7 +221,7 @@ impl<T> WriteableGrid for Grid<T> where T: WriteableCell + Default {
     }
 }
 
-impl<T> Resizeable for Grid<T> {
+impl<T: Pack> Resizeable for Grid<T> {
     fn dims(&self) -> (u32, u32) {
         (self.width as u32, self.height as u32)
     }
@@ -122,30 +234,117 @@ impl<T> Resizeable for Grid<T> {
     }
 
     fn resize_height(&mut self, height: u32) {
-        let new_rem = (height as usize).saturating_sub(self.height);
-        if let Some(ref mut rem) = self.rem_y {
-            *rem = cmp::max(*rem, new_rem)
-        }
+        self.view_height = height as usize;
+        self.reset_rem_y();
+        self.count_offscreen();
     }
 }
 
-impl<T> Grid<T> {
+impl<T: Pack> Grid<T> {
     fn with_caps(max_x: usize, max_y: usize) -> Grid<T> {
-        Grid::new(Some(max_x), Some(max_y))
-    }
-
-    fn with_infinite_scroll() -> Grid<T> {
-        Grid::new(None, None)
+        let mut grid = Grid::new(Some(max_x), max_y, ScrollbackPolicy::MaxRows(0));
+        grid.retain_offscreen_state = false;
+        grid.hot_rows = None;
+        grid
     }
 
-    fn new(max_x: Option<usize>, max_y: Option<usize>)
-            -> Grid<T> {
-        Grid {
+    fn new(max_x: Option<usize>, view_height: usize, scrollback: ScrollbackPolicy) -> Grid<T> {
+        let mut grid = Grid {
             width: 0,
             height: 0,
+            cold: VecDeque::new(),
+            decoded: RefCell::new(Vec::new()),
             data: VecDeque::new(),
+            lines: VecDeque::new(),
+            hot_rows: Some(HOT_ROWS),
+            history: None,
+            history_error: None,
+            usage: Usage::default(),
             rem_x: max_x,
-            rem_y: max_y,
+            rem_y: None,
+            view_height: view_height,
+            retain_offscreen_state: true,
+            scrollback: scrollback,
+        };
+        grid.reset_rem_y();
+        grid
+    }
+
+    pub fn scrollback_policy(&self) -> ScrollbackPolicy {
+        self.scrollback
+    }
+
+    /// Bring the count of the memory used by the offscreen rows up to date after rows have
+    /// moved onscreen or offscreen. Nothing is counted unless the scrollback has a byte limit.
+    fn count_offscreen(&mut self) {
+        match self.scrollback {
+            ScrollbackPolicy::MaxBytes(_) if self.retain_offscreen_state => {
+                let offscreen = self.height.saturating_sub(self.view_height);
+                while self.usage.len() > offscreen {
+                    self.usage.pop_back();
+                }
+                while self.usage.len() < offscreen {
+                    let usage = self.row_usage(self.usage.len());
+                    self.usage.push_back(usage);
+                }
+            }
+            _                                                           => self.usage.clear(),
+        }
+    }
+
+    /// Count the memory used by the rows from `top` up to `bottom` again, after they have been
+    /// changed, packed or unpacked.
+    fn recount(&mut self, top: usize, bottom: usize) {
+        for y in top..cmp::min(bottom, self.usage.len()) {
+            let usage = self.row_usage(y);
+            self.usage.replace(y, usage);
+        }
+    }
+
+    /// Change how many offscreen rows are kept unpacked before they are moved into cold storage.
+    /// `None` means that rows are never packed.
+    pub fn set_hot_rows(&mut self, hot_rows: Option<usize>) {
+        self.hot_rows = hot_rows;
+    }
+
+    /// The approximate amount of memory used by the rows of this grid, in bytes, including the
+    /// memory which the cells own, such as the text of graphemes and the data of images. The
+    /// data of an image is counted once, however many cells hold it.
+    pub fn memory_usage(&self) -> usize {
+        let mut images = HashSet::new();
+        (0..self.height).map(|y| {
+            let usage = self.row_usage(y);
+            usage.bytes + usage.images.into_iter().filter(|&(image, _)| images.insert(image))
+                                                  .map(|(_, len)| len).sum::<usize>()
+        }).sum()
+    }
+
+    /// The approximate amount of memory used by row `y`, whether it is packed or not, and the
+    /// images it holds.
+    fn row_usage(&self, y: usize) -> RowUsage {
+        let mut usage = RowUsage::default();
+        if y < self.cold.len() {
+            let row = &self.cold[y];
+            usage.bytes = row.size();
+            for image in row.packed.images() { usage.add_image(image); }
+        } else {
+            let row = &self.data[y - self.cold.len()];
+            usage.bytes = mem::size_of::<Vec<T>>() + row.len() * mem::size_of::<T>()
+                + row.iter().map(T::heap_size).sum::<usize>();
+            for image in row.iter().filter_map(T::image) { usage.add_image(image); }
+        }
+        usage
+    }
+
+    /// Apply a change to the history file of this grid, if it has one. If the change fails, the
+    /// grid stops saving its history, and the error is reported by the next `sync_history`.
+    fn update_history<F>(&mut self, f: F)
+            where F: FnOnce(&mut History, &Self) -> io::Result<()> {
+        if let Some(mut history) = self.history.take() {
+            match f(&mut history, self) {
+                Ok(())      => self.history = Some(history),
+                Err(err)    => self.history_error = Some(err),
+            }
         }
     }
 
@@ -157,50 +356,174 @@ impl<T> Grid<T> {
         self.rem_y.map(|y| y + self.height)
     }
 
+    /// The number of rows this grid may hold under its scrollback policy. A byte limit does not
+    /// limit the number of rows; instead the oldest rows are evicted as rows are scrolled off
+    /// while the offscreen rows use more memory than it allows.
+    fn max_rows(&self) -> Option<usize> {
+        match self.scrollback {
+            ScrollbackPolicy::Unlimited | ScrollbackPolicy::MaxBytes(_) => None,
+            ScrollbackPolicy::MaxRows(n)    => Some(self.view_height.saturating_add(n)),
+        }
+    }
+
+    fn reset_rem_y(&mut self) {
+        let height = self.height;
+        self.rem_y = self.max_rows().map(|max| max.saturating_sub(height));
+    }
+
     fn bounds(&self) -> Option<Region> {
         if self.width > 0 && self.height > 0 {
             Some(Region::new(0, 0, self.width as u32, self.height as u32))
         } else { None }
     }
 
+    fn hot_height(&self) -> usize {
+        self.data.len()
+    }
+}
+
+impl<T: Default + Pack> Grid<T> {
+    /// The cell at `coords`. If it is in cold storage, its row is decoded and kept decoded until
+    /// the grid next scrolls or grows.
     fn get(&self, coords: Coords) -> Option<&T> {
-        self.bounds().and_then(move |bounds| if bounds.contains(coords) { 
-            Some(&self.data[linearize(self.width, coords)])
+        self.bounds().and_then(move |bounds| if bounds.contains(coords) {
+            let (x, y) = (coords.x as usize, coords.y as usize);
+            if y < self.cold.len() {
+                Some(&self.decode(y)[x])
+            } else {
+                Some(&self.data[y - self.cold.len()][x])
+            }
         } else { None })
     }
 
+    /// The cells of row `y`, which must be in cold storage.
+    fn decode(&self, y: usize) -> &[T] {
+        let row = &self.cold[y];
+        row.cells.get_or_init(|| {
+            self.decoded.borrow_mut().push(y);
+            self.unpack(&row.packed).into_boxed_slice()
+        })
+    }
+
+    /// Drop the cells of every row in cold storage which have been decoded.
+    fn forget_decoded(&mut self) {
+        for y in self.decoded.get_mut().drain(..) {
+            if let Some(row) = self.cold.get_mut(y) {
+                row.cells.take();
+            }
+        }
+    }
+
+    /// Row `y` of this grid. A row in cold storage is decoded into a new row, unless its cells
+    /// have already been decoded.
+    pub fn row(&self, y: usize) -> Option<Cow<[T]>> where T: Clone {
+        if y < self.cold.len() {
+            let row = &self.cold[y];
+            Some(row.cells.get().map_or_else(|| Cow::Owned(self.unpack(&row.packed)),
+                                              |cells| Cow::Borrowed(&cells[..])))
+        } else {
+            self.data.get(y - self.cold.len()).map(|row| Cow::Borrowed(&row[..]))
+        }
+    }
+
+    /// Iterate over the rows of this grid, decoding the rows in cold storage as they are reached.
+    pub fn rows(&self) -> Rows<T> {
+        Rows { grid: self, y: 0 }
+    }
+
     fn get_mut(&mut self, coords: Coords) -> Option<&mut T> {
-        self.bounds().and_then(move |bounds| if bounds.contains(coords) { 
-            Some(&mut self.data[linearize(self.width, coords)])
-        } else { None })
+        match self.bounds() {
+            Some(bounds) if bounds.contains(coords) => {
+                self.thaw_from(coords.y as usize);
+                let y = coords.y as usize - self.cold.len();
+                Some(&mut self.data[y][coords.x as usize])
+            }
+            _                                       => None,
+        }
+    }
+
+    fn insert_blank_at(&mut self, Coords { x, y }: Coords, n: u32, right: u32) {
+        let (x, right) = (x as usize, cmp::min(right as usize, self.width));
+        if x >= right || y as usize >= self.height { return; }
+        let n = cmp::min(n as usize, right - x);
+        let cells = &mut self.row_mut(y as usize)[x..right];
+        cells.rotate_right(n);
+        for cell in &mut cells[..n] { *cell = T::default(); }
+        self.recount(y as usize, y as usize + 1);
+    }
+
+    fn remove_at(&mut self, Coords { x, y }: Coords, n: u32, right: u32) {
+        let (x, right) = (x as usize, cmp::min(right as usize, self.width));
+        if x >= right || y as usize >= self.height { return; }
+        let n = cmp::min(n as usize, right - x);
+        let cells = &mut self.row_mut(y as usize)[x..right];
+        cells.rotate_left(n);
+        let len = cells.len();
+        for cell in &mut cells[len - n..] { *cell = T::default(); }
+        self.recount(y as usize, y as usize + 1);
+    }
+
+    fn insert_rows_at(&mut self, y: u32, n: u32, bottom: u32) {
+        let (y, bottom) = (y as usize, cmp::min(bottom as usize, self.height));
+        if y >= bottom { return; }
+        self.thaw_from(y);
+        let cold = self.cold.len();
+        for _ in 0..cmp::min(n as usize, bottom - y) {
+            self.data.remove(bottom - 1 - cold);
+            self.lines.remove(bottom - 1);
+            self.data.insert(y - cold, blank_row(self.width));
+            self.lines.insert(y, LineAttrs::default());
+        }
+        self.recount(y, bottom);
+    }
+
+    fn remove_rows_at(&mut self, y: u32, n: u32, bottom: u32) {
+        let (y, bottom) = (y as usize, cmp::min(bottom as usize, self.height));
+        if y >= bottom { return; }
+        self.thaw_from(y);
+        let cold = self.cold.len();
+        for _ in 0..cmp::min(n as usize, bottom - y) {
+            self.data.remove(y - cold);
+            self.lines.remove(y);
+            self.data.insert(bottom - 1 - cold, blank_row(self.width));
+            self.lines.insert(bottom - 1, LineAttrs::default());
+        }
+        self.recount(y, bottom);
+    }
+
+    /// Row `y`, unpacked out of cold storage so that it can be mutated.
+    fn row_mut(&mut self, y: usize) -> &mut Vec<T> {
+        self.thaw_from(y);
+        &mut self.data[y - self.cold.len()]
     }
-}
 
-impl<T: Default> Grid<T> {
-    pub fn scroll(&mut self, direction: Direction, n: u32) {
+    /// Scroll the cells of this grid `n` cells in `direction`, growing the grid if it may
+    /// grow. Returns the number of the oldest rows which were then discarded to keep the
+    /// scrollback within a byte limit; every remaining row has moved up by that many rows.
+    pub fn scroll(&mut self, direction: Direction, n: u32) -> usize {
         let n = n as usize;
         use datatypes::Direction::*;
         match direction {
             Up if self.rem_y != Some(0)     => self.extend_up(n),
-            Up if n >= self.height          => self.data.clear(),
+            Up if n >= self.height          => self.blank(),
             Up                              => self.shift_up(n),
             Down if self.rem_y != Some(0)   => self.extend_down(n),
-            Down if n >= self.height        => self.data.clear(),
+            Down if n >= self.height        => self.blank(),
             Down                            => self.shift_down(n),
             Left if self.rem_x != Some(0)   => self.extend_left(n),
-            Left if n >= self.width         => self.data.clear(),
+            Left if n >= self.width         => self.blank(),
             Left                            => self.shift_left(n),
             Right if self.rem_x != Some(0)  => self.extend_right(n),
-            Right if n >= self.width        => self.data.clear(),
+            Right if n >= self.width        => self.blank(),
             Right                           => self.shift_right(n),
         }
+        self.freeze();
+        self.evict()
     }
 
     fn extend_up(&mut self, n: usize) {
         let rem_or_n = self.rem_y.map_or(n, |y| cmp::min(y, n));
-        for _ in 0..(rem_or_n * self.width) {
-            self.data.push_front(T::default());
-        }
+        self.push_front_rows(rem_or_n);
         self.height += rem_or_n;
         if self.rem_y.map_or(false, |y| n > y) {
             let rem = n - self.rem_y.unwrap();
@@ -210,14 +533,13 @@ impl<T: Default> Grid<T> {
         if self.height > 0 && self.width == 0 {
             self.width = 1;
             self.rem_x.as_mut().map(|x| *x -= 1);
+            for row in &mut self.data { row.push(T::default()); }
         }
     }
 
     fn extend_down(&mut self, n: usize) {
         let rem_or_n = self.rem_y.map_or(n, |y| cmp::min(y, n));
-        for _ in 0..(rem_or_n * self.width) {
-            self.data.push_back(T::default());
-        }
+        self.push_back_rows(rem_or_n);
         self.height += rem_or_n;
         if self.rem_y.map_or(false, |y| n > y) {
             let rem = n - self.rem_y.unwrap();
@@ -227,86 +549,214 @@ impl<T: Default> Grid<T> {
         if self.height > 0 && self.width == 0 {
             self.width = 1;
             self.rem_x.as_mut().map(|x| *x -= 1);
+            for row in &mut self.data { row.push(T::default()); }
         }
     }
 
     fn extend_left(&mut self, n: usize) {
+        self.thaw_from(0);
+        self.usage.clear();
         let rem_or_n = self.rem_x.map_or(n, |x| cmp::min(x, n));
-        for i in 0..rem_or_n {
-            for j in (1..self.height).rev() {
-                self.data.insert((self.width + i) * j, T::default());
-            }
-            self.data.push_front(T::default());
+        for row in &mut self.data {
+            let old = mem::replace(row, blank_row(rem_or_n));
+            row.extend(old);
         }
         self.width += rem_or_n;
+        if self.width > 0 && self.height == 0 {
+            self.data.push_back(blank_row(self.width));
+            self.lines.push_back(LineAttrs::default());
+            self.height = 1;
+        }
         if self.rem_x.map_or(false, |x| n > x) {
             let rem = n - self.rem_x.unwrap();
             self.shift_left(rem);
         }
         self.rem_x = self.rem_x.map(|x| x.saturating_sub(n));
-        if self.width > 0 && self.height == 0 {
-            self.height = 1;
-            self.rem_y.as_mut().map(|y| *y -= 1);
-        }
+        self.reset_rem_y();
     }
 
     fn extend_right(&mut self, n: usize) {
+        self.thaw_from(0);
+        self.usage.clear();
         let rem_or_n = self.rem_x.map_or(n, |x| cmp::min(x, n));
-        for i in 0..rem_or_n {
-            for j in (1..self.height).rev() {
-                self.data.insert((self.width + i) * j, T::default());
-            }
-            self.data.push_back(T::default());
+        for row in &mut self.data {
+            row.extend(blank_row::<T>(rem_or_n));
         }
         self.width += rem_or_n;
+        if self.width > 0 && self.height == 0 {
+            self.data.push_back(blank_row(self.width));
+            self.lines.push_back(LineAttrs::default());
+            self.height = 1;
+        }
         if self.rem_x.map_or(false, |x| n > x) {
             let rem = n - self.rem_x.unwrap();
             self.shift_right(rem);
         }
         self.rem_x = self.rem_x.map(|x| x.saturating_sub(n));
-        if self.width > 0 && self.height == 0 {
-            self.height = 1;
-            self.rem_y.as_mut().map(|y| *y -= 1);
-        }
+        self.reset_rem_y();
     }
 
     fn shift_up(&mut self, n: usize) {
-        for _ in 0..(n * self.width) {
-            self.data.pop_back();
-            self.data.push_front(T::default());
-        }
+        self.pop_back_rows(n);
+        self.push_front_rows(n);
     }
 
     fn shift_down(&mut self, n: usize) {
-        for _ in 0..(n * self.width) {
-            self.data.pop_front();
-            self.data.push_back(T::default());
-        }
+        self.pop_front_rows(n);
+        self.push_back_rows(n);
     }
 
     fn shift_left(&mut self, n: usize) {
-        for _ in 0..n {
-            self.data.pop_back();
-            self.data.push_front(T::default());
-            for i in 1..self.height {
-                self.data[i * self.width] = T::default();
-            }
+        self.thaw_from(0);
+        self.usage.clear();
+        let (n, width) = (cmp::min(n, self.width), self.width);
+        for row in &mut self.data {
+            row.truncate(width - n);
+            let old = mem::replace(row, blank_row(n));
+            row.extend(old);
         }
     }
 
     fn shift_right(&mut self, n: usize) {
-        for _ in 0..n {
-            self.data.pop_front();
-            self.data.push_back(T::default());
-            for i in 1..self.height {
-                self.data[(i * self.width) - 1] = T::default();
-            }
+        self.thaw_from(0);
+        self.usage.clear();
+        let n = cmp::min(n, self.width);
+        for row in &mut self.data {
+            row.drain(..n);
+            row.extend(blank_row::<T>(n));
+        }
+    }
+
+    /// Change how many offscreen rows this grid keeps. Grids which do not retain offscreen
+    /// state never keep any, regardless of the policy. If the grid already holds more rows than
+    /// the new policy allows, the oldest rows are discarded at once. Returns the number of rows
+    /// which were discarded.
+    pub fn set_scrollback_policy(&mut self, scrollback: ScrollbackPolicy) -> usize {
+        if !self.retain_offscreen_state { return 0; }
+        self.scrollback = scrollback;
+        let excess = self.max_rows().map_or(0, |max| self.height.saturating_sub(max));
+        self.discard_front_rows(excess);
+        let evicted = self.evict();
+        self.reset_rem_y();
+        excess + evicted
+    }
+
+    /// Discard the oldest `n` rows of this grid.
+    fn discard_front_rows(&mut self, n: usize) {
+        if n == 0 { return; }
+        self.pop_front_rows(n);
+        self.height -= n;
+        if self.height == 0 {
+            self.rem_x = self.rem_x.map(|x| x + self.width);
+            self.width = 0;
+            self.data.clear();
+        }
+    }
+
+    /// Discard the oldest rows while the offscreen rows use more memory than a byte limit on
+    /// the scrollback allows. Returns the number of rows which were discarded.
+    fn evict(&mut self) -> usize {
+        self.count_offscreen();
+        let limit = match self.scrollback {
+            ScrollbackPolicy::MaxBytes(n)   => n,
+            _                               => return 0,
+        };
+        let mut evicted = 0;
+        while !self.usage.is_empty() && self.usage.bytes() > limit {
+            self.discard_front_rows(1);
+            evicted += 1;
+        }
+        evicted
+    }
+
+    /// Discard every cell outside of the visible region, so that the region becomes the whole
+    /// of the grid. The maximum dimensions of the grid are unchanged.
+    pub fn clear_scrollback(&mut self, visible: Region) {
+        let left = cmp::min(visible.left as usize, self.width);
+        let right = cmp::min(visible.right as usize, self.width);
+        let top = cmp::min(visible.top as usize, self.height);
+        let bottom = cmp::min(visible.bottom as usize, self.height);
+        self.thaw_from(top);
+        let (top, bottom) = (top - self.cold.len(), bottom - self.cold.len());
+        self.forget_decoded();
+        self.cold.clear();
+        self.usage.clear();
+        self.update_history(|history, _| history.clear());
+        let (width, height) = match (right - left, bottom - top) {
+            (0, _) | (_, 0) => (0, 0),
+            (w, h)          => (w, h),
+        };
+        let lines = mem::replace(&mut self.lines, VecDeque::with_capacity(height));
+        self.lines.extend(lines.into_iter().skip(visible.top as usize).take(height));
+        let data = mem::replace(&mut self.data, VecDeque::with_capacity(height));
+        for row in data.into_iter().skip(top).take(height) {
+            self.data.push_back(row.into_iter().skip(left).take(width).collect());
+        }
+        self.rem_x = self.rem_x.map(|x| x + self.width - width);
+        self.width = width;
+        self.height = height;
+        self.reset_rem_y();
+        self.count_offscreen();
+    }
+
+    /// Restore the rows saved in the history file at `path` into this grid, which must be empty.
+    /// From now on, rows are also saved to that file as they are moved into cold storage. Only as
+    /// many of the most recent rows as the scrollback policy allows are restored.
+    pub fn restore_history(&mut self, path: &Path, width: usize) -> io::Result<()> {
+        if !self.retain_offscreen_state || self.height > 0 {
+            return Err(io::Error::new(io::ErrorKind::InvalidInput,
+                                      "history can only be restored into an empty grid which \
+                                       retains offscreen state"));
+        }
+        let (mut history, rows) = try!(History::open(path));
+        if rows.iter().any(|&(_, ref row)| T::unpack(row).is_none()) {
+            return Err(io::Error::new(io::ErrorKind::InvalidData,
+                                      "scrollback file holds an invalid row"));
+        }
+        let keep = match self.max_rows() {
+            Some(max)   => cmp::min(rows.len(), max.saturating_sub(self.view_height)),
+            None        => rows.len(),
+        };
+        let skip = rows.len() - keep;
+        try!(history.forget_front(skip));
+        self.cold.extend(rows.into_iter().skip(skip).map(|(_, row)| ColdRow::new(row)));
+        if !self.cold.is_empty() {
+            self.width = width;
+            self.height = self.cold.len();
+            self.lines = self.cold.iter().map(|_| LineAttrs::default()).collect();
+        }
+        self.reset_rem_y();
+        self.history = Some(history);
+        self.evict();
+        Ok(())
+    }
+
+    /// Whether this grid saves its rows to a history file.
+    pub fn has_history(&self) -> bool {
+        self.history.is_some()
+    }
+
+    /// Save every row of this grid, including the rows which are not in cold storage, to its
+    /// history file, and flush the file to disk. The rows which are not in cold storage are
+    /// removed from the file again before the next change is saved.
+    pub fn sync_history(&mut self) -> io::Result<()> {
+        self.save_cold_rows();
+        let width = self.width;
+        let rows = self.data.iter().map(|row| T::pack(row)).collect::<Vec<_>>();
+        self.update_history(|history, _| history.sync(width, &rows));
+        match self.history_error.take() {
+            Some(err)   => Err(err),
+            None        => Ok(()),
         }
     }
 
     fn fill_to(&mut self, Coords { x, y }: Coords) {
-        if x as usize >= self.width { self.fill_to_width(x as usize + 1); }
-        if y as usize >= self.height { self.fill_to_height(y as usize + 1); }
+        if x as usize >= self.width || y as usize >= self.height {
+            if x as usize >= self.width { self.fill_to_width(x as usize + 1); }
+            if y as usize >= self.height { self.fill_to_height(y as usize + 1); }
+            self.freeze();
+            self.count_offscreen();
+        }
     }
 
     fn fill_to_width(&mut self, width: usize) {
@@ -318,25 +768,248 @@ impl<T: Default> Grid<T> {
         let extension = height.saturating_sub(self.height);
         self.extend_down(extension);
     }
+
+    fn blank(&mut self) {
+        let cold = self.cold.len();
+        self.update_history(|history, _| history.forget_front(cold));
+        self.forget_decoded();
+        self.cold.clear();
+        self.data.clear();
+        self.lines.clear();
+        self.usage.clear();
+        for _ in 0..self.height {
+            self.data.push_back(blank_row(self.width));
+            self.lines.push_back(LineAttrs::default());
+        }
+    }
+
+    fn push_front_rows(&mut self, n: usize) {
+        for _ in 0..n {
+            self.lines.push_front(LineAttrs::default());
+        }
+        if self.cold.is_empty() {
+            for _ in 0..n {
+                self.data.push_front(blank_row(self.width));
+            }
+        } else {
+            let blank = T::pack(&blank_row::<T>(self.width));
+            self.forget_decoded();
+            self.update_history(|history, _| history.truncate(0));
+            for _ in 0..n {
+                self.cold.push_front(ColdRow::new(blank.clone()));
+            }
+        }
+        if !self.usage.is_empty() {
+            for y in (0..n).rev() {
+                let usage = self.row_usage(y);
+                self.usage.push_front(usage);
+            }
+        }
+    }
+
+    fn push_back_rows(&mut self, n: usize) {
+        for _ in 0..n {
+            self.data.push_back(blank_row(self.width));
+            self.lines.push_back(LineAttrs::default());
+        }
+    }
+
+    fn pop_front_rows(&mut self, n: usize) {
+        self.forget_decoded();
+        let cold = cmp::min(n, self.cold.len());
+        self.cold.drain(..cold);
+        self.update_history(|history, _| history.forget_front(cold));
+        let hot = cmp::min(n - cold, self.data.len());
+        self.data.drain(..hot);
+        self.lines.drain(..cold + hot);
+        for _ in 0..cold + hot {
+            self.usage.pop_front();
+        }
+    }
+
+    fn pop_back_rows(&mut self, n: usize) {
+        let hot = cmp::min(n, self.hot_height());
+        for _ in 0..hot {
+            self.data.pop_back();
+        }
+        for _ in hot..n {
+            self.cold.pop_back();
+        }
+        for _ in 0..n {
+            self.lines.pop_back();
+        }
+        while self.usage.len() > self.lines.len() {
+            self.usage.pop_back();
+        }
+        let cold = self.cold.len();
+        self.update_history(|history, _| history.truncate(cold));
+    }
+
+    /// Pack the oldest rows into cold storage until no more than the allowed number of rows are
+    /// left unpacked.
+    fn freeze(&mut self) {
+        self.forget_decoded();
+        if let Some(hot_rows) = self.hot_rows {
+            let max_hot = self.view_height + hot_rows;
+            let cold = self.cold.len();
+            while self.hot_height() > max_hot {
+                let row = self.data.pop_front().unwrap();
+                self.cold.push_back(ColdRow::new(T::pack(&row)));
+            }
+            let frozen = self.cold.len();
+            self.recount(cold, frozen);
+        }
+        self.save_cold_rows();
+    }
+
+    fn save_cold_rows(&mut self) {
+        self.update_history(|history, grid| {
+            for y in history.saved()..grid.cold.len() {
+                try!(history.append(grid.width, &grid.cold[y].packed));
+            }
+            Ok(())
+        });
+    }
+
+    /// Unpack every row from `y` onward out of cold storage, so that it can be mutated.
+    fn thaw_from(&mut self, y: usize) {
+        if y >= self.cold.len() { return; }
+        self.update_history(|history, _| history.truncate(y));
+        let cold = self.cold.len();
+        while self.cold.len() > y {
+            let row = self.cold.pop_back().unwrap();
+            let row = match row.cells.into_inner() {
+                Some(cells) => cells.into_vec(),
+                None        => self.unpack(&row.packed),
+            };
+            self.data.push_front(row);
+        }
+        self.recount(y, cold);
+    }
+
+    fn unpack(&self, row: &PackedRow) -> Vec<T> {
+        let mut cells = T::unpack(row).expect(E_PACKED);
+        cells.truncate(self.width);
+        while cells.len() < self.width {
+            cells.push(T::default());
+        }
+        cells
+    }
+}
+
+/// Grids are saved with every row packed. The history file of a grid is not part of the session;
+/// a restored grid does not save its history until it is given a history file again.
+impl<T: Default + Pack> Persist for Grid<T> {
+    fn persist(&self, session: &mut SessionWriter) {
+        session.write_uint(self.width as u64);
+        session.write_option(self.rem_x);
+        session.write_option(self.rem_y);
+        session.write_uint(self.view_height as u64);
+        session.write_bool(self.retain_offscreen_state);
+        self.scrollback.persist(session);
+        session.write_option(self.hot_rows);
+        session.write_uint(self.cold.len() as u64);
+        for row in &self.cold {
+            session.write_row(&row.packed);
+        }
+        session.write_uint(self.data.len() as u64);
+        for row in &self.data {
+            session.write_row(&T::pack(row));
+        }
+        for line in &self.lines {
+            session.write_bool(line.wrapped);
+            line.size.persist(session);
+        }
+    }
+
+    fn restore(session: &mut SessionReader) -> Option<Grid<T>> {
+        let width = match session.read_uint() { Some(n) => n as usize, None => return None };
+        let rem_x = match session.read_option() { Some(n) => n, None => return None };
+        let rem_y = match session.read_option() { Some(n) => n, None => return None };
+        let view_height = match session.read_uint() { Some(n) => n as usize, None => return None };
+        let retain = match session.read_bool() { Some(flag) => flag, None => return None };
+        let scrollback = match ScrollbackPolicy::restore(session) {
+            Some(scrollback)    => scrollback,
+            None                => return None,
+        };
+        let hot_rows = match session.read_option() { Some(n) => n, None => return None };
+        if width > MAX_ROW_WIDTH || rem_x.map_or(false, |x| x > MAX_ROW_WIDTH - width)
+                || view_height > MAX_ROW_WIDTH {
+            return None;
+        }
+        let mut grid = Grid::new(rem_x, view_height, scrollback);
+        grid.width = width;
+        grid.rem_y = rem_y;
+        grid.retain_offscreen_state = retain;
+        grid.hot_rows = hot_rows;
+        let cold = match session.read_uint() { Some(n) => n, None => return None };
+        for _ in 0..cold {
+            match session.read_row() {
+                Some(row)   => {
+                    if T::unpack(&row).is_none() { return None; }
+                    grid.cold.push_back(ColdRow::new(row));
+                }
+                None        => return None,
+            }
+        }
+        let hot = match session.read_uint() { Some(n) => n, None => return None };
+        for _ in 0..hot {
+            match session.read_row().as_ref().and_then(T::unpack) {
+                Some(mut row)   => {
+                    row.truncate(width);
+                    while row.len() < width {
+                        row.push(T::default());
+                    }
+                    grid.data.push_back(row);
+                }
+                None            => return None,
+            }
+        }
+        grid.height = grid.cold.len() + grid.data.len();
+        if (grid.width == 0) != (grid.height == 0) { return None; }
+        for _ in 0..grid.height {
+            match (session.read_bool(), LineSize::restore(session)) {
+                (Some(wrapped), Some(size)) => {
+                    grid.lines.push_back(LineAttrs { wrapped: wrapped, size: size })
+                }
+                _                           => return None,
+            }
+        }
+        grid.count_offscreen();
+        Some(grid)
+    }
 }
 
-fn linearize(width: usize, Coords { x, y }: Coords) -> usize {
-    y as usize * width + x as usize
+fn blank_row<T: Default>(width: usize) -> Vec<T> {
+    (0..width).map(|_| T::default()).collect()
 }
 
-impl<'a, T> IntoIterator for &'a Grid<T> {
-    type IntoIter = <&'a VecDeque<T> as IntoIterator>::IntoIter;
-    type Item = <&'a VecDeque<T> as IntoIterator>::Item;
-    fn into_iter(self) -> Self::IntoIter {
-        (&self.data).into_iter()
+/// An iterator over every row in a grid, including the rows in cold storage.
+pub struct Rows<'a, T: 'a> {
+    grid: &'a Grid<T>,
+    y: usize,
+}
+
+impl<'a, T: Default + Pack + Clone> Iterator for Rows<'a, T> {
+    type Item = Cow<'a, [T]>;
+    fn next(&mut self) -> Option<Cow<'a, [T]>> {
+        let row = self.grid.row(self.y);
+        self.y += 1;
+        row
     }
 }
 
-impl<'a, T> IntoIterator for &'a mut Grid<T> {
-    type IntoIter = <&'a mut VecDeque<T> as IntoIterator>::IntoIter;
-    type Item = <&'a mut VecDeque<T> as IntoIterator>::Item;
-    fn into_iter(self) -> Self::IntoIter {
-        (&mut self.data).into_iter()
+/// An iterator over every cell in a grid, which unpacks all of its rows out of cold storage.
+pub type IterMut<'a, T> = iter::FlatMap<vec_deque::IterMut<'a, Vec<T>>, slice::IterMut<'a, T>,
+                                        fn(&'a mut Vec<T>) -> slice::IterMut<'a, T>>;
+
+impl<'a, T: Default + Pack> IntoIterator for &'a mut Grid<T> {
+    type IntoIter = IterMut<'a, T>;
+    type Item = &'a mut T;
+    fn into_iter(self) -> IterMut<'a, T> {
+        fn cells<T>(row: &mut Vec<T>) -> slice::IterMut<T> { row.iter_mut() }
+        self.thaw_from(0);
+        self.data.iter_mut().flat_map(cells as fn(&'a mut Vec<T>) -> slice::IterMut<'a, T>)
     }
 }
 
@@ -348,7 +1021,8 @@ pub fn coords_before(Coords { x, y }: Coords, width: u32) -> Coords {
     }
 }
 
-pub fn cell_to_extend<T: WriteableCell>(grid: &Grid<T>, coords: Coords) -> Option<Coords> {
+pub fn cell_to_extend<T>(grid: &Grid<T>, coords: Coords) -> Option<Coords>
+        where T: WriteableCell + Default + Pack {
     if let Some(cell) = grid.get(coords) {
         if cell.is_extendable() {
             Some(coords)
@@ -361,11 +1035,99 @@ pub fn cell_to_extend<T: WriteableCell>(grid: &Grid<T>, coords: Coords) -> Optio
 #[cfg(test)]
 mod tests {
 
-    use datatypes::{Coords, Region};
+    use std::env;
+    use std::fs;
+    use std::iter;
+    use std::mem;
+    use std::path::Path;
+    use std::sync::Arc;
+
+    use mime::{Mime, SubLevel, TopLevel};
+    #[cfg(feature = "bench")]
+    use test::Bencher;
+
+    use datatypes::{Coords, LineSize, MediaPosition, Region, ScrollbackPolicy};
     use datatypes::Direction::*;
-    use terminal::interfaces::Resizeable;
+    use terminal::{CellData, CharCell, ImageData, PackedRow, RowWriter, UseStyles};
+    use terminal::interfaces::{CellGrid, Pack, Resizeable, WriteableCell};
+
+    use super::{history, Grid, LineAttrs};
+
+    impl Pack for i32 {
+        fn pack(cells: &[i32]) -> PackedRow {
+            let mut writer = RowWriter::new();
+            writer.write_uint(cells.len() as u64);
+            for &cell in cells { writer.write_uint(cell as u32 as u64); }
+            writer.finish()
+        }
+
+        fn unpack(row: &PackedRow) -> Option<Vec<i32>> {
+            let mut reader = row.reader();
+            reader.read_uint().and_then(|len| {
+                (0..len).map(|_| reader.read_uint().map(|n| n as u32 as i32)).collect()
+            })
+        }
+    }
+
+    fn cold_grid(hot_rows: usize) -> Grid<i32> {
+        let mut grid = Grid::new(None, 4, ScrollbackPolicy::Unlimited);
+        grid.set_hot_rows(Some(hot_rows));
+        grid.fill_to(Coords { x: 3, y: 0 });
+        for i in 1..10 {
+            grid.scroll(Down, 1);
+            for x in 0..4 {
+                *grid.get_mut(Coords { x: x, y: i }).unwrap() = i as i32;
+            }
+        }
+        grid
+    }
 
-    use super::Grid;
+    fn log_grid(hot_rows: Option<usize>) -> Grid<CharCell> {
+        let mut grid = Grid::new(None, 24, ScrollbackPolicy::Unlimited);
+        grid.set_hot_rows(hot_rows);
+        grid.fill_to(Coords { x: 79, y: 0 });
+        let line = "   Compiling notty v0.1.0 (file:///home/user/notty)";
+        for y in 1..10_000 {
+            grid.scroll(Down, 1);
+            for (x, c) in line.chars().enumerate() {
+                let cell = grid.get_mut(Coords { x: x as u32, y: y }).unwrap();
+                cell.write(CellData::Char(c), UseStyles::default());
+            }
+        }
+        grid
+    }
+
+    fn full_grid(width: u32, height: u32) -> Grid<CharCell> {
+        let mut grid = Grid::with_caps(width as usize, height as usize);
+        grid.fill_to(Coords { x: width - 1, y: height - 1 });
+        for cell in &mut grid {
+            cell.write(CellData::Char('x'), UseStyles::default());
+        }
+        grid
+    }
+
+    fn image(len: usize) -> CellData {
+        CellData::Image {
+            data: Arc::new(ImageData { data: vec![0; len], coords: Coords { x: 0, y: 0 } }),
+            mime: Mime(TopLevel::Image, SubLevel::Png, vec![]),
+            pos: MediaPosition::default(),
+            width: 1,
+            height: 2,
+        }
+    }
+
+    /// A grid which saves its history in `dir`, with an image in its only row of scrollback.
+    fn image_history_grid(dir: &Path, scrollback: ScrollbackPolicy) -> Grid<CharCell> {
+        let _ = fs::remove_dir_all(dir);
+        let mut grid = Grid::new(None, 1, scrollback);
+        grid.set_hot_rows(Some(0));
+        grid.restore_history(&dir.join("scrollback"), 1).unwrap();
+        grid.fill_to(Coords { x: 0, y: 0 });
+        grid.get_mut(Coords { x: 0, y: 0 }).unwrap().write(image(16), UseStyles::default());
+        grid.scroll(Down, 1);
+        assert_eq!(fs::read_dir(dir.join("images")).unwrap().count(), 1);
+        grid
+    }
 
     fn run_test<F: Fn(Grid<i32>, usize, usize)>(test: F, new_w: usize, new_h: usize) {
         fn fill(grid: &mut Grid<i32>) {
@@ -391,7 +1153,8 @@ mod tests {
             }
             assert_eq!(width, grid.width);
             assert_eq!(height, grid.height);
-            assert_eq!(grid.data.len(), width * height);
+            assert_eq!(grid.data.len(), height);
+            assert!(grid.data.iter().all(|row| row.len() == width));
         }, 10, 8);
     }
 
@@ -407,7 +1170,8 @@ mod tests {
             }
             assert_eq!(width, grid.width);
             assert_eq!(height, grid.height);
-            assert_eq!(grid.data.len(), width * height);
+            assert_eq!(grid.data.len(), height);
+            assert!(grid.data.iter().all(|row| row.len() == width));
         }, 10, 8);
     }
 
@@ -423,7 +1187,8 @@ mod tests {
             }
             assert_eq!(width, grid.width);
             assert_eq!(height, grid.height);
-            assert_eq!(grid.data.len(), width * height);
+            assert_eq!(grid.data.len(), height);
+            assert!(grid.data.iter().all(|row| row.len() == width));
         }, 8, 10);
     }
 
@@ -439,7 +1204,8 @@ mod tests {
             }
             assert_eq!(width, grid.width);
             assert_eq!(height, grid.height);
-            assert_eq!(grid.data.len(), width * height);
+            assert_eq!(grid.data.len(), height);
+            assert!(grid.data.iter().all(|row| row.len() == width));
         }, 8, 10);
     }
 
@@ -464,6 +1230,20 @@ mod tests {
         }, 12, 8)
     }
 
+    #[test]
+    fn clear_scrollback() {
+        run_test(|mut grid, width, height| {
+            grid.clear_scrollback(Region::new(2, 3, 7, 6));
+            assert_eq!(grid.width, 5);
+            assert_eq!(grid.height, 3);
+            assert_eq!(grid.data.len(), 3);
+            assert!(grid.data.iter().all(|row| row.len() == 5));
+            assert_eq!(grid.max_width(), Some(width));
+            assert_eq!(grid.max_height(), Some(height));
+            assert!(grid.data.iter().all(|row| row.iter().all(|&i| i == 1)));
+        }, 10, 10);
+    }
+
     #[test]
     fn resize_height() {
         run_test(|mut grid, height, _| {
@@ -471,4 +1251,326 @@ mod tests {
             assert_eq!(grid.max_height(), Some(12));
         }, 8, 12)
     }
+
+    #[test]
+    fn scrollback_max_rows() {
+        let mut grid: Grid<i32> = Grid::new(None, 4, ScrollbackPolicy::MaxRows(2));
+        grid.fill_to(Coords { x: 0, y: 5 });
+        for (i, cell) in (&mut grid).into_iter().enumerate() { *cell = i as i32; }
+        grid.scroll(Down, 2);
+        assert_eq!(grid.height, 6);
+        assert_eq!(grid.max_height(), Some(6));
+        assert_eq!(*grid.get(Coords { x: 0, y: 0 }).unwrap(), 2);
+        assert_eq!(*grid.get(Coords { x: 0, y: 3 }).unwrap(), 5);
+        assert_eq!(*grid.get(Coords { x: 0, y: 5 }).unwrap(), 0);
+    }
+
+    #[test]
+    fn scrollback_max_bytes() {
+        let row = mem::size_of::<Vec<i32>>() + 4 * mem::size_of::<i32>();
+        let mut grid: Grid<i32> = Grid::new(None, 2, ScrollbackPolicy::MaxBytes(3 * row));
+        grid.fill_to(Coords { x: 3, y: 1 });
+        assert_eq!(grid.max_height(), None);
+        assert_eq!((0..5).map(|_| grid.scroll(Down, 1)).sum::<usize>(), 2);
+        assert_eq!(grid.height, 5);
+        assert_eq!(grid.usage.bytes(), 3 * row);
+    }
+
+    #[test]
+    fn scrollback_max_bytes_evicts_large_rows() {
+        let mut grid: Grid<CharCell> = Grid::new(None, 1, ScrollbackPolicy::Unlimited);
+        grid.fill_to(Coords { x: 0, y: 2 });
+        let limit = grid.memory_usage();
+        assert_eq!(grid.set_scrollback_policy(ScrollbackPolicy::MaxBytes(limit)), 0);
+        let grapheme = iter::repeat("e\u{301}").take(limit).collect();
+        grid.get_mut(Coords { x: 0, y: 2 }).unwrap()
+            .write(CellData::Grapheme(grapheme), UseStyles::default());
+        assert_eq!(grid.scroll(Down, 1), 3);
+        assert_eq!(grid.height, 1);
+        assert_eq!(grid.usage.bytes(), 0);
+    }
+
+    #[test]
+    fn shared_images_are_counted_once() {
+        let image = image(1000);
+        let mut grid: Grid<CharCell> = Grid::new(None, 1, ScrollbackPolicy::Unlimited);
+        grid.fill_to(Coords { x: 0, y: 2 });
+        let blank = grid.memory_usage();
+        for y in 0..2 {
+            grid.get_mut(Coords { x: 0, y: y }).unwrap().write(image.clone(), UseStyles::default());
+        }
+        assert_eq!(grid.memory_usage(), blank + 1000);
+        let limit = blank - blank / 3 + 1000;
+        assert_eq!(grid.set_scrollback_policy(ScrollbackPolicy::MaxBytes(limit)), 0);
+        assert_eq!(grid.usage.bytes(), limit);
+    }
+
+    #[test]
+    fn scrollback_max_bytes_counts_owned_memory() {
+        let mut grid: Grid<CharCell> = Grid::new(None, 1, ScrollbackPolicy::Unlimited);
+        grid.fill_to(Coords { x: 0, y: 2 });
+        let blank = grid.memory_usage();
+        let grapheme = iter::repeat("e\u{301}").take(100).collect();
+        grid.get_mut(Coords { x: 0, y: 0 }).unwrap()
+            .write(CellData::Grapheme(grapheme), UseStyles::default());
+        assert!(grid.memory_usage() >= blank + 300);
+        let limit = grid.memory_usage() - blank / 3;
+        assert_eq!(grid.set_scrollback_policy(ScrollbackPolicy::MaxBytes(limit)), 0);
+        assert_eq!(grid.set_scrollback_policy(ScrollbackPolicy::MaxBytes(limit - 1)), 1);
+        assert_eq!(grid.height, 2);
+    }
+
+    #[test]
+    fn set_scrollback_policy() {
+        let mut grid: Grid<i32> = Grid::new(None, 4, ScrollbackPolicy::Unlimited);
+        assert_eq!(grid.max_height(), None);
+        grid.set_scrollback_policy(ScrollbackPolicy::MaxRows(3));
+        assert_eq!(grid.max_height(), Some(7));
+        let mut grid: Grid<i32> = Grid::with_caps(8, 8);
+        grid.set_scrollback_policy(ScrollbackPolicy::Unlimited);
+        assert_eq!(grid.max_height(), Some(8));
+    }
+
+    #[test]
+    fn lower_scrollback_policy() {
+        let mut grid = cold_grid(2);
+        assert_eq!(grid.set_scrollback_policy(ScrollbackPolicy::MaxRows(2)), 4);
+        assert_eq!(grid.height, 6);
+        assert_eq!(grid.lines.len(), 6);
+        assert_eq!(grid.max_height(), Some(6));
+        assert_eq!(*grid.get(Coords { x: 0, y: 0 }).unwrap(), 4);
+        assert_eq!(*grid.get(Coords { x: 0, y: 5 }).unwrap(), 9);
+        assert_eq!(grid.set_scrollback_policy(ScrollbackPolicy::MaxRows(0)), 2);
+        assert_eq!(*grid.get(Coords { x: 0, y: 0 }).unwrap(), 6);
+    }
+
+    #[test]
+    fn wrapped_rows_scroll() {
+        let mut grid = full_grid(4, 4);
+        grid.set_wrapped(1, true);
+        grid.scroll(Down, 1);
+        assert!(grid.is_wrapped(0));
+        assert!(!grid.is_wrapped(1));
+        grid.scroll(Up, 4);
+        assert!(!grid.is_wrapped(0));
+    }
+
+    #[test]
+    fn insert_and_remove_cells() {
+        let mut grid = Grid::with_caps(6, 1);
+        grid.fill_to(Coords { x: 5, y: 0 });
+        for (i, cell) in (&mut grid).into_iter().enumerate() { *cell = i as i32 + 1; }
+        grid.insert_blank_at(Coords { x: 1, y: 0 }, 2, 5);
+        assert_eq!(grid.data[0], vec![1, 0, 0, 2, 3, 6]);
+        grid.remove_at(Coords { x: 0, y: 0 }, 1, 6);
+        assert_eq!(grid.data[0], vec![0, 0, 2, 3, 6, 0]);
+        grid.remove_at(Coords { x: 2, y: 0 }, 10, 6);
+        assert_eq!(grid.data[0], vec![0, 0, 0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn insert_and_remove_rows() {
+        let mut grid = Grid::with_caps(1, 5);
+        grid.fill_to(Coords { x: 0, y: 4 });
+        for (i, cell) in (&mut grid).into_iter().enumerate() { *cell = i as i32 + 1; }
+        let attrs = LineAttrs { wrapped: true, size: LineSize::DoubleWidth };
+        grid.lines[2] = attrs;
+        grid.insert_rows_at(1, 1, 4);
+        assert_eq!(grid.data.iter().map(|row| row[0]).collect::<Vec<_>>(), vec![1, 0, 2, 3, 5]);
+        assert_eq!(grid.lines[3], attrs);
+        assert_eq!(grid.lines[2], LineAttrs::default());
+        grid.remove_rows_at(0, 2, 5);
+        assert_eq!(grid.data.iter().map(|row| row[0]).collect::<Vec<_>>(), vec![2, 3, 5, 0, 0]);
+        assert_eq!(grid.lines[1], attrs);
+        assert_eq!(grid.lines[4], LineAttrs::default());
+    }
+
+    #[test]
+    fn cold_rows() {
+        let grid = cold_grid(2);
+        assert_eq!(grid.height, 10);
+        assert_eq!(grid.cold.len(), 4);
+        for y in 0..10 {
+            assert_eq!(*grid.get(Coords { x: 2, y: y }).unwrap(), y as i32);
+        }
+        assert_eq!(grid.cold.len(), 4);
+        assert_eq!(grid.rows().flat_map(|row| row.into_owned()).collect::<Vec<_>>(),
+                   (0..40).map(|i| i / 4).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn thaw_cold_rows() {
+        let mut grid = cold_grid(2);
+        *grid.get_mut(Coords { x: 0, y: 2 }).unwrap() = 20;
+        assert_eq!(grid.cold.len(), 2);
+        assert_eq!(*grid.get(Coords { x: 0, y: 2 }).unwrap(), 20);
+        assert_eq!(*grid.get(Coords { x: 1, y: 2 }).unwrap(), 2);
+        grid.scroll(Down, 1);
+        assert_eq!(grid.cold.len(), 5);
+        assert_eq!(*grid.get(Coords { x: 0, y: 2 }).unwrap(), 20);
+    }
+
+    #[test]
+    fn scroll_cold_rows() {
+        let mut grid = cold_grid(2);
+        grid.set_scrollback_policy(ScrollbackPolicy::MaxRows(6));
+        grid.scroll(Down, 2);
+        assert_eq!(grid.height, 10);
+        assert_eq!(grid.cold.len(), 4);
+        assert_eq!(*grid.get(Coords { x: 0, y: 0 }).unwrap(), 2);
+        assert_eq!(*grid.get(Coords { x: 0, y: 7 }).unwrap(), 9);
+        grid.scroll(Up, 1);
+        assert_eq!(*grid.get(Coords { x: 0, y: 0 }).unwrap(), 0);
+        assert_eq!(*grid.get(Coords { x: 0, y: 1 }).unwrap(), 2);
+    }
+
+    #[test]
+    fn decoded_rows_are_dropped_when_grid_scrolls() {
+        let mut grid = cold_grid(2);
+        assert_eq!(*grid.get(Coords { x: 1, y: 1 }).unwrap(), 1);
+        assert!(grid.cold[1].cells.get().is_some());
+        grid.scroll(Down, 1);
+        assert!(grid.cold.iter().all(|row| row.cells.get().is_none()));
+        assert_eq!(*grid.get(Coords { x: 1, y: 1 }).unwrap(), 1);
+    }
+
+    #[test]
+    fn clear_cold_scrollback() {
+        let mut grid = cold_grid(2);
+        grid.clear_scrollback(Region::new(0, 6, 4, 10));
+        assert_eq!(grid.height, 4);
+        assert!(grid.cold.is_empty());
+        assert_eq!(*grid.get(Coords { x: 0, y: 0 }).unwrap(), 6);
+    }
+
+    #[test]
+    fn restore_synced_history() {
+        let dir = env::temp_dir().join("notty-test-restore-synced-history");
+        let _ = fs::remove_dir_all(&dir);
+        let path = dir.join("scrollback");
+        let mut grid = Grid::new(None, 4, ScrollbackPolicy::Unlimited);
+        grid.set_hot_rows(Some(2));
+        grid.restore_history(&path, 4).unwrap();
+        grid.fill_to(Coords { x: 3, y: 0 });
+        for i in 1..10 {
+            grid.scroll(Down, 1);
+            for x in 0..4 {
+                *grid.get_mut(Coords { x: x, y: i }).unwrap() = i as i32;
+            }
+        }
+        grid.sync_history().unwrap();
+        let mut restored = Grid::<i32>::new(None, 4, ScrollbackPolicy::Unlimited);
+        restored.restore_history(&path, 4).unwrap();
+        assert_eq!(restored.height, 10);
+        assert_eq!(restored.width, 4);
+        for y in 0..10 {
+            assert_eq!(*restored.get(Coords { x: 3, y: y }).unwrap(), y as i32);
+        }
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn restore_flushed_history() {
+        let dir = env::temp_dir().join("notty-test-restore-flushed-history");
+        let _ = fs::remove_dir_all(&dir);
+        let path = dir.join("scrollback");
+        let mut grid: Grid<i32> = Grid::new(None, 1, ScrollbackPolicy::Unlimited);
+        grid.set_hot_rows(Some(0));
+        grid.restore_history(&path, 4).unwrap();
+        grid.fill_to(Coords { x: 3, y: 0 });
+        for _ in 0..100 {
+            grid.scroll(Down, 1);
+        }
+        // Leak the grid, as a crash would, so that its buffered rows are never written.
+        mem::forget(grid);
+        let mut restored = Grid::<i32>::new(None, 1, ScrollbackPolicy::Unlimited);
+        restored.restore_history(&path, 4).unwrap();
+        assert!(restored.height >= 64);
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn clearing_history_removes_images() {
+        let dir = env::temp_dir().join("notty-test-clearing-history-removes-images");
+        let mut grid = image_history_grid(&dir, ScrollbackPolicy::Unlimited);
+        grid.clear_scrollback(Region::new(0, 1, 1, 2));
+        assert_eq!(fs::read_dir(dir.join("images")).unwrap().count(), 0);
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn compacting_history_removes_images() {
+        let dir = env::temp_dir().join("notty-test-compacting-history-removes-images");
+        let path = dir.join("scrollback");
+        let mut grid = image_history_grid(&dir, ScrollbackPolicy::MaxRows(1));
+        for _ in 0..history::COMPACT_ROWS + 1 {
+            grid.scroll(Down, 1);
+        }
+        assert_eq!(fs::read_dir(dir.join("images")).unwrap().count(), 0);
+        grid.sync_history().unwrap();
+        let mut restored = Grid::<CharCell>::new(None, 1, ScrollbackPolicy::Unlimited);
+        restored.restore_history(&path, 1).unwrap();
+        assert!(restored.height < history::COMPACT_ROWS);
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn cold_storage_memory() {
+        let hot = log_grid(None).memory_usage();
+        let cold = log_grid(Some(0)).memory_usage();
+        assert!(cold * 10 < hot, "packed scrollback uses {} of {} bytes", cold, hot);
+    }
+
+    #[cfg(feature = "bench")]
+    #[bench]
+    fn scroll_into_cold_storage(b: &mut Bencher) {
+        let mut grid = log_grid(Some(0));
+        b.iter(|| grid.scroll(Down, 1));
+    }
+
+    #[cfg(feature = "bench")]
+    #[bench]
+    fn unpack_cold_row(b: &mut Bencher) {
+        let grid = log_grid(Some(0));
+        b.iter(|| CharCell::unpack(&grid.cold[5000].packed));
+    }
+
+    #[cfg(feature = "bench")]
+    #[bench]
+    fn scroll_full_screen(b: &mut Bencher) {
+        let mut grid = full_grid(80, 24);
+        b.iter(|| grid.scroll(Down, 1));
+    }
+
+    #[cfg(feature = "bench")]
+    #[bench]
+    fn scroll_tall_grid_horizontally(b: &mut Bencher) {
+        let mut grid = full_grid(80, 1000);
+        b.iter(|| grid.scroll(Left, 1));
+    }
+
+    #[cfg(feature = "bench")]
+    #[bench]
+    fn extend_tall_grid_right(b: &mut Bencher) {
+        b.iter(|| {
+            let mut grid: Grid<CharCell> = Grid::new(None, 24, ScrollbackPolicy::Unlimited);
+            grid.fill_to(Coords { x: 0, y: 999 });
+            grid.scroll(Right, 80);
+            grid
+        });
+    }
+
+    #[cfg(feature = "bench")]
+    #[bench]
+    fn insert_blank_in_row(b: &mut Bencher) {
+        let mut grid = full_grid(80, 24);
+        b.iter(|| grid.insert_blank_at(Coords { x: 0, y: 12 }, 1, 80));
+    }
+
+    #[cfg(feature = "bench")]
+    #[bench]
+    fn insert_rows_in_screen(b: &mut Bencher) {
+        let mut grid = full_grid(80, 24);
+        b.iter(|| grid.insert_rows_at(0, 1, 24));
+    }
 }
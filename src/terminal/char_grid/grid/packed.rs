@@ -0,0 +1,175 @@
+//  notty is a new kind of terminal emulator.
+//  Copyright (C) 2015 without boats
+//  
+//  This program is free software: you can redistribute it and/or modify
+//  it under the terms of the GNU Affero General Public License as published by
+//  the Free Software Foundation, either version 3 of the License, or
+//  (at your option) any later version.
+//  
+//  This program is distributed in the hope that it will be useful,
+//  but WITHOUT ANY WARRANTY; without even the implied warranty of
+//  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+//  GNU Affero General Public License for more details.
+//  
+//  You should have received a copy of the GNU Affero General Public License
+//  along with this program.  If not, see <http://www.gnu.org/licenses/>.
+use std::mem;
+use std::str;
+use std::sync::Arc;
+
+use terminal::ImageData;
+
+/// A row of cells packed into a compact byte encoding. Rows of scrollback which have not been
+/// accessed recently are stored this way. Images are held by reference rather than copied into
+/// the encoding.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PackedRow {
+    bytes: Box<[u8]>,
+    images: Vec<Arc<ImageData>>,
+}
+
+impl PackedRow {
+    pub fn from_parts(bytes: Vec<u8>, images: Vec<Arc<ImageData>>) -> PackedRow {
+        PackedRow {
+            bytes: bytes.into_boxed_slice(),
+            images: images,
+        }
+    }
+
+    pub fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    pub fn images(&self) -> &[Arc<ImageData>] {
+        &self.images
+    }
+
+    /// The approximate amount of memory used by this row, in bytes. The data of the images it
+    /// holds is shared with every other holder of those images, so it is not included.
+    pub fn size(&self) -> usize {
+        mem::size_of::<PackedRow>()
+            + self.bytes.len()
+            + self.images.len() * mem::size_of::<Arc<ImageData>>()
+    }
+
+    pub fn reader(&self) -> RowReader {
+        RowReader::new(&self.bytes, &self.images)
+    }
+}
+
+/// The widest row which can be unpacked. Packed rows which claim to be wider than this are
+/// rejected rather than allocated.
+pub const MAX_ROW_WIDTH: usize = 1 << 16;
+
+/// Builds a `PackedRow`. Integers are written as LEB128 varints and strings are written as a
+/// length followed by their UTF-8 bytes.
+#[derive(Default)]
+pub struct RowWriter {
+    bytes: Vec<u8>,
+    images: Vec<Arc<ImageData>>,
+}
+
+impl RowWriter {
+    pub fn new() -> RowWriter {
+        RowWriter::default()
+    }
+
+    pub fn write_u8(&mut self, n: u8) {
+        self.bytes.push(n);
+    }
+
+    pub fn write_uint(&mut self, mut n: u64) {
+        while n >= 0x80 {
+            self.bytes.push((n as u8) | 0x80);
+            n >>= 7;
+        }
+        self.bytes.push(n as u8);
+    }
+
+    pub fn write_str(&mut self, s: &str) {
+        self.write_bytes(s.as_bytes());
+    }
+
+    pub fn write_bytes(&mut self, bytes: &[u8]) {
+        self.write_uint(bytes.len() as u64);
+        self.bytes.extend_from_slice(bytes);
+    }
+
+    pub fn write_image(&mut self, image: &Arc<ImageData>) {
+        let idx = self.images.len() as u64;
+        self.write_uint(idx);
+        self.images.push(image.clone());
+    }
+
+    pub fn finish(self) -> PackedRow {
+        PackedRow::from_parts(self.bytes, self.images)
+    }
+
+    /// The bytes which have been written. Any images which have been written are discarded.
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.bytes
+    }
+}
+
+/// Reads the values written by a `RowWriter` back out of a `PackedRow`, in the same order. Every
+/// method returns `None` if the row does not contain a well formed value at this point.
+pub struct RowReader<'a> {
+    bytes: &'a [u8],
+    images: &'a [Arc<ImageData>],
+    pos: usize,
+}
+
+impl<'a> RowReader<'a> {
+    pub fn new(bytes: &'a [u8], images: &'a [Arc<ImageData>]) -> RowReader<'a> {
+        RowReader { bytes: bytes, images: images, pos: 0 }
+    }
+
+    pub fn read_u8(&mut self) -> Option<u8> {
+        let byte = self.bytes.get(self.pos).cloned();
+        if byte.is_some() { self.pos += 1; }
+        byte
+    }
+
+    pub fn read_uint(&mut self) -> Option<u64> {
+        let mut n = 0;
+        let mut shift = 0;
+        while let Some(byte) = self.read_u8() {
+            if shift >= 64 { return None; }
+            n |= ((byte & 0x7f) as u64) << shift;
+            if byte & 0x80 == 0 { return Some(n); }
+            shift += 7;
+        }
+        None
+    }
+
+    pub fn read_str(&mut self) -> Option<&'a str> {
+        self.read_bytes().and_then(|bytes| str::from_utf8(bytes).ok())
+    }
+
+    pub fn read_bytes(&mut self) -> Option<&'a [u8]> {
+        let bytes = self.bytes;
+        let len = match self.read_uint() { Some(len) => len as usize, None => return None };
+        match self.pos.checked_add(len) {
+            Some(end) if end <= bytes.len() => {
+                let start = mem::replace(&mut self.pos, end);
+                Some(&bytes[start..end])
+            }
+            _ => None,
+        }
+    }
+
+    pub fn read_image(&mut self) -> Option<Arc<ImageData>> {
+        let images = self.images;
+        self.read_uint().and_then(|idx| images.get(idx as usize).cloned())
+    }
+
+    /// The number of bytes which have been read.
+    pub fn position(&self) -> usize {
+        self.pos
+    }
+
+    /// Whether every byte has been read.
+    pub fn is_empty(&self) -> bool {
+        self.pos == self.bytes.len()
+    }
+}
@@ -0,0 +1,121 @@
+//  notty is a new kind of terminal emulator.
+//  Copyright (C) 2015 without boats
+//  
+//  This program is free software: you can redistribute it and/or modify
+//  it under the terms of the GNU Affero General Public License as published by
+//  the Free Software Foundation, either version 3 of the License, or
+//  (at your option) any later version.
+//  
+//  This program is distributed in the hope that it will be useful,
+//  but WITHOUT ANY WARRANTY; without even the implied warranty of
+//  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+//  GNU Affero General Public License for more details.
+//  
+//  You should have received a copy of the GNU Affero General Public License
+//  along with this program.  If not, see <http://www.gnu.org/licenses/>.
+//! Keeping count of the memory used by the offscreen rows of a grid, so that a byte limit on
+//! its scrollback can be enforced without measuring every row each time the grid changes.
+use std::collections::{HashMap, VecDeque};
+use std::mem;
+use std::sync::Arc;
+
+use terminal::ImageData;
+
+/// The memory used by one row, not counting the data of the images it refers to, and the images
+/// it refers to, identified by the address of their data.
+#[derive(Debug, Default)]
+pub struct RowUsage {
+    pub bytes: usize,
+    pub images: Vec<(usize, usize)>,
+}
+
+impl RowUsage {
+    pub fn add_image(&mut self, image: &Arc<ImageData>) {
+        self.images.push((&**image as *const ImageData as usize, image.data.len()));
+    }
+}
+
+/// The memory used by the oldest rows of a grid. An image which several rows refer to is only
+/// counted once.
+#[derive(Default)]
+pub struct Usage {
+    rows: VecDeque<RowUsage>,
+    images: HashMap<usize, usize>,
+    bytes: usize,
+}
+
+impl Usage {
+    /// The number of rows counted, which are always the oldest rows of the grid.
+    pub fn len(&self) -> usize {
+        self.rows.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rows.is_empty()
+    }
+
+    pub fn bytes(&self) -> usize {
+        self.bytes
+    }
+
+    pub fn push_front(&mut self, row: RowUsage) {
+        self.add(&row);
+        self.rows.push_front(row);
+    }
+
+    pub fn push_back(&mut self, row: RowUsage) {
+        self.add(&row);
+        self.rows.push_back(row);
+    }
+
+    pub fn pop_front(&mut self) {
+        if let Some(row) = self.rows.pop_front() {
+            self.remove(&row);
+        }
+    }
+
+    pub fn pop_back(&mut self) {
+        if let Some(row) = self.rows.pop_back() {
+            self.remove(&row);
+        }
+    }
+
+    /// Count row `y` again, because its contents or the way it is stored have changed. Rows
+    /// which are not counted are ignored.
+    pub fn replace(&mut self, y: usize, row: RowUsage) {
+        if y < self.rows.len() {
+            self.add(&row);
+            let old = mem::replace(&mut self.rows[y], row);
+            self.remove(&old);
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.rows.clear();
+        self.images.clear();
+        self.bytes = 0;
+    }
+
+    fn add(&mut self, row: &RowUsage) {
+        self.bytes += row.bytes;
+        for &(image, len) in &row.images {
+            let refs = self.images.entry(image).or_insert(0);
+            if *refs == 0 { self.bytes += len; }
+            *refs += 1;
+        }
+    }
+
+    fn remove(&mut self, row: &RowUsage) {
+        self.bytes -= row.bytes;
+        for &(image, len) in &row.images {
+            let gone = match self.images.get_mut(&image) {
+                Some(refs)  => { *refs -= 1; *refs == 0 }
+                None        => false,
+            };
+            if gone {
+                self.images.remove(&image);
+                self.bytes -= len;
+            }
+        }
+    }
+}
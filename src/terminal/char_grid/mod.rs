@@ -13,13 +13,20 @@
 //  
 //  You should have received a copy of the GNU Affero General Public License
 //  along with this program.  If not, see <http://www.gnu.org/licenses/>.
-use std::collections::HashMap;
+use std::cmp;
+use std::collections::{hash_map, HashMap};
+use std::io;
+use std::mem;
 use std::ops::{Index, Deref, DerefMut};
+use std::path::Path;
+use std::u32;
 
-use datatypes::{Area, Coords, GridSettings, CoordsIter, Direction, Movement, Style, move_within};
+use datatypes::{Area, Coords, GridSettings, CoordsIter, Direction, LineSize, Movement, Region,
+                ScrollbackPolicy, Style, move_within};
 
-use terminal::UseStyles;
+use terminal::{Damage, UseStyles};
 use terminal::interfaces::*;
+use terminal::session::{Persist, SessionReader, SessionWriter};
 
 mod cell;
 mod cursor;
@@ -30,6 +37,7 @@ mod writers;
 
 pub use self::cell::{CharCell, CellData, ImageData, EMPTY_CELL};
 pub use self::cursor::Cursor;
+pub use self::grid::{PackedRow, RowReader, RowWriter, MAX_ROW_WIDTH};
 pub use self::tooltip::Tooltip;
 pub use self::writers::*;
 
@@ -45,71 +53,183 @@ pub struct CharGrid<G=Grid<CharCell>> {
     view: View,
     tooltips: HashMap<Coords, Tooltip>,
     text_styles: UseStyles,
+    damage: Damage,
+    tab_stop: u32,
 }
 
 // Public methods
 
 impl<G: CellGrid + WriteableGrid> CharGrid<G> where <G as WriteableGrid>::Cell: WriteableCell {
-    pub fn write<C: CharData>(&mut self, data: &C) {
-        let coords = data.write(self.cursor.coords,
-                                self.text_styles,
-                                &mut self.grid);
-        self.cursor.coords = self.calculate_movement(coords, RIGHT_ONE);
-        self.view.keep_within(self.cursor.coords);
+    pub fn write<C: CharData>(&mut self, data: C) {
+        let coords = data.write(self.cursor.coords, self.text_styles, &mut DamageRecorder {
+            grid: &mut self.grid,
+            damage: &mut self.damage,
+            view: self.view.bounds(),
+        });
+        let cursor = self.calculate_movement(coords, RIGHT_ONE);
+        if cursor.y > coords.y {
+            self.grid.set_wrapped(coords.y, true);
+        }
+        self.move_cursor_to(cursor);
+    }
+
+    /// Fill the visible part of the grid with `E`, for the screen alignment test (DECALN). Every
+    /// visible row is set to normal size and the cursor is moved to the top left corner.
+    pub fn alignment_test(&mut self) {
+        let bounds = self.view.bounds();
+        for y in bounds.top..bounds.bottom {
+            self.grid.set_line_size(y, LineSize::Normal);
+            self.grid.set_wrapped(y, false);
+            for x in bounds.left..bounds.right {
+                if let Some(cell) = self.grid.writeable(Coords { x: x, y: y }) {
+                    cell.write(CellData::Char('E'), UseStyles::default());
+                }
+            }
+        }
+        self.damage.add_all();
+        self.move_cursor_to(Coords { x: bounds.left, y: bounds.top });
+    }
+
+    /// Write a run of characters which are each `width` cells wide, as though each had been
+    /// written in turn. The characters which fit in the rest of the cursor's row are written
+    /// straight into it, and the cursor is moved once for each row the run is written to.
+    pub fn write_str(&mut self, text: &str, width: u32) {
+        let mut chars = text.chars().peekable();
+        while chars.peek().is_some() {
+            let start = self.cursor.coords;
+            let right = self.row_bounds(start.y).right;
+            let mut coords = start;
+            while let Some(ch) = chars.next() {
+                let mut recorder = DamageRecorder {
+                    grid: &mut self.grid,
+                    damage: &mut self.damage,
+                    view: self.view.bounds(),
+                };
+                let last = match width {
+                    1   => ch.write(coords, self.text_styles, &mut recorder),
+                    _   => WideChar::new(ch, width).write(coords, self.text_styles, &mut recorder),
+                };
+                coords = last;
+                // Moving right within the row only needs recalculating if it would leave the
+                // row or land in the extension of a wide character.
+                let next = Coords { x: last.x + 1, y: last.y };
+                if chars.peek().is_none() || last.y != start.y || next.x >= right ||
+                    recorder.grid.move_out_of_extension(next, Direction::Right) != next {
+                        break
+                }
+                coords = next;
+            }
+            let cursor = self.calculate_movement(coords, RIGHT_ONE);
+            if cursor.y > coords.y {
+                self.grid.set_wrapped(coords.y, true);
+            }
+            self.move_cursor_to(cursor);
+        }
     }
 }
 
 impl<T: CellGrid> CharGrid<T> {
+    /// Whether text written at the end of row `y` of the grid continued onto the next row.
+    pub fn is_wrapped(&self, y: u32) -> bool {
+        self.grid.is_wrapped(y)
+    }
+
+    /// The size of the characters in row `y` of the visible part of the grid.
+    pub fn line_size_in_view(&self, y: u32) -> LineSize {
+        self.grid.line_size(self.view.bounds().top + y)
+    }
+
     pub fn move_cursor(&mut self, movement: Movement) {
-        self.cursor.coords = self.calculate_movement(self.cursor.coords, movement);
-        self.view.keep_within(self.cursor.coords);
+        let cursor = self.calculate_movement(self.cursor.coords, movement);
+        self.move_cursor_to(cursor);
     }
 
     pub fn insert_blank_at(&mut self, n: u32) {
-        let iter = self.iterate_over_area(TO_RIGHT_EDGE);
-        let CharGrid { ref mut grid, ref view, .. } = * self;
-        let iter = iter.rev().skip(n as usize)
-                       .map(|coords| view.translate(coords));
-        for coords in iter {
-            grid.moveover(coords, Coords { x: coords.x + n, y: coords.y });
-        }
+        self.damage_area(TO_RIGHT_EDGE);
+        let coords = self.cursor.coords;
+        let right = self.row_bounds(coords.y).right;
+        self.grid.insert_blank_at(coords, n, right);
     }
 
     pub fn remove_at(&mut self, n: u32) {
-        let iter = self.iterate_over_area(TO_RIGHT_EDGE);
-        let CharGrid { ref mut grid, ref view, .. } = *self;
-        let iter = iter.take_while(|&Coords { x, .. }| x + n < view.width())
-                       .map(|coords| view.translate(coords));
-        for coords in iter {
-            grid.moveover(Coords { x: coords.x + n, y: coords.y }, coords);
-        }
+        self.damage_area(TO_RIGHT_EDGE);
+        let coords = self.cursor.coords;
+        let right = self.row_bounds(coords.y).right;
+        self.grid.remove_at(coords, n, right);
     }
 
     pub fn insert_rows_at(&mut self, n: u32, include: bool) {
-        let iter = self.iterate_over_area(Area::BelowCursor(include));
-        let CharGrid { ref mut grid, ref view, .. } = *self;
-        let iter = iter.rev().skip((n * view.width()) as usize)
-                       .map(|coords| view.translate(coords));
-        for coords in iter {
-            grid.moveover(coords, Coords { x: coords.x, y: coords.y + n });
-        }
+        self.damage_area(Area::BelowCursor(include));
+        let y = if include { self.cursor.coords.y } else { self.cursor.coords.y + 1 };
+        let bottom = self.view.bounds().bottom;
+        self.grid.insert_rows_at(y, n, bottom);
     }
 
     pub fn remove_rows_at(&mut self, n: u32, include: bool) {
-        let iter = self.iterate_over_area(Area::BelowCursor(include));
-        let CharGrid { ref mut grid, ref view, .. } = *self;
-        let iter = iter.take_while(|&Coords { y, .. }| y + n < view.height())
-                       .map(|coords| view.translate(coords));
-        for coords in iter {
-            grid.moveover(Coords { x: coords.x, y: coords.y + n }, coords);
-        }
+        self.damage_area(Area::BelowCursor(include));
+        let y = if include { self.cursor.coords.y } else { self.cursor.coords.y + 1 };
+        let bottom = self.view.bounds().bottom;
+        self.grid.remove_rows_at(y, n, bottom);
+    }
+}
+
+impl<T: CellGrid<Cell=CharCell>> CharGrid<T> {
+    /// The cell at these grid coordinates, which need not be visible.
+    pub fn cell_at(&self, coords: Coords) -> Option<&CharCell> {
+        self.grid.get(coords)
     }
 }
 
 impl<T: CellGrid> CharGrid<T> where T::Cell: Cell {
+    /// Set the size of the characters in the row of the cursor. The cells of the row which are
+    /// not used at that size are erased, and the cursor is kept within the cells which are.
+    pub fn set_line_size(&mut self, size: LineSize) {
+        let y = self.cursor.coords.y;
+        self.grid.set_line_size(y, size);
+        let (bounds, right) = (self.view.bounds(), self.row_bounds(y).right);
+        for x in bounds.left..bounds.right {
+            if x >= right {
+                self.grid.get_mut(Coords { x: x, y: y }).map(Cell::erase);
+            }
+            self.damage_cell(Coords { x: x, y: y });
+        }
+        if self.cursor.coords.x >= right {
+            self.move_cursor_to(Coords { x: right - 1, y: y });
+        }
+    }
+
+    /// Reset this grid for a hard reset of the terminal. The visible rows are erased and set to
+    /// normal size and the tooltips on them are removed; the cursor is moved to the top left
+    /// corner of the view, the cursor and text styles are reset and the tab stop is set to
+    /// `tab_stop`. The rows above the view are kept.
+    pub fn reset(&mut self, tab_stop: u32) {
+        let bounds = self.view.bounds();
+        self.erase(Area::WholeScreen);
+        self.tooltips = self.tooltips.drain().filter(|&(coords, _)| !bounds.contains(coords))
+                            .collect();
+        self.cursor = Cursor::default();
+        self.cursor.coords = Coords { x: bounds.left, y: bounds.top };
+        self.text_styles = UseStyles::default();
+        self.tab_stop = cmp::max(tab_stop, 1);
+        self.damage.add_all();
+    }
+
+    /// Erase the cells in `area`. Erasing the whole screen also sets every visible row to normal
+    /// size.
     pub fn erase(&mut self, area: Area) {
+        let bounds = self.view.bounds();
+        if let Area::WholeScreen = area {
+            for y in bounds.top..bounds.bottom {
+                self.grid.set_line_size(y, LineSize::Normal);
+            }
+        }
+        let right = bounds.right;
         for coords in self.iterate_over_area(area) {
             self.grid.get_mut(coords).map(Cell::erase);
+            if coords.x + 1 == right {
+                self.grid.set_wrapped(coords.y, false);
+            }
+            self.damage_cell(coords);
         }
     }
 }
@@ -118,12 +238,14 @@ impl<T: CellGrid> CharGrid<T> where T::Cell: Styleable {
     pub fn set_style_in_area(&mut self, area: Area, style: Style) {
         for coords in self.iterate_over_area(area) {
             self.grid.get_mut(coords).map(|cell| cell.set_style(style));
+            self.damage_cell(coords);
         }
     }
 
     pub fn reset_styles_in_area(&mut self, area: Area) {
         for coords in self.iterate_over_area(area) {
             self.grid.get_mut(coords).map(Styleable::reset_style);
+            self.damage_cell(coords);
         }
     }
 }
@@ -134,9 +256,32 @@ impl<T> CharGrid<T> {
     }
 
     pub fn cursor_mut(&mut self) -> &mut Cursor {
+        let cursor = self.cursor.coords;
+        self.damage_cell(cursor);
+        self.damage.add_cursor();
         &mut self.cursor
     }
 
+    /// The position of the cursor relative to the top left corner of the visible part of the
+    /// grid, or `None` if the cursor is not visible.
+    pub fn cursor_in_view(&self) -> Option<Coords> {
+        let bounds = self.view.bounds();
+        let Coords { x, y } = self.cursor.coords;
+        if bounds.contains(self.cursor.coords) {
+            Some(Coords { x: x - bounds.left, y: y - bounds.top })
+        } else { None }
+    }
+
+    /// The region of the grid which is visible.
+    pub fn view(&self) -> Region {
+        self.view.bounds()
+    }
+
+    /// Every tooltip in the grid, with the grid coordinates of the cell it is attached to.
+    pub fn tooltips(&self) -> hash_map::Iter<Coords, Tooltip> {
+        self.tooltips.iter()
+    }
+
     pub fn tooltip_at(&self, coords: Coords) -> Option<&Tooltip> {
         self.tooltips.get(&coords)
     }
@@ -147,14 +292,91 @@ impl<T> CharGrid<T> {
 
     pub fn add_tooltip(&mut self, coords: Coords, tooltip: String) {
         self.tooltips.insert(coords, Tooltip::Basic(tooltip));
+        self.damage_cell(coords);
     }
 
     pub fn add_drop_down(&mut self, coords: Coords, options: Vec<String>) {
         self.tooltips.insert(coords, Tooltip::Menu { options: options, position: None });
+        self.damage_cell(coords);
     }
 
     pub fn remove_tooltip(&mut self, coords: Coords) {
         self.tooltips.remove(&coords);
+        self.damage_cell(coords);
+    }
+
+    /// Take the damage to this grid since it was last taken, relative to the top left corner of
+    /// the visible part of the grid.
+    pub fn take_damage(&mut self) -> Damage {
+        mem::replace(&mut self.damage, Damage::default())
+    }
+}
+
+impl<T: Default + Pack> CharGrid<Grid<T>> {
+    pub fn scroll(&mut self, direction: Direction, n: u32) {
+        let evicted = self.grid.scroll(direction, n) as u32;
+        self.move_up_rows(evicted);
+        self.damage.add_all();
+    }
+
+    /// Discard all of the retained offscreen state of this grid, keeping only the cells which
+    /// are currently visible.
+    pub fn clear_scrollback(&mut self) {
+        let bounds = self.view.bounds();
+        self.grid.clear_scrollback(bounds);
+        self.view.move_to_origin();
+        let Coords { x, y } = self.cursor.coords;
+        self.cursor.coords = Coords { x: x - bounds.left, y: y - bounds.top };
+        self.tooltips = self.tooltips.drain().filter(|&(coords, _)| bounds.contains(coords))
+                            .map(|(Coords { x, y }, tooltip)| {
+                                (Coords { x: x - bounds.left, y: y - bounds.top }, tooltip)
+                            }).collect();
+        self.damage.add_all();
+    }
+
+    /// Change how many offscreen rows this grid keeps. If the grid holds more rows than the new
+    /// policy allows, the oldest rows are discarded at once, and everything below them moves up.
+    pub fn set_scrollback_policy(&mut self, scrollback: ScrollbackPolicy) {
+        let n = self.grid.set_scrollback_policy(scrollback) as u32;
+        if n == 0 { return; }
+        self.move_up_rows(n);
+        self.damage.add_all();
+    }
+
+    /// Move the view, the cursor and the tooltips up by `n` rows, after the oldest `n` rows of
+    /// the grid have been discarded.
+    fn move_up_rows(&mut self, n: u32) {
+        if n == 0 { return; }
+        let top = self.view.bounds().top;
+        self.view.move_to_row(top.saturating_sub(n));
+        let Coords { x, y } = self.cursor.coords;
+        self.cursor.coords = Coords { x: x, y: y.saturating_sub(n) };
+        self.tooltips = self.tooltips.drain().filter(|&(Coords { y, .. }, _)| y >= n)
+                            .map(|(Coords { x, y }, tooltip)| (Coords { x: x, y: y - n }, tooltip))
+                            .collect();
+    }
+
+    /// Restore the scrollback saved in the file at `path` into this grid, which must not have been
+    /// written to yet, and save its scrollback to that file from now on. The restored rows are
+    /// placed above the visible part of the grid.
+    pub fn restore_scrollback<P: AsRef<Path>>(&mut self, path: P) -> io::Result<()> {
+        try!(self.grid.restore_history(path.as_ref(), self.view.width() as usize));
+        let top = self.grid.height as u32;
+        self.view.move_to_row(top);
+        self.cursor.coords = Coords { x: 0, y: top };
+        self.damage.add_all();
+        Ok(())
+    }
+
+    /// Whether this grid saves its scrollback to a file.
+    pub fn has_scrollback_file(&self) -> bool {
+        self.grid.has_history()
+    }
+
+    /// Save all of this grid to its scrollback file, if it has one, so that it can be restored
+    /// after the terminal closes.
+    pub fn sync_scrollback(&mut self) -> io::Result<()> {
+        self.grid.sync_history()
     }
 }
 
@@ -166,7 +388,63 @@ impl<T: ConstructGrid> ConstructGrid for CharGrid<T> {
             view: View::new(settings),
             tooltips: HashMap::new(),
             text_styles: UseStyles::default(),
+            damage: Damage::default(),
+            tab_stop: settings.tab_stop,
+        }
+    }
+}
+
+impl<T: Persist + Resizeable> Persist for CharGrid<T> {
+    fn persist(&self, session: &mut SessionWriter) {
+        self.grid.persist(session);
+        self.cursor.persist(session);
+        self.view.persist(session);
+        session.write_uint(self.tooltips.len() as u64);
+        for (coords, tooltip) in &self.tooltips {
+            coords.persist(session);
+            tooltip.persist(session);
+        }
+        self.text_styles.persist(session);
+        session.write_uint(self.tab_stop as u64);
+    }
+
+    fn restore(session: &mut SessionReader) -> Option<CharGrid<T>> {
+        let grid = match T::restore(session) { Some(grid) => grid, None => return None };
+        let cursor = match Cursor::restore(session) { Some(cursor) => cursor, None => return None };
+        let view = match View::restore(session) { Some(view) => view, None => return None };
+        // The view must start within the rows and columns the grid holds, and the cursor must be
+        // within the grid or the view; anything else could not have been saved.
+        let ((width, height), bounds) = (grid.dims(), view.bounds());
+        if bounds.width() == 0 || bounds.height() == 0 || bounds.width() as usize > MAX_ROW_WIDTH
+                || bounds.height() as usize > MAX_ROW_WIDTH || bounds.left > width
+                || bounds.top > height || cursor.coords.x >= cmp::max(width, bounds.right)
+                || cursor.coords.y >= cmp::max(height, bounds.bottom) {
+            return None;
+        }
+        let count = match session.read_uint() { Some(n) => n, None => return None };
+        let mut tooltips = HashMap::new();
+        for _ in 0..count {
+            match (Coords::restore(session), Tooltip::restore(session)) {
+                (Some(coords), Some(tooltip))   => { tooltips.insert(coords, tooltip); }
+                _                               => return None,
+            }
         }
+        let text_styles = match UseStyles::restore(session) { Some(s) => s, None => return None };
+        let tab_stop = match session.read_uint() {
+            Some(n) if n <= u32::MAX as u64 => n as u32,
+            _                               => return None,
+        };
+        let mut damage = Damage::default();
+        damage.add_all();
+        Some(CharGrid {
+            grid: grid,
+            cursor: cursor,
+            view: view,
+            tooltips: tooltips,
+            text_styles: text_styles,
+            damage: damage,
+            tab_stop: tab_stop,
+        })
     }
 }
 
@@ -178,11 +456,13 @@ impl<T: Resizeable> Resizeable for CharGrid<T> {
     fn resize_width(&mut self, width: u32) {
         self.view.resize_width(width);
         self.grid.resize_width(width);
+        self.damage.add_all();
     }
 
     fn resize_height(&mut self, height: u32) {
         self.view.resize_height(height);
         self.grid.resize_height(height);
+        self.damage.add_all();
     }
 }
 
@@ -196,11 +476,17 @@ impl<T> Styleable for CharGrid<T> {
     }
 }
 
+/// A grid is indexed relative to the top left corner of its view, not of the grid itself, so
+/// that the screen can index each panel by the panel's own coordinates whichever part of its grid
+/// is in view; `Screen::cells`, `Screen::snapshot` and the renderers all rely on this. Indexing
+/// outside of the grid gives an empty cell. Use `cell_at` to look a cell up by grid coordinates.
 impl<T: CellGrid<Cell=CharCell>> Index<Coords> for CharGrid<T> {
     type Output = CharCell;
 
     fn index(&self, coords: Coords) -> &CharCell {
         static DEFAULT_CELL: &'static CharCell = &EMPTY_CELL;
+        let bounds = self.view.bounds();
+        let coords = Coords { x: coords.x + bounds.left, y: coords.y + bounds.top };
         self.grid.get(coords).unwrap_or(DEFAULT_CELL)
     }
 }
@@ -224,13 +510,148 @@ impl<T> DerefMut for CharGrid<T> {
 
 impl<T> CharGrid<T> {
     fn iterate_over_area(&self, area: Area) -> CoordsIter {
-        CoordsIter::from_area(area, self.cursor.coords, self.view.bounds())
+        CoordsIter::from_area(area, self.cursor.coords, self.view.bounds(), self.tab_stop)
+    }
+
+    /// Move the cursor, damaging the cells it moves between, and move the view to keep the
+    /// cursor visible.
+    fn move_cursor_to(&mut self, coords: Coords) {
+        let old = self.cursor.coords;
+        if coords != old {
+            self.damage_cell(old);
+            self.damage.add_cursor();
+        }
+        self.cursor.coords = coords;
+        let bounds = self.view.bounds();
+        self.view.keep_within(coords);
+        if self.view.bounds() != bounds {
+            self.damage.add_all();
+        }
+        self.damage_cell(coords);
+    }
+
+    fn damage_area(&mut self, area: Area) {
+        for coords in self.iterate_over_area(area) {
+            self.damage_cell(coords);
+        }
+    }
+
+    /// Damage the cell at these grid coordinates, if it is visible.
+    fn damage_cell(&mut self, coords: Coords) {
+        let bounds = self.view.bounds();
+        if bounds.contains(coords) {
+            self.damage.add_cell(Coords { x: coords.x - bounds.left, y: coords.y - bounds.top });
+        }
+    }
+}
+
+/// Wraps a grid while data is written to it, recording each cell written to as damage.
+struct DamageRecorder<'a, G: 'a> {
+    grid: &'a mut G,
+    damage: &'a mut Damage,
+    view: Region,
+}
+
+impl<'a, G: WriteableGrid> WriteableGrid for DamageRecorder<'a, G> {
+    type Cell = G::Cell;
+
+    fn writeable(&mut self, coords: Coords) -> Option<&mut G::Cell> {
+        if self.view.contains(coords) {
+            let Coords { x, y } = coords;
+            self.damage.add_cell(Coords { x: x - self.view.left, y: y - self.view.top });
+        }
+        self.grid.writeable(coords)
+    }
+
+    fn best_fit_for_region(&self, region: Region) -> Coords {
+        self.grid.best_fit_for_region(region)
+    }
+
+    fn find_cell_to_extend(&self, coords: Coords) -> Option<Coords> {
+        self.grid.find_cell_to_extend(coords)
     }
 }
 
 impl<T: CellGrid> CharGrid<T> {
     fn calculate_movement(&self, coords: Coords, movement: Movement) -> Coords {
-        let new_coords = move_within(self.cursor.coords, movement, self.view.bounds());
+        let new_coords = move_within(coords, movement, self.row_bounds(coords.y),
+                                     self.tab_stop);
+        let right = self.row_bounds(new_coords.y).right;
+        let new_coords = Coords { x: cmp::min(new_coords.x, right - 1), ..new_coords };
         self.grid.move_out_of_extension(new_coords, movement.direction(coords))
     }
+
+    /// The visible region of the grid, narrowed to the columns which are used in row `y`.
+    fn row_bounds(&self, y: u32) -> Region {
+        let bounds = self.view.bounds();
+        match self.grid.line_size(y) {
+            LineSize::Normal    => bounds,
+            _                   => {
+                Region { right: bounds.left + cmp::max(bounds.width() / 2, 1), ..bounds }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cfg::TerminalConfig;
+    use datatypes::{Area, Coords, LineSize};
+    use terminal::interfaces::ConstructGrid;
+    use terminal::session::{self, Persist};
+
+    use super::{CharGrid, WideChar};
+
+    fn restore(grid: &CharGrid) -> bool {
+        let mut data = vec![];
+        session::save(&mut data, |session| grid.persist(session)).unwrap();
+        session::restore(&mut &data[..], CharGrid::restore).is_ok()
+    }
+
+    #[test]
+    fn restore_rejects_cursor_and_view_outside_grid() {
+        let mut grid: CharGrid = CharGrid::new(TerminalConfig::default().grid_settings(8, 2, true));
+        grid.write('a');
+        assert!(restore(&grid));
+        grid.cursor.coords = Coords { x: 0, y: 1 << 20 };
+        assert!(!restore(&grid));
+        grid.cursor.coords = Coords { x: 0, y: 0 };
+        grid.view.move_to_row(1 << 20);
+        assert!(!restore(&grid));
+    }
+
+    #[test]
+    fn write_str_matches_writing_each_char() {
+        let new = || -> CharGrid {
+            CharGrid::new(TerminalConfig::default().grid_settings(5, 3, true))
+        };
+        let (mut by_str, mut by_char) = (new(), new());
+        for grid in &mut [&mut by_str, &mut by_char] {
+            grid.write(WideChar::new('\u{4e00}', 2));
+            grid.write(WideChar::new('\u{4e00}', 2));
+            grid.cursor.coords = Coords { x: 0, y: 0 };
+        }
+        by_str.write_str("abcdefghijklmn", 1);
+        for ch in "abcdefghijklmn".chars() { by_char.write(ch); }
+        by_str.write_str("\u{4e00}\u{4e01}\u{4e02}", 2);
+        for ch in "\u{4e00}\u{4e01}\u{4e02}".chars() { by_char.write(WideChar::new(ch, 2)); }
+        assert_eq!(by_str.cursor.coords, by_char.cursor.coords);
+        for y in 0..by_char.grid.height as u32 {
+            assert_eq!(by_str.is_wrapped(y), by_char.is_wrapped(y));
+            for x in 0..5 {
+                let coords = Coords { x: x, y: y };
+                assert_eq!(by_str.cell_at(coords), by_char.cell_at(coords));
+            }
+        }
+    }
+
+    #[test]
+    fn erasing_whole_screen_resets_line_sizes() {
+        let mut grid: CharGrid = CharGrid::new(TerminalConfig::default().grid_settings(8, 2, true));
+        grid.set_line_size(LineSize::DoubleWidth);
+        grid.erase(Area::BelowCursor(true));
+        assert_eq!(grid.line_size_in_view(0), LineSize::DoubleWidth);
+        grid.erase(Area::WholeScreen);
+        assert_eq!(grid.line_size_in_view(0), LineSize::Normal);
+    }
 }
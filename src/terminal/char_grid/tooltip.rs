@@ -16,9 +16,11 @@
 use std::fmt;
 
 use datatypes::Key;
+use terminal::session::{Persist, SessionReader, SessionWriter};
 
 use self::Tooltip::*;
 
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub enum Tooltip {
     Basic(String),
     Menu {
@@ -52,6 +54,46 @@ impl Tooltip {
     }
 }
 
+impl Persist for Tooltip {
+    fn persist(&self, session: &mut SessionWriter) {
+        match *self {
+            Basic(ref s)                            => {
+                session.write_u8(0);
+                session.write_str(s);
+            }
+            Menu { ref options, position }          => {
+                session.write_u8(1);
+                session.write_uint(options.len() as u64);
+                for option in options {
+                    session.write_str(option);
+                }
+                session.write_option(position);
+            }
+        }
+    }
+
+    fn restore(session: &mut SessionReader) -> Option<Tooltip> {
+        match session.read_u8() {
+            Some(0) => session.read_str().map(|s| Basic(String::from(s))),
+            Some(1) => {
+                let count = match session.read_uint() { Some(n) => n, None => return None };
+                let mut options = vec![];
+                for _ in 0..count {
+                    match session.read_str() {
+                        Some(option)    => options.push(String::from(option)),
+                        None            => return None,
+                    }
+                }
+                session.read_option().map(|position| Menu {
+                    options: options,
+                    position: position,
+                })
+            }
+            _       => None,
+        }
+    }
+}
+
 impl fmt::Display for Tooltip {
     fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
         match *self {
@@ -1,5 +1,6 @@
 use datatypes::{Coords, Region, GridSettings, Flow};
 use terminal::interfaces::{ConstructGrid, Resizeable};
+use terminal::session::{Persist, SessionReader, SessionWriter};
 
 use self::View::*;
 
@@ -41,18 +42,20 @@ impl Resizeable for View {
     }
 }
 
-impl View {
-    pub fn translate(&self, Coords { x, y }: Coords) -> Coords {
+impl Persist for View {
+    fn persist(&self, session: &mut SessionWriter) {
         match *self {
-            Moveable(region)    => {
-                let coords = Coords { x: x + region.left, y: y + region.top };
-                assert!(region.contains(coords));
-                coords
-            }
+            Moveable(region)    => region.persist(session),
             Reflowable(_)       => unimplemented!()
         }
     }
 
+    fn restore(session: &mut SessionReader) -> Option<View> {
+        Region::restore(session).map(Moveable)
+    }
+}
+
+impl View {
     pub fn width(&self) -> u32 {
         match *self {
             Moveable(region)    => region.width(),
@@ -74,6 +77,29 @@ impl View {
         }
     }
 
+    /// Move the view down so that its top edge is at row `top` of the grid.
+    pub fn move_to_row(&mut self, top: u32) {
+        match *self {
+            Moveable(ref mut region) => {
+                *region = Region::new(region.left, top, region.right, top + region.height());
+            }
+            Reflowable(_) => {
+                unimplemented!()
+            }
+        }
+    }
+
+    pub fn move_to_origin(&mut self) {
+        match *self {
+            Moveable(ref mut region) => {
+                *region = Region::new(0, 0, region.width(), region.height());
+            }
+            Reflowable(_) => {
+                unimplemented!()
+            }
+        }
+    }
+
     pub fn keep_within(&mut self, coords: Coords) {
         match *self {
             Moveable(ref mut region) => {
@@ -3,15 +3,14 @@ use terminal::{CharData, CellData, UseStyles};
 use terminal::interfaces::{WriteableGrid, WriteableCell};
 
 impl CharData for char {
-    fn write<T>(&self, coords: Coords, styles: UseStyles, grid: &mut T) -> Coords
+    fn write<T>(self, coords: Coords, styles: UseStyles, grid: &mut T) -> Coords
     where T: WriteableGrid, T::Cell: WriteableCell {
         if let Some(cell) = grid.writeable(coords) {
-            cell.write(CellData::Char(*self), styles);
+            cell.write(CellData::Char(self), styles);
         }
         coords
     }
 
-    #[cfg(any(debug_assertions, test))]
     fn repr(&self) -> String {
         self.to_string()
     }
@@ -26,7 +25,7 @@ impl WideChar {
 }
 
 impl CharData for WideChar {
-    fn write<T>(&self, coords: Coords, styles: UseStyles, grid: &mut T) -> Coords
+    fn write<T>(self, coords: Coords, styles: UseStyles, grid: &mut T) -> Coords
     where T: WriteableGrid, T::Cell: WriteableCell {
         let coords = grid.best_fit_for_region(Region::new(coords.x, coords.y, coords.x + self.1, coords.y + 1));
         if let Some(cell) = grid.writeable(coords) {
@@ -40,29 +39,42 @@ impl CharData for WideChar {
         Coords { x: coords.x + self.1 - 1, y: coords.y }
     }
 
-    #[cfg(any(debug_assertions, test))]
     fn repr(&self) -> String {
         self.0.to_string()
     }
 }
 
-pub struct CharExtender(pub char);
+/// A character which extends the grapheme cluster written before it. The second field is the
+/// width of the cluster once it has been extended; a cluster which becomes wider than the cells
+/// it occupies, such as a narrow emoji followed by an emoji presentation selector, is spread
+/// over the cells after it.
+pub struct CharExtender(pub char, pub u32);
 
 impl CharExtender {
-    pub fn new(ch: char) -> CharExtender {
-        CharExtender(ch)
+    pub fn new(ch: char, width: u32) -> CharExtender {
+        CharExtender(ch, width)
     }
 }
 
 impl CharData for CharExtender {
-    fn write<T>(&self, coords: Coords, styles: UseStyles, grid: &mut T) -> Coords
+    fn write<T>(self, coords: Coords, styles: UseStyles, grid: &mut T) -> Coords
     where T: WriteableGrid, T::Cell: WriteableCell {
         match grid.find_cell_to_extend(coords) {
             Some(coords)    => {
                 if let Some(cell) = grid.writeable(coords) {
                     cell.extend(self.0, styles);
                 }
-                coords
+                let mut last = coords;
+                for extension_coords in (1..self.1).map(|i| Coords { x: coords.x + i, ..coords }) {
+                    match grid.writeable(extension_coords) {
+                        Some(cell)  => if cell.source() != Some(coords) {
+                            cell.write(CellData::Extension(coords), styles);
+                        },
+                        None        => break,
+                    }
+                    last = extension_coords;
+                }
+                last
             }
             None            => {
                 if let Some(cell) = grid.writeable(coords) {
@@ -73,7 +85,6 @@ impl CharData for CharExtender {
         }
     }
 
-    #[cfg(any(debug_assertions, test))]
     fn repr(&self) -> String {
         self.0.to_string()
     }
@@ -98,7 +98,7 @@ mod test_char_extender {
 
     pub const CHAR: char = '$';
     pub const DATA: CellData = CellData::Char(CHAR);
-    pub const CHAR_EXTENDER: CharExtender = CharExtender(CHAR);
+    pub const CHAR_EXTENDER: CharExtender = CharExtender(CHAR, 1);
     pub const COORDS_BEFORE: Coords = Coords { x: 0, y: 0 };
 
     mod extendable_cell {
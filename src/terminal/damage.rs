@@ -0,0 +1,186 @@
+//  notty is a new kind of terminal emulator.
+//  Copyright (C) 2015 without boats
+//  
+//  This program is free software: you can redistribute it and/or modify
+//  it under the terms of the GNU Affero General Public License as published by
+//  the Free Software Foundation, either version 3 of the License, or
+//  (at your option) any later version.
+//  
+//  This program is distributed in the hope that it will be useful,
+//  but WITHOUT ANY WARRANTY; without even the implied warranty of
+//  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+//  GNU Affero General Public License for more details.
+//  
+//  You should have received a copy of the GNU Affero General Public License
+//  along with this program.  If not, see <http://www.gnu.org/licenses/>.
+use std::cmp;
+use std::collections::BTreeMap;
+use std::collections::btree_map;
+
+use datatypes::{Coords, Region};
+
+/// The parts of the screen which have changed since the damage was last taken, so that a
+/// renderer only needs to redraw those parts.
+///
+/// Damaged cells are recorded as a span of columns in each row. Changes which affect the whole
+/// screen - such as scrolling, resizing, or changing the layout of the panels - damage every
+/// cell.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Damage {
+    rows: BTreeMap<u32, (u32, u32)>,
+    all: bool,
+    cursor: bool,
+    layout: bool,
+    reset: bool,
+}
+
+impl Damage {
+    pub fn add_cell(&mut self, Coords { x, y }: Coords) {
+        self.add_span(y, x, x + 1);
+    }
+
+    /// Damage the columns from `left` up to but not including `right` in row `y`.
+    pub fn add_span(&mut self, y: u32, left: u32, right: u32) {
+        if self.all || left >= right { return; }
+        let span = self.rows.entry(y).or_insert((left, right));
+        *span = (cmp::min(span.0, left), cmp::max(span.1, right));
+    }
+
+    pub fn add_region(&mut self, region: Region) {
+        for y in region.top..region.bottom {
+            self.add_span(y, region.left, region.right);
+        }
+    }
+
+    /// Damage every cell.
+    pub fn add_all(&mut self) {
+        self.all = true;
+        self.rows.clear();
+    }
+
+    /// The position or style of the cursor has changed.
+    pub fn add_cursor(&mut self) {
+        self.cursor = true;
+    }
+
+    /// The panels of the screen have been split, unsplit, resized, rotated or switched.
+    pub fn add_layout(&mut self) {
+        self.layout = true;
+        self.add_all();
+    }
+
+    /// The terminal has been hard reset. Renderers should also discard any state they keep for
+    /// the program running in the terminal, such as colors it has changed in the palette.
+    pub fn add_reset(&mut self) {
+        self.reset = true;
+        self.add_layout();
+    }
+
+    /// Add damage taken from a panel occupying `area` of the screen, whose damage is recorded
+    /// relative to its top left corner.
+    pub fn merge(&mut self, damage: Damage, area: Region) {
+        self.cursor |= damage.cursor;
+        self.layout |= damage.layout;
+        self.reset |= damage.reset;
+        if damage.all {
+            self.add_region(area);
+        } else {
+            for (y, (left, right)) in damage.rows {
+                if y >= area.height() { break; }
+                let right = cmp::min(right, area.width());
+                self.add_span(y + area.top, left + area.left, right + area.left);
+            }
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        !self.all && !self.cursor && !self.layout && !self.reset && self.rows.is_empty()
+    }
+
+    /// Whether every cell has been damaged.
+    pub fn is_all(&self) -> bool {
+        self.all
+    }
+
+    pub fn cursor(&self) -> bool {
+        self.cursor
+    }
+
+    pub fn layout(&self) -> bool {
+        self.layout
+    }
+
+    pub fn reset(&self) -> bool {
+        self.reset
+    }
+
+    pub fn is_row_damaged(&self, y: u32) -> bool {
+        self.all || self.rows.contains_key(&y)
+    }
+
+    /// The damaged span of each row, as a region one row high, from the top of the screen
+    /// down. This is empty if every cell has been damaged.
+    pub fn rows(&self) -> DamagedRows {
+        DamagedRows { iter: self.rows.iter() }
+    }
+}
+
+/// An iterator over the damaged spans of a `Damage`.
+pub struct DamagedRows<'a> {
+    iter: btree_map::Iter<'a, u32, (u32, u32)>,
+}
+
+impl<'a> Iterator for DamagedRows<'a> {
+    type Item = Region;
+
+    fn next(&mut self) -> Option<Region> {
+        self.iter.next().map(|(&y, &(left, right))| Region::new(left, y, right, y + 1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use datatypes::{Coords, Region};
+
+    use super::Damage;
+
+    #[test]
+    fn spans_merge_within_a_row() {
+        let mut damage = Damage::default();
+        damage.add_cell(Coords { x: 4, y: 1 });
+        damage.add_span(1, 1, 3);
+        damage.add_cell(Coords { x: 0, y: 2 });
+        assert_eq!(damage.rows().collect::<Vec<_>>(),
+                   vec![Region::new(1, 1, 5, 2), Region::new(0, 2, 1, 3)]);
+        assert!(damage.is_row_damaged(1));
+        assert!(!damage.is_row_damaged(3));
+    }
+
+    #[test]
+    fn merge_translates_into_area() {
+        let mut panel = Damage::default();
+        panel.add_span(0, 2, 10);
+        panel.add_span(6, 0, 1);
+        panel.add_cursor();
+        let mut damage = Damage::default();
+        damage.merge(panel, Region::new(4, 2, 8, 6));
+        assert_eq!(damage.rows().collect::<Vec<_>>(), vec![Region::new(6, 2, 8, 3)]);
+        assert!(damage.cursor());
+        let mut panel = Damage::default();
+        panel.add_all();
+        damage.merge(panel, Region::new(4, 2, 8, 4));
+        assert_eq!(damage.rows().count(), 2);
+        assert!(!damage.is_all());
+    }
+
+    #[test]
+    fn layout_damages_everything() {
+        let mut damage = Damage::default();
+        damage.add_cell(Coords { x: 0, y: 0 });
+        damage.add_layout();
+        assert!(damage.is_all());
+        assert!(damage.layout());
+        assert_eq!(damage.rows().count(), 0);
+        assert!(damage.is_row_damaged(100));
+    }
+}
@@ -0,0 +1,374 @@
+//  notty is a new kind of terminal emulator.
+//  Copyright (C) 2015 without boats
+//  
+//  This program is free software: you can redistribute it and/or modify
+//  it under the terms of the GNU Affero General Public License as published by
+//  the Free Software Foundation, either version 3 of the License, or
+//  (at your option) any later version.
+//  
+//  This program is distributed in the hope that it will be useful,
+//  but WITHOUT ANY WARRANTY; without even the implied warranty of
+//  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+//  GNU Affero General Public License for more details.
+//  
+//  You should have received a copy of the GNU Affero General Public License
+//  along with this program.  If not, see <http://www.gnu.org/licenses/>.
+use std::borrow::Cow;
+use std::cmp;
+
+use base64;
+
+use datatypes::{Color, ConfigStyle, Region};
+use terminal::{CellData, CharCell, CharGrid, Styles, UseStyles, DEFAULT_STYLES, EMPTY_CELL};
+use terminal::interfaces::Styleable;
+
+/// The part of the active grid to export.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum ExportRange {
+    /// The part of the grid which is visible.
+    Screen,
+    /// Every row of the grid, including the rows which have scrolled out of view.
+    Scrollback,
+    /// A region of the visible part of the grid, relative to its top left corner.
+    Selection(Region),
+}
+
+/// The format of an export.
+#[derive(Copy, Clone)]
+pub enum ExportFormat<'a> {
+    /// Plain text, with rows which were soft-wrapped joined into a single line.
+    Text,
+    /// Text with SGR escape codes which reproduce the styles of each cell.
+    Ansi,
+    /// A standalone HTML document, with colors resolved through the palette. Images are
+    /// embedded as data URIs.
+    Html(&'a Palette),
+}
+
+/// The colors and configured styles which the renderer uses, so that an export can show text
+/// the way the renderer would.
+pub trait Palette {
+    fn styles(&self, style: ConfigStyle) -> Styles;
+    fn fg_color(&self, color: Color) -> (u8, u8, u8);
+    fn bg_color(&self, color: Color) -> (u8, u8, u8);
+}
+
+impl CharGrid {
+    /// Export part of this grid as text. Trailing blank cells are left out of each row. Images
+    /// are only included in HTML exports.
+    pub fn export(&self, range: ExportRange, format: ExportFormat) -> String {
+        let rows = self.export_rows(self.export_region(range));
+        match format {
+            ExportFormat::Text          => text(&rows),
+            ExportFormat::Ansi          => ansi(&rows),
+            ExportFormat::Html(palette) => html(&rows, palette),
+        }
+    }
+
+    fn export_region(&self, range: ExportRange) -> Region {
+        let view = self.view();
+        match range {
+            ExportRange::Screen             => view,
+            ExportRange::Scrollback         => {
+                let bottom = cmp::max(self.height as u32, view.bottom);
+                Region::new(view.left, 0, view.right, bottom)
+            }
+            ExportRange::Selection(region)  => {
+                let right = cmp::min(view.left + region.right, view.right);
+                let bottom = cmp::min(view.top + region.bottom, view.bottom);
+                Region::new(cmp::min(view.left + region.left, right),
+                            cmp::min(view.top + region.top, bottom), right, bottom)
+            }
+        }
+    }
+
+    fn export_rows(&self, region: Region) -> Vec<Row> {
+        static DEFAULT_CELL: &'static CharCell = &EMPTY_CELL;
+        let joins = region.right >= self.view().right;
+        (region.top..region.bottom).map(|y| {
+            let wrapped = joins && y + 1 < region.bottom && self.is_wrapped(y);
+            let row = self.row(y as usize).unwrap_or(Cow::Borrowed(&[]));
+            let mut cells = (region.left..region.right).map(|x| {
+                row.get(x as usize).unwrap_or(DEFAULT_CELL)
+            }).collect::<Vec<_>>();
+            if !wrapped {
+                while cells.last().map_or(false, |cell| is_blank(cell)) { cells.pop(); }
+            }
+            let mut runs: Vec<(UseStyles, Run)> = vec![];
+            for cell in cells {
+                let styles = *cell.styles();
+                let text = match *cell.content() {
+                    CellData::Empty             => String::from(" "),
+                    CellData::Char(c)           => c.to_string(),
+                    CellData::Grapheme(ref s)   => s.clone(),
+                    CellData::Extension(_)      => continue,
+                    CellData::Image { .. }      => {
+                        runs.push((styles, Run::Image(cell.content().clone())));
+                        continue
+                    }
+                };
+                if let Some(&mut (run_styles, Run::Text(ref mut s))) = runs.last_mut() {
+                    if run_styles == styles {
+                        s.push_str(&text);
+                        continue
+                    }
+                }
+                runs.push((styles, Run::Text(text)));
+            }
+            Row { runs: runs, wrapped: wrapped }
+        }).collect()
+    }
+}
+
+/// A row of an export, as runs of adjacent cells which share the same styles.
+struct Row {
+    runs: Vec<(UseStyles, Run)>,
+    /// Whether this row continues onto the next row without a line break.
+    wrapped: bool,
+}
+
+enum Run {
+    Text(String),
+    Image(CellData),
+}
+
+fn is_blank(cell: &CharCell) -> bool {
+    match *cell.content() {
+        CellData::Empty => *cell.styles() == DEFAULT_STYLES,
+        _               => false,
+    }
+}
+
+fn text(rows: &[Row]) -> String {
+    let mut out = String::new();
+    for row in rows {
+        for &(_, ref run) in &row.runs {
+            if let Run::Text(ref s) = *run { out.push_str(s); }
+        }
+        if !row.wrapped { out.push('\n'); }
+    }
+    out
+}
+
+/// Styles are reset at the end of every line, so that background colors do not extend to the
+/// right edge of the terminal the export is shown in.
+fn ansi(rows: &[Row]) -> String {
+    let mut out = String::new();
+    let mut current = DEFAULT_STYLES;
+    for row in rows {
+        for &(styles, ref run) in &row.runs {
+            if let Run::Text(ref s) = *run {
+                if styles != current {
+                    out.push_str(&sgr(styles));
+                    current = styles;
+                }
+                out.push_str(s);
+            }
+        }
+        if !row.wrapped {
+            if current != DEFAULT_STYLES {
+                out.push_str("\x1b[0m");
+                current = DEFAULT_STYLES;
+            }
+            out.push('\n');
+        }
+    }
+    out
+}
+
+fn sgr(styles: UseStyles) -> String {
+    let mut sgr = String::from("\x1b[0");
+    if let UseStyles::Custom(styles) = styles {
+        for &(flag, param) in &[(styles.bold, ";1"), (styles.italic, ";3"),
+                                (styles.underline, ";4"), (styles.blink, ";5"),
+                                (styles.inverted, ";7"), (styles.strikethrough, ";9"),
+                                (styles.double_underline, ";21")] {
+            if flag { sgr.push_str(param); }
+        }
+        sgr.push_str(&sgr_color(38, styles.fg_color));
+        sgr.push_str(&sgr_color(48, styles.bg_color));
+    }
+    sgr.push('m');
+    sgr
+}
+
+fn sgr_color(base: u8, color: Color) -> String {
+    match color {
+        Color::Default          => String::new(),
+        Color::Palette(n)       => format!(";{};5;{}", base, n),
+        Color::True(r, g, b)    => format!(";{};2;{};{};{}", base, r, g, b),
+    }
+}
+
+fn html(rows: &[Row], palette: &Palette) -> String {
+    let mut out = format!("<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n</head>\n\
+                           <body style=\"background-color: {}; color: {}\">\n<pre>\n",
+                          hex(palette.bg_color(Color::Default)),
+                          hex(palette.fg_color(Color::Default)));
+    for row in rows {
+        for &(styles, ref run) in &row.runs {
+            let styles = match styles {
+                UseStyles::Custom(styles)   => styles,
+                UseStyles::Config(style)    => palette.styles(style),
+            };
+            match *run {
+                Run::Text(ref s)    => {
+                    out.push_str(&format!("<span style=\"{}\">{}</span>", css(styles, palette),
+                                          escape(s)));
+                }
+                Run::Image(CellData::Image { ref data, ref mime, width, height, .. }) => {
+                    let data = base64::u8en(&data.data).ok()
+                                      .and_then(|data| String::from_utf8(data).ok())
+                                      .unwrap_or_else(String::new);
+                    out.push_str(&format!("<img src=\"data:{};base64,{}\" style=\"width: {}ch; \
+                                           height: {}em; vertical-align: top\">",
+                                          escape(&mime.to_string()), data, width, height));
+                }
+                Run::Image(_)       => unreachable!(),
+            }
+        }
+        if !row.wrapped { out.push('\n'); }
+    }
+    out.push_str("</pre>\n</body>\n</html>\n");
+    out
+}
+
+fn css(styles: Styles, palette: &Palette) -> String {
+    let fg = palette.fg_color(styles.fg_color);
+    let bg = palette.bg_color(styles.bg_color);
+    let (fg, bg) = if styles.inverted { (bg, fg) } else { (fg, bg) };
+    let mut css = format!("color: {}; background-color: {}", hex(fg), hex(bg));
+    if styles.bold { css.push_str("; font-weight: bold"); }
+    if styles.italic { css.push_str("; font-style: italic"); }
+    let decorations = [(styles.underline || styles.double_underline, "underline"),
+                       (styles.strikethrough, "line-through")];
+    let decorations = decorations.iter().filter(|&&(flag, _)| flag).map(|&(_, line)| line)
+                                 .collect::<Vec<_>>();
+    if !decorations.is_empty() {
+        css.push_str(&format!("; text-decoration: {}", decorations.join(" ")));
+    }
+    if styles.double_underline { css.push_str("; text-decoration-style: double"); }
+    if styles.opacity != 0xff {
+        css.push_str(&format!("; opacity: {:.2}", styles.opacity as f32 / 255.0));
+    }
+    css
+}
+
+fn hex((r, g, b): (u8, u8, u8)) -> String {
+    format!("#{:02x}{:02x}{:02x}", r, g, b)
+}
+
+fn escape(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            _   => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use mime::{Mime, SubLevel, TopLevel};
+
+    use cfg::TerminalConfig;
+    use datatypes::{Color, ConfigStyle, Coords, MediaPosition, Region, Style};
+    use terminal::{CharGrid, Image, Screen, Styles};
+    use terminal::interfaces::Styleable;
+
+    use super::*;
+
+    struct TestPalette;
+
+    impl Palette for TestPalette {
+        fn styles(&self, _: ConfigStyle) -> Styles {
+            Styles::default()
+        }
+
+        fn fg_color(&self, color: Color) -> (u8, u8, u8) {
+            match color {
+                Color::True(r, g, b)    => (r, g, b),
+                _                       => (0xff, 0xff, 0xff),
+            }
+        }
+
+        fn bg_color(&self, color: Color) -> (u8, u8, u8) {
+            match color {
+                Color::True(r, g, b)    => (r, g, b),
+                _                       => (0, 0, 0),
+            }
+        }
+    }
+
+    fn screen(text: &str) -> Screen<CharGrid> {
+        let mut screen = Screen::new(4, 3, TerminalConfig::default());
+        for c in text.chars() {
+            screen.write(c);
+        }
+        screen
+    }
+
+    #[test]
+    fn text_joins_wrapped_rows() {
+        let screen = screen("abcdef");
+        assert_eq!(screen.export(ExportRange::Screen, ExportFormat::Text), "abcdef\n\n");
+    }
+
+    #[test]
+    fn text_of_selection() {
+        let screen = screen("abcdef");
+        let selection = ExportRange::Selection(Region::new(1, 0, 3, 2));
+        assert_eq!(screen.export(selection, ExportFormat::Text), "bc\nf\n");
+    }
+
+    #[test]
+    fn text_of_cold_scrollback() {
+        let mut screen = Screen::new(4, 3, TerminalConfig::default());
+        screen.set_hot_rows(Some(0));
+        for c in "abcdefghijklmn".chars() {
+            screen.write(c);
+        }
+        assert_eq!(screen.cell_at(Coords { x: 0, y: 0 }).map(|cell| cell.repr()),
+                   Some(String::from("a")));
+        assert_eq!(screen.export(ExportRange::Scrollback, ExportFormat::Text),
+                   "abcdefghijklmn\n");
+    }
+
+    #[test]
+    fn ansi_reproduces_styles() {
+        let mut screen = screen("a");
+        screen.set_style(Style::Bold(true));
+        screen.set_style(Style::FgColor(Color::Palette(1)));
+        screen.write('b');
+        screen.reset_style();
+        screen.write('c');
+        let ansi = screen.export(ExportRange::Screen, ExportFormat::Ansi);
+        assert_eq!(ansi, "a\x1b[0;1;38;5;1mb\x1b[0mc\n\n\n");
+    }
+
+    #[test]
+    fn html_resolves_colors() {
+        let mut screen = screen("");
+        screen.set_style(Style::FgColor(Color::True(0x12, 0x34, 0x56)));
+        screen.write('<');
+        let html = screen.export(ExportRange::Screen, ExportFormat::Html(&TestPalette));
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(html.contains("<span style=\"color: #123456; background-color: #000000\">\
+                               &lt;</span>"));
+    }
+
+    #[test]
+    fn html_escapes_image_mime() {
+        let mut screen = screen("");
+        let mime = Mime(TopLevel::Image, SubLevel::Ext(String::from("png\"><script>")), vec![]);
+        screen.write(Image::new(b"image".to_vec(), mime, MediaPosition::default(), 1, 1));
+        let html = screen.export(ExportRange::Screen, ExportFormat::Html(&TestPalette));
+        assert!(html.contains("data:image/png&quot;&gt;&lt;script&gt;;base64,"));
+        assert!(!html.contains("<script>"));
+    }
+}
@@ -0,0 +1,438 @@
+//  notty is a new kind of terminal emulator.
+//  Copyright (C) 2015 without boats
+//  
+//  This program is free software: you can redistribute it and/or modify
+//  it under the terms of the GNU Affero General Public License as published by
+//  the Free Software Foundation, either version 3 of the License, or
+//  (at your option) any later version.
+//  
+//  This program is distributed in the hope that it will be useful,
+//  but WITHOUT ANY WARRANTY; without even the implied warranty of
+//  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+//  GNU Affero General Public License for more details.
+//  
+//  You should have received a copy of the GNU Affero General Public License
+//  along with this program.  If not, see <http://www.gnu.org/licenses/>.
+use std::sync::Arc;
+
+use mime::Mime;
+
+use datatypes::{Coords, LineSize, MediaPosition, Region};
+use terminal::{CellData, CharCell, CharGrid, ImageData, Tooltip, UseStyles};
+use terminal::interfaces::Styleable;
+
+/// An owned snapshot of everything which is visible on the screen. A frame does not borrow the
+/// terminal, so it can be drawn while more commands are applied, sent to another thread, or
+/// compared with an earlier frame. Cloning a frame is cheap, because its contents are shared.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Frame {
+    inner: Arc<FrameData>,
+}
+
+#[derive(Debug, PartialEq)]
+struct FrameData {
+    width: u32,
+    height: u32,
+    rows: Vec<Vec<StyledRun>>,
+    cursor: Option<FrameCursor>,
+    panels: Vec<PanelArea>,
+    images: Vec<PlacedImage>,
+    tooltips: Vec<(Coords, Tooltip)>,
+}
+
+impl Frame {
+    pub fn dims(&self) -> (u32, u32) {
+        (self.inner.width, self.inner.height)
+    }
+
+    /// The runs of each row of the screen, from top to bottom. The runs in each row are in
+    /// order from left to right; columns which are not covered by any run are covered by an
+    /// image which begins on an earlier row.
+    pub fn rows(&self) -> &[Vec<StyledRun>] {
+        &self.inner.rows
+    }
+
+    /// The cursor of the active panel, if it is visible.
+    pub fn cursor(&self) -> Option<&FrameCursor> {
+        self.inner.cursor.as_ref()
+    }
+
+    /// The area and tag of each visible panel.
+    pub fn panels(&self) -> &[PanelArea] {
+        &self.inner.panels
+    }
+
+    /// Every image whose top left corner is visible.
+    pub fn images(&self) -> &[PlacedImage] {
+        &self.inner.images
+    }
+
+    /// The tooltips attached to visible cells, with the position of those cells.
+    pub fn tooltips(&self) -> &[(Coords, Tooltip)] {
+        &self.inner.tooltips
+    }
+}
+
+/// A run of adjacent cells in one row of the screen.
+#[derive(Clone, Debug, PartialEq)]
+pub struct StyledRun {
+    /// The column of the screen at which the run begins.
+    pub column: u32,
+    /// The number of cells covered by the run.
+    pub width: u32,
+    pub styles: UseStyles,
+    pub content: RunContent,
+    /// The size of the line the run is in. A run in a line which is not of normal size covers
+    /// two columns of the screen for every cell of the grid, and its text is drawn scaled to fit.
+    pub line: LineSize,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum RunContent {
+    /// Text which fills one cell per character or grapheme. Empty cells are spaces.
+    Text(String),
+    /// A single character or grapheme which fills every cell of the run.
+    Wide(String),
+    /// An image whose top left corner is the first cell of the run.
+    Image(PlacedImage),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FrameCursor {
+    pub position: Coords,
+    pub styles: UseStyles,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct PanelArea {
+    pub tag: u64,
+    pub area: Region,
+    pub active: bool,
+}
+
+/// An image, and the area of the screen it covers. The area may extend past the bottom or right
+/// edge of the panel which holds the image.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PlacedImage {
+    pub area: Region,
+    pub data: Arc<ImageData>,
+    pub mime: Mime,
+    pub pos: MediaPosition,
+}
+
+/// Collects the visible panels of the screen into a `Frame`.
+pub(crate) struct FrameBuilder {
+    data: FrameData,
+}
+
+impl FrameBuilder {
+    pub fn new(area: Region) -> FrameBuilder {
+        FrameBuilder {
+            data: FrameData {
+                width: area.width(),
+                height: area.height(),
+                rows: vec![Vec::new(); area.height() as usize],
+                cursor: None,
+                panels: Vec::new(),
+                images: Vec::new(),
+                tooltips: Vec::new(),
+            }
+        }
+    }
+
+    /// Add a panel which fills `area` of the screen.
+    pub fn add_panel(&mut self, tag: u64, area: Region, active: bool, grid: &CharGrid) {
+        let offset = |Coords { x, y }: Coords| Coords { x: x + area.left, y: y + area.top };
+        self.data.panels.push(PanelArea { tag: tag, area: area, active: active });
+        if active {
+            self.data.cursor = grid.cursor_in_view().map(|coords| FrameCursor {
+                position: offset(match grid.line_size_in_view(coords.y) {
+                    LineSize::Normal    => coords,
+                    _                   => Coords { x: coords.x * 2, ..coords },
+                }),
+                styles: *grid.cursor().styles(),
+            });
+        }
+        let view = grid.view();
+        for (&coords, tooltip) in grid.tooltips() {
+            if view.contains(coords) {
+                let coords = Coords { x: coords.x - view.left, y: coords.y - view.top };
+                self.data.tooltips.push((offset(coords), tooltip.clone()));
+            }
+        }
+        for y in area.top..area.bottom {
+            let row = &mut self.data.rows[y as usize];
+            for run in panel_row(grid, area, y) {
+                if let RunContent::Image(ref image) = run.content {
+                    self.data.images.push(image.clone());
+                }
+                row.push(run);
+            }
+        }
+    }
+
+    pub fn finish(mut self) -> Frame {
+        for row in &mut self.data.rows {
+            row.sort_by_key(|run| run.column);
+        }
+        Frame { inner: Arc::new(self.data) }
+    }
+}
+
+/// An iterator over the rows of the screen, from top to bottom, yielding the runs of each row in
+/// order from left to right.
+pub struct StyledRows<'a> {
+    panels: Vec<(Region, &'a CharGrid)>,
+    y: u32,
+    height: u32,
+}
+
+impl<'a> StyledRows<'a> {
+    /// Iterate over the rows of a screen of `height` rows, which is filled by these panels.
+    pub(crate) fn new(panels: Vec<(Region, &'a CharGrid)>, height: u32) -> StyledRows<'a> {
+        StyledRows { panels: panels, y: 0, height: height }
+    }
+}
+
+impl<'a> Iterator for StyledRows<'a> {
+    type Item = Vec<StyledRun>;
+
+    fn next(&mut self) -> Option<Vec<StyledRun>> {
+        if self.y >= self.height { return None; }
+        let y = self.y;
+        self.y += 1;
+        let mut row = vec![];
+        for &(area, grid) in &self.panels {
+            if area.top <= y && y < area.bottom {
+                row.extend(panel_row(grid, area, y));
+            }
+        }
+        row.sort_by_key(|run| run.column);
+        Some(row)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = (self.height - self.y) as usize;
+        (len, Some(len))
+    }
+}
+
+impl<'a> ExactSizeIterator for StyledRows<'a> { }
+
+/// The runs of row `y` of the screen within a panel which fills `area` of the screen.
+fn panel_row(grid: &CharGrid, area: Region, y: u32) -> Vec<StyledRun> {
+    let line = grid.line_size_in_view(y - area.top);
+    let width = match line {
+        LineSize::Normal    => area.width(),
+        _                   => area.width() / 2,
+    };
+    let cells = (0..width).map(|x| &grid[Coords { x: x, y: y - area.top }])
+                          .collect::<Vec<_>>();
+    let mut runs = runs(&cells, area.left, y, grid.view().left);
+    if line != LineSize::Normal {
+        for run in &mut runs {
+            run.column = area.left + (run.column - area.left) * 2;
+            run.width *= 2;
+            run.line = line;
+        }
+    }
+    runs
+}
+
+/// Merge a row of cells which begins at column `left` of row `y` into runs. Adjacent text cells
+/// with the same styles share a run; wide characters and images each have a run of their own.
+/// `grid_left` is the column of the first cell in its grid, which extension cells refer to.
+///
+/// A cell is only wide if the cell after it extends it. Wide characters never span rows, so only
+/// the column of the extended cell is compared; this stays correct when whole rows are moved
+/// without their cells being rewritten.
+fn runs(cells: &[&CharCell], left: u32, y: u32, grid_left: u32) -> Vec<StyledRun> {
+    let mut runs: Vec<StyledRun> = vec![];
+    for (x, cell) in cells.iter().enumerate() {
+        let column = left + x as u32;
+        let styles = *cell.styles();
+        let is_wide = cells.get(x + 1).map_or(false, |next| match *next.content() {
+            CellData::Extension(source) => source.x == grid_left + x as u32,
+            _                           => false,
+        });
+        let text = match *cell.content() {
+            CellData::Empty                 => String::from(" "),
+            CellData::Char(c)               => c.to_string(),
+            CellData::Grapheme(ref s)       => s.clone(),
+            CellData::Extension(_)          => {
+                // Extend the wide character before this cell, if there is one; otherwise this
+                // cell is covered by an image.
+                if let Some(run) = runs.last_mut() {
+                    if let RunContent::Wide(_) = run.content {
+                        if run.column + run.width == column { run.width += 1; }
+                    }
+                }
+                continue
+            }
+            CellData::Image { ref data, ref mime, pos, width, height } => {
+                runs.push(StyledRun {
+                    column: column,
+                    width: width,
+                    styles: styles,
+                    content: RunContent::Image(PlacedImage {
+                        area: Region::new(column, y, column + width, y + height),
+                        data: data.clone(),
+                        mime: mime.clone(),
+                        pos: pos,
+                    }),
+                    line: LineSize::Normal,
+                });
+                continue
+            }
+        };
+        if is_wide {
+            runs.push(StyledRun {
+                column: column,
+                width: 1,
+                styles: styles,
+                content: RunContent::Wide(text),
+                line: LineSize::Normal,
+            });
+            continue
+        }
+        if let Some(run) = runs.last_mut() {
+            if run.styles == styles && run.column + run.width == column {
+                if let RunContent::Text(ref mut s) = run.content {
+                    s.push_str(&text);
+                    run.width += 1;
+                    continue
+                }
+            }
+        }
+        runs.push(StyledRun {
+            column: column,
+            width: 1,
+            styles: styles,
+            content: RunContent::Text(text),
+            line: LineSize::Normal,
+        });
+    }
+    runs
+}
+
+#[cfg(test)]
+mod tests {
+    use datatypes::{Coords, LineSize, ResizeRule, SaveGrid, SplitKind};
+    use cfg::TerminalConfig;
+    use terminal::{CharGrid, Screen, UseStyles, WideChar};
+    use terminal::interfaces::{Resizeable, WriteableCell};
+
+    use super::*;
+
+    fn run(column: u32, width: u32, content: RunContent) -> StyledRun {
+        StyledRun {
+            column: column,
+            width: width,
+            styles: UseStyles::default(),
+            content: content,
+            line: LineSize::Normal,
+        }
+    }
+
+    #[test]
+    fn snapshot_rows() {
+        let mut screen: Screen<CharGrid> = Screen::new(8, 2, TerminalConfig::default());
+        screen.write('a');
+        screen.write('b');
+        screen.write(WideChar::new('x', 2));
+        let frame = screen.snapshot();
+        assert_eq!(frame.dims(), (8, 2));
+        assert_eq!(frame.rows()[0], vec![
+            run(0, 2, RunContent::Text(String::from("ab"))),
+            run(2, 2, RunContent::Wide(String::from("x"))),
+            run(4, 4, RunContent::Text(String::from("    "))),
+        ]);
+        assert_eq!(frame.rows()[1], vec![run(0, 8, RunContent::Text(String::from("        ")))]);
+        assert_eq!(frame.cursor().map(|cursor| cursor.position), Some(Coords { x: 4, y: 0 }));
+    }
+
+    #[test]
+    fn panels_are_indexed_from_their_view() {
+        let mut screen: Screen<CharGrid> = Screen::new(2, 2, TerminalConfig::default());
+        for ch in "abcdef".chars() {
+            screen.write(ch);
+        }
+        {
+            let grid: &CharGrid = &screen;
+            assert_eq!(grid[Coords { x: 0, y: 0 }].repr(), "c");
+            assert_eq!(grid.cell_at(Coords { x: 0, y: 0 }).unwrap().repr(), "a");
+        }
+        assert_eq!(screen.cells().map(|cell| cell.repr()).collect::<String>(), "cdef");
+        let frame = screen.snapshot();
+        assert_eq!(frame.rows()[0], vec![run(0, 2, RunContent::Text(String::from("cd")))]);
+    }
+
+    #[test]
+    fn split_panels_are_indexed_from_their_view() {
+        let mut screen: Screen<CharGrid> = Screen::new(2, 3, TerminalConfig::default());
+        screen.split(SaveGrid::Left, SplitKind::Horizontal(1), ResizeRule::Percentage, None, 1, 2,
+                     true);
+        for ch in "abc".chars() {
+            screen.write(ch);
+        }
+        assert_eq!(screen.cells().map(|cell| cell.repr()).collect::<String>(), "c");
+        let frame = screen.snapshot();
+        assert_eq!(frame.rows()[0], vec![run(0, 2, RunContent::Text(String::from("c ")))]);
+    }
+
+    #[test]
+    fn extension_of_another_cell() {
+        let cell = |data| {
+            let mut cell = CharCell::default();
+            cell.write(data, UseStyles::default());
+            cell
+        };
+        let a = cell(CellData::Char('a'));
+        let ext = cell(CellData::Extension(Coords { x: 3, y: 0 }));
+        assert_eq!(runs(&[&a, &ext], 0, 0, 0),
+                   vec![run(0, 1, RunContent::Text(String::from("a")))]);
+        assert_eq!(runs(&[&a, &ext], 0, 0, 3),
+                   vec![run(0, 2, RunContent::Wide(String::from("a")))]);
+    }
+
+    #[test]
+    fn snapshot_double_width() {
+        let mut screen: Screen<CharGrid> = Screen::new(8, 2, TerminalConfig::default());
+        screen.set_line_size(LineSize::DoubleWidth);
+        for ch in "abcde".chars() {
+            screen.write(ch);
+        }
+        let frame = screen.snapshot();
+        assert_eq!(frame.rows()[0], vec![StyledRun {
+            line: LineSize::DoubleWidth,
+            ..run(0, 8, RunContent::Text(String::from("abcd")))
+        }]);
+        assert_eq!(frame.rows()[1], vec![run(0, 8, RunContent::Text(String::from("e       ")))]);
+        assert_eq!(frame.cursor().map(|cursor| cursor.position), Some(Coords { x: 1, y: 1 }));
+    }
+
+    #[test]
+    fn snapshot_panels() {
+        let mut screen: Screen<CharGrid> = Screen::new(8, 2, TerminalConfig::default());
+        screen.split(SaveGrid::Left, SplitKind::Vertical(4), ResizeRule::Percentage, None, 1, 2,
+                     true);
+        let frame = screen.snapshot();
+        assert_eq!(frame.panels().iter().map(|panel| (panel.tag, panel.active))
+                                        .collect::<Vec<_>>(), vec![(1, true), (2, false)]);
+        for row in frame.rows() {
+            assert!(row.windows(2).all(|runs| runs[0].column + runs[0].width <= runs[1].column));
+            assert_eq!(row.iter().map(|run| run.width).sum::<u32>(), 8);
+        }
+    }
+
+    #[test]
+    fn rows_match_snapshot() {
+        let mut screen: Screen<CharGrid> = Screen::new(8, 3, TerminalConfig::default());
+        screen.split(SaveGrid::Left, SplitKind::Horizontal(1), ResizeRule::Percentage, None, 1, 2,
+                     true);
+        screen.write('a');
+        let rows = screen.rows();
+        assert_eq!(rows.len(), screen.dims().1 as usize);
+        assert_eq!(rows.collect::<Vec<_>>(), screen.snapshot().rows().to_vec());
+    }
+}
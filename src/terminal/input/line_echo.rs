@@ -43,14 +43,8 @@ impl LineEcho {
             Char(c) if c == self.settings.lerase as char  => {
                 self.len = 0;
                 wrap(CommandSeries(vec![
-                    Command {
-                        inner: Box::new(Move::new(To(Left, self.position, true)))
-                               as Box<CommandTrait>
-                    },
-                    Command {
-                        inner: Box::new(Erase::new(CursorTo(To(Right, self.len, true))))
-                               as Box<CommandTrait>
-                    },
+                    Command { inner: Move::new(To(Left, self.position, true)).into() },
+                    Command { inner: Erase::new(CursorTo(To(Right, self.len, true))).into() },
                 ]))
             }
             Char(c) if c == self.settings.lnext as char   => unimplemented!(),
@@ -77,10 +71,8 @@ impl LineEcho {
                 self.position -= 1;
                 self.len -= 1;
                 wrap(CommandSeries(vec![
-                    Command {
-                        inner: Box::new(Move::new(To(Left, 1, false))) as Box<CommandTrait>
-                    },
-                    Command { inner: Box::new(RemoveChars::new(1)) as Box<CommandTrait> },
+                    Command { inner: Move::new(To(Left, 1, false)).into() },
+                    Command { inner: RemoveChars::new(1).into() },
                 ]))
             }
             Delete if self.position != self.len => {
@@ -101,8 +93,8 @@ impl LineEcho {
     }
 }
 
-fn wrap<T: CommandTrait>(cmd: T) -> Option<Command> {
-    Some(Command { inner: Box::new(cmd) as Box<CommandTrait> })
+fn wrap<T: Into<CommandKind>>(cmd: T) -> Option<Command> {
+    Some(Command { inner: cmd.into() })
 }
 
 #[cfg(test)]
@@ -17,6 +17,8 @@ use std::io::{self, Write};
 
 use Command;
 use datatypes::{InputSettings, Key};
+use datatypes::args::Argument;
+use terminal::session::{SessionReader, SessionWriter};
 
 mod buffer;
 mod ansi;
@@ -36,9 +38,18 @@ pub trait Tty: Write {
     fn set_winsize(&mut self, u16, u16) -> io::Result<()>;
 }
 
+/// A sink can be used as the tty of a terminal which is not connected to a controlling process,
+/// such as a terminal replaying a recording.
+impl Tty for io::Sink {
+    fn set_winsize(&mut self, _: u16, _: u16) -> io::Result<()> {
+        Ok(())
+    }
+}
+
 pub struct Input {
     tty: Box<Tty + Send>,
     mode: InputMode,
+    settings: InputSettings,
     paste_mode: PasteMode,
     modifiers: Modifiers,
 }
@@ -49,6 +60,7 @@ impl Input {
         Input {
             tty: Box::new(tty),
             mode: Ansi(false),
+            settings: InputSettings::Ansi(false),
             paste_mode: PasteMode::Silent,
             modifiers: Modifiers::new(),
         }
@@ -66,6 +78,10 @@ impl Input {
                 self.mode = ExtendedScreen(ScreenEcho::new(settings), Extended),
             InputSettings::BracketedPasteMode(_)        => (),
         };
+        match mode {
+            InputSettings::BracketedPasteMode(_)        => (),
+            _                                           => self.settings = mode,
+        }
         self.paste_mode = match mode {
             InputSettings::BracketedPasteMode(true)     => PasteMode::Bracketed,
             InputSettings::BracketedPasteMode(false)    => PasteMode::Silent,
@@ -74,6 +90,40 @@ impl Input {
         };
     }
 
+    /// Return to the initial input mode. If `hard` is false, only the application cursor keys
+    /// mode is reset; the extended input modes and bracketed paste mode are kept.
+    pub fn reset(&mut self, hard: bool) {
+        let reset_mode = match self.mode {
+            Ansi(_) => true,
+            _       => hard,
+        };
+        if reset_mode {
+            self.mode = Ansi(false);
+            self.settings = InputSettings::Ansi(false);
+        }
+        if hard { self.paste_mode = PasteMode::Silent; }
+    }
+
+    /// Save the input mode in a session. A mode is saved as the settings it was set with, so any
+    /// input buffered in line buffer mode is not saved.
+    pub fn persist_mode(&self, session: &mut SessionWriter) {
+        session.write_str(&self.settings.encode());
+        session.write_bool(match self.paste_mode {
+            PasteMode::Silent       => false,
+            PasteMode::Bracketed    => true,
+        });
+    }
+
+    /// Read an input mode saved by `persist_mode`, returning the settings it was set with and
+    /// whether bracketed paste mode was on.
+    pub fn restore_mode(session: &mut SessionReader) -> Option<(InputSettings, bool)> {
+        let settings = match session.read_str().and_then(|s| InputSettings::decode(Some(s), None)) {
+            Some(settings)  => settings,
+            None            => return None,
+        };
+        session.read_bool().map(|bracketed| (settings, bracketed))
+    }
+
     pub fn set_winsize(&mut self, width: u32, height: u32) -> io::Result<()> {
         self.tty.set_winsize(width as u16, height as u16)
     }
@@ -36,8 +36,8 @@ impl ScreenEcho {
         match key {
             Char(c) if c == self.settings.lerase as char  => {
                 wrap(CommandSeries(vec![
-                    Command { inner: Box::new(Move::new(ToEdge(Left))) as Box<CommandTrait> },
-                    Command { inner: Box::new(Erase::new(CursorRow)) as Box<CommandTrait> },
+                    Command { inner: Move::new(ToEdge(Left)).into() },
+                    Command { inner: Erase::new(CursorRow).into() },
                 ]))
             }
             Char(c) if c == self.settings.lnext as char   => unimplemented!(),
@@ -50,12 +50,8 @@ impl ScreenEcho {
             Enter       => wrap(Move::new(NextLine(1))),
             Backspace   => {
                 wrap(CommandSeries(vec![
-                    Command {
-                        inner: Box::new(Move::new(To(Left, 1, false))) as Box<CommandTrait>
-                    },
-                    Command {
-                        inner: Box::new(RemoveChars::new(1)) as Box<CommandTrait>
-                    }
+                    Command { inner: Move::new(To(Left, 1, false)).into() },
+                    Command { inner: RemoveChars::new(1).into() },
                 ]))
             }
             PageUp      => wrap(Move::new(PreviousLine(25))),
@@ -68,6 +64,6 @@ impl ScreenEcho {
     }
 }
 
-fn wrap<T: CommandTrait>(cmd: T) -> Option<Command> {
-    Some(Command { inner: Box::new(cmd) as Box<CommandTrait> })
+fn wrap<T: Into<CommandKind>>(cmd: T) -> Option<Command> {
+    Some(Command { inner: cmd.into() })
 }
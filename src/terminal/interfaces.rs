@@ -1,5 +1,7 @@
+use std::sync::Arc;
+
 use datatypes::*;
-use terminal::{CellData, UseStyles};
+use terminal::{CellData, ImageData, PackedRow, UseStyles};
 
 pub trait Styleable {
     fn styles(&self) -> &UseStyles;
@@ -30,10 +32,9 @@ pub trait ConstructGrid {
 }
 
 pub trait CharData: Send + 'static {
-    fn write<T>(&self, coords: Coords, styles: UseStyles, grid: &mut T) -> Coords
+    fn write<T>(self, coords: Coords, styles: UseStyles, grid: &mut T) -> Coords
     where T: WriteableGrid, T::Cell: WriteableCell;
 
-    #[cfg(any(test, debug_assertions))]
     fn repr(&self) -> String {
         String::from("DATA")
     }
@@ -43,8 +44,34 @@ pub trait CellGrid {
     type Cell;
     fn get(&self, coords: Coords) -> Option<&Self::Cell>;
     fn get_mut(&mut self, coords: Coords) -> Option<&mut Self::Cell>;
-    fn moveover(&mut self, from: Coords, to: Coords);
+    /// Move the cells of row `coords.y` from column `coords.x` up to column `right` along by `n`
+    /// columns, dropping the cells which pass `right` and leaving blank cells behind.
+    fn insert_blank_at(&mut self, coords: Coords, n: u32, right: u32);
+    /// Remove `n` cells from row `coords.y` at column `coords.x`, moving the cells up to column
+    /// `right` back to take their place and filling the end of that span with blank cells.
+    fn remove_at(&mut self, coords: Coords, n: u32, right: u32);
+    /// Move the rows from row `y` up to row `bottom` down by `n` rows, along with their wrapping
+    /// and line sizes, dropping the rows which pass `bottom` and leaving blank rows behind.
+    fn insert_rows_at(&mut self, y: u32, n: u32, bottom: u32);
+    /// Remove `n` rows at row `y`, moving the rows up to row `bottom` up to take their place,
+    /// along with their wrapping and line sizes, and filling the end of that span with blank
+    /// rows.
+    fn remove_rows_at(&mut self, y: u32, n: u32, bottom: u32);
     fn move_out_of_extension(&self, coords: Coords, direction: Direction) -> Coords;
+
+    /// Record whether text written at the end of row `y` continued onto the next row.
+    fn set_wrapped(&mut self, _y: u32, _wrapped: bool) { }
+
+    fn is_wrapped(&self, _y: u32) -> bool {
+        false
+    }
+
+    /// Set the size of the characters in row `y`.
+    fn set_line_size(&mut self, _y: u32, _size: LineSize) { }
+
+    fn line_size(&self, _y: u32) -> LineSize {
+        LineSize::Normal
+    }
 }
 
 pub trait Cell: Styleable {
@@ -65,3 +92,21 @@ pub trait WriteableCell {
     fn is_extendable(&self) -> bool;
     fn source(&self) -> Option<Coords>;
 }
+
+/// Cells which can be packed into a compact encoding, so that rows of scrollback which are not
+/// being accessed take up less memory.
+pub trait Pack: Sized {
+    fn pack(cells: &[Self]) -> PackedRow;
+    fn unpack(row: &PackedRow) -> Option<Vec<Self>>;
+
+    /// The amount of memory owned by this cell outside of the cell itself, in bytes. The data of
+    /// an image is shared with every copy of the cell, so it is not included.
+    fn heap_size(&self) -> usize {
+        0
+    }
+
+    /// The image held by this cell, if it holds one.
+    fn image(&self) -> Option<&Arc<ImageData>> {
+        None
+    }
+}
@@ -13,49 +13,70 @@
 //  
 //  You should have received a copy of the GNU Affero General Public License
 //  along with this program.  If not, see <http://www.gnu.org/licenses/>.
-use std::io::{self};
+use std::io::{self, Read, Write};
 use std::ops::{Deref, DerefMut};
-use std::sync::atomic::Ordering::Relaxed;
 
 mod char_grid;
+mod damage;
+mod export;
+mod frame;
 mod input;
 pub(crate) mod interfaces;
 mod screen;
+pub(crate) mod session;
 mod styles;
+mod trace;
+mod unsupported;
 
 use Command;
+use cfg::TerminalConfig;
 use datatypes::{InputSettings, Key};
 
 pub use self::char_grid::*;
+pub use self::damage::{Damage, DamagedRows};
+pub use self::export::{ExportFormat, ExportRange, Palette};
+pub use self::frame::{Frame, FrameCursor, PanelArea, PlacedImage, RunContent, StyledRun,
+                      StyledRows};
 pub use self::input::Tty;
 pub use self::interfaces::{CharData, Styleable, Resizeable};
 pub use self::screen::{Screen, Cells, Panels};
 pub use self::styles::*;
+pub use self::trace::{Trace, TraceEntry};
+pub use self::unsupported::{Unsupported, UnsupportedSequence, MAX_UNSUPPORTED};
 
 use self::input::Input;
-use cfg::{TAB_STOP, SCROLLBACK};
+use self::session::Persist;
 
 pub struct Terminal {
     title: String,
     screen: Screen,
     tty: Input,
+    unsupported: Unsupported,
+    trace: Option<Trace>,
 }
 
 impl Terminal {
 
-    pub fn new<W: Tty + Send + 'static>(width: u32, height: u32, tty: W)
+    pub fn new<W: Tty + Send + 'static>(width: u32, height: u32, tty: W, config: TerminalConfig)
             -> Terminal {
-        if TAB_STOP.load(Relaxed) == 0 { TAB_STOP.store(4, Relaxed) };
-        if SCROLLBACK.load(Relaxed) == 0 { SCROLLBACK.store(-1, Relaxed) };
         Terminal {
             title: String::new(),
-            screen: Screen::new(width, height),
+            screen: Screen::new(width, height, config),
             tty: Input::new(tty),
+            unsupported: Unsupported::default(),
+            trace: None,
         }
     }
 
-    pub fn apply(&mut self, cmd: &Command) -> io::Result<()> {
-        cmd.inner.apply(self)
+    pub fn apply(&mut self, cmd: Command) -> io::Result<()> {
+        if self.trace.is_none() { return cmd.inner.apply(self) }
+        let (repr, panel, before) = (cmd.inner.repr(), self.active(), self.cursor().position());
+        let result = cmd.inner.apply(self);
+        let after = self.cursor().position();
+        if let Some(ref mut trace) = self.trace {
+            trace.push(repr, panel, before, after);
+        }
+        result
     }
 
     pub fn paste(&mut self, data: &str) -> io::Result<()> {
@@ -91,6 +112,93 @@ impl Terminal {
         self.tty.set_mode(mode);
     }
 
+    /// Reset the terminal. A hard reset (RIS) returns the terminal to the state it was in when
+    /// it was created: every panel is discarded, leaving a single empty grid with the tab stop of
+    /// the `TerminalConfig`, and the title and input mode are cleared. The palette belongs to the
+    /// renderer, which is told to restore it by `Damage::reset`. A soft reset (DECSTR) only
+    /// resets the text and cursor styles and the cursor keys mode, leaving the contents of the
+    /// screen alone.
+    ///
+    /// Grids have no scrolling margins, so there are none to reset. The scrollback is discarded
+    /// with the grids, unless it is being saved to a file; see `Screen::reset`.
+    pub fn reset(&mut self, hard: bool) {
+        if hard {
+            self.title.clear();
+            self.screen.reset();
+        } else {
+            self.cursor_mut().reset_style();
+            self.reset_style();
+        }
+        self.tty.reset(hard);
+    }
+
+    /// Save the state of this terminal as a session, which can be restored with
+    /// `restore_session`, possibly by another process. Everything but the connection to the
+    /// controlling process is saved.
+    pub fn save_session<W: Write>(&self, w: &mut W) -> io::Result<()> {
+        session::save(w, |session| {
+            session.write_str(&self.title);
+            self.tty.persist_mode(session);
+            self.screen.persist(session);
+        })
+    }
+
+    /// Replace the state of this terminal with a session saved by `save_session`. The restored
+    /// screen is resized to the size of this terminal. If the session is malformed, the
+    /// terminal is unchanged. The session carries the config of the terminal it was saved
+    /// from; the `Output` which parses for this terminal should be given it with `set_config`.
+    pub fn restore_session<R: Read>(&mut self, r: &mut R) -> io::Result<()> {
+        let (title, (settings, bracketed), mut screen) = try!(session::restore(r, |session| {
+            let title = match session.read_str() {
+                Some(title) => String::from(title),
+                None        => return None,
+            };
+            let mode = match Input::restore_mode(session) { Some(m) => m, None => return None };
+            Screen::restore(session).map(|screen| (title, mode, screen))
+        }));
+        let area = self.area();
+        screen.resize(area.width(), area.height());
+        self.title = title;
+        self.screen = screen;
+        self.tty.set_mode(settings);
+        self.tty.set_mode(InputSettings::BracketedPasteMode(bracketed));
+        Ok(())
+    }
+
+    /// Export part of the active panel as plain text, ANSI-escaped text or HTML.
+    pub fn export(&self, range: ExportRange, format: ExportFormat) -> String {
+        self.screen.export(range, format)
+    }
+
+    /// The escape sequences this terminal has received which it does not support.
+    pub fn unsupported(&self) -> &Unsupported {
+        &self.unsupported
+    }
+
+    pub fn clear_unsupported(&mut self) {
+        self.unsupported.clear();
+    }
+
+    pub(crate) fn record_unsupported(&mut self, sequence: &str) {
+        self.unsupported.record(sequence, &self.title);
+    }
+
+    /// Begin tracing the commands applied to this terminal, keeping the last `capacity` of them.
+    /// Any trace already in progress is discarded.
+    pub fn start_trace(&mut self, capacity: usize) {
+        self.trace = Some(Trace::new(capacity));
+    }
+
+    /// Stop tracing commands, returning the trace.
+    pub fn stop_trace(&mut self) -> Option<Trace> {
+        self.trace.take()
+    }
+
+    /// The trace in progress, if commands are being traced.
+    pub fn trace(&self) -> Option<&Trace> {
+        self.trace.as_ref()
+    }
+
     pub fn bell(&mut self) {
         println!("BELL");
     }
@@ -120,3 +228,161 @@ impl DerefMut for Terminal {
         &mut self.screen
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use std::fs;
+    use std::io::{self, Write};
+    use std::sync::{Arc, Mutex};
+
+    use cfg::TerminalConfig;
+    use datatypes::{Coords, Direction, InputSettings, Key, Movement, Region, ResizeRule,
+                    SaveGrid, SplitKind, Style};
+    use super::*;
+
+    /// A tty which keeps everything written to it.
+    #[derive(Clone, Default)]
+    struct Recorder(Arc<Mutex<Vec<u8>>>);
+
+    impl Recorder {
+        fn take(&self) -> Vec<u8> {
+            self.0.lock().unwrap().drain(..).collect()
+        }
+    }
+
+    impl Write for Recorder {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.lock().unwrap().write(buf)
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl Tty for Recorder {
+        fn set_winsize(&mut self, _: u16, _: u16) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    /// A terminal with a split screen, changed modes and styles, a tooltip and a row of
+    /// scrollback.
+    fn used_terminal(tty: Recorder) -> Terminal {
+        let mut terminal = Terminal::new(8, 2, tty, TerminalConfig::default().tab_stop(4));
+        terminal.set_title(String::from("title"));
+        terminal.set_input_mode(InputSettings::Ansi(true));
+        terminal.set_input_mode(InputSettings::BracketedPasteMode(true));
+        for _ in 0..3 {
+            terminal.write('a');
+            terminal.move_cursor(Movement::NextLine(1));
+        }
+        terminal.add_tooltip(Coords { x: 0, y: 1 }, String::from("tooltip"));
+        terminal.set_style(Style::Underline(1));
+        terminal.cursor_mut().set_style(Style::Blink(true));
+        terminal.split(SaveGrid::Left, SplitKind::Horizontal(1), ResizeRule::Percentage, None, 1, 2,
+                       true);
+        terminal
+    }
+
+    fn is_blank(terminal: &Terminal, coords: Coords) -> bool {
+        terminal.cell_at(coords).map_or(true, |cell| *cell.content() == CellData::Empty)
+    }
+
+    fn input(terminal: &mut Terminal, tty: &Recorder) -> Vec<u8> {
+        terminal.send_input(Key::UpArrow, true).unwrap();
+        terminal.paste("p").unwrap();
+        tty.take()
+    }
+
+    #[test]
+    fn hard_reset() {
+        let tty = Recorder::default();
+        let mut terminal = used_terminal(tty.clone());
+        terminal.reset(true);
+        assert_eq!(terminal.title, "");
+        assert_eq!(terminal.panels().len(), 1);
+        assert_eq!(terminal.active(), 0);
+        assert_eq!(terminal.area(), terminal.view());
+        assert_eq!(terminal.styles(), &UseStyles::default());
+        assert_eq!(terminal.cursor().styles(), &UseStyles::default());
+        assert_eq!(terminal.cursor().position(), Coords { x: 0, y: 0 });
+        assert!(terminal.tooltip_at(Coords { x: 0, y: 1 }).is_none());
+        assert!(is_blank(&terminal, Coords { x: 0, y: 0 }));
+        assert_eq!(input(&mut terminal, &tty), b"\x1b[Ap");
+        terminal.move_cursor(Movement::Tab(Direction::Right, 1, false));
+        assert_eq!(terminal.cursor().position(), Coords { x: 4, y: 0 });
+        assert!(terminal.take_damage().reset());
+    }
+
+    #[test]
+    fn hard_reset_keeps_saved_scrollback() {
+        let dir = env::temp_dir().join("notty-test-hard-reset-keeps-saved-scrollback");
+        let _ = fs::remove_dir_all(&dir);
+        let mut terminal = Terminal::new(8, 2, Recorder::default(), TerminalConfig::default());
+        terminal.restore_scrollback(dir.join("scrollback")).unwrap();
+        for _ in 0..3 {
+            terminal.write('a');
+            terminal.move_cursor(Movement::NextLine(1));
+        }
+        terminal.split(SaveGrid::Right, SplitKind::Horizontal(1), ResizeRule::Percentage, None, 1,
+                       2, true);
+        terminal.reset(true);
+        assert_eq!(terminal.panels().len(), 1);
+        assert_eq!(terminal.view().height(), 2);
+        let top = terminal.view().top;
+        assert_eq!(terminal.cursor().position(), Coords { x: 0, y: top });
+        assert!(!is_blank(&terminal, Coords { x: 0, y: 0 }));
+        assert!(is_blank(&terminal, Coords { x: 0, y: top }));
+        terminal.sync_scrollback().unwrap();
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn truncated_session() {
+        let mut terminal = used_terminal(Recorder::default());
+        let mut data = vec![];
+        terminal.save_session(&mut data).unwrap();
+        let before = terminal.snapshot();
+        for len in 0..data.len() {
+            assert!(terminal.restore_session(&mut &data[..len]).is_err());
+        }
+        assert_eq!(terminal.snapshot(), before);
+        terminal.restore_session(&mut &data[..]).unwrap();
+    }
+
+    #[test]
+    fn deeply_nested_session() {
+        let mut terminal = Terminal::new(8, 2, Recorder::default(), TerminalConfig::default());
+        let mut data = vec![];
+        session::save(&mut data, |session| {
+            session.write_str("");
+            terminal.tty.persist_mode(session);
+            session.write_uint(0);
+            TerminalConfig::default().persist(session);
+            for _ in 0..100_000 {
+                session.write_uint(0);
+                Region::new(0, 0, 8, 2).persist(session);
+                session.write_uint(1);
+                session.write_u8(1);
+            }
+        }).unwrap();
+        assert!(terminal.restore_session(&mut &data[..]).is_err());
+    }
+
+    #[test]
+    fn soft_reset() {
+        let tty = Recorder::default();
+        let mut terminal = used_terminal(tty.clone());
+        terminal.reset(false);
+        assert_eq!(terminal.title, "title");
+        assert_eq!(terminal.panels().len(), 2);
+        assert_eq!(terminal.styles(), &UseStyles::default());
+        assert_eq!(terminal.cursor().styles(), &UseStyles::default());
+        assert_eq!(input(&mut terminal, &tty), b"\x1b[A\x1b[200~p\x1b[201~");
+        terminal.switch(1);
+        assert!(!is_blank(&terminal, Coords { x: 0, y: 0 }));
+        assert!(terminal.tooltip_at(Coords { x: 0, y: 1 }).is_some());
+    }
+}
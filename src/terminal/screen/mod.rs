@@ -1,8 +1,12 @@
+use std::mem;
 use std::ops::{Deref, DerefMut};
 
-use datatypes::{Region, SaveGrid, SplitKind, ResizeRule};
-use terminal::{CharGrid};
+use cfg::TerminalConfig;
+use datatypes::{Region, SaveGrid, ScrollbackPolicy, SplitKind, ResizeRule};
+use terminal::{CharGrid, Damage, Frame, StyledRows};
+use terminal::frame::FrameBuilder;
 use terminal::interfaces::{Resizeable, ConstructGrid};
+use terminal::session::{Persist, SessionReader, SessionWriter};
 
 mod iter;
 mod panel;
@@ -20,39 +24,54 @@ const E_ACTIVE: &'static str = "Active screen section must exist.";
 
 pub struct Screen<T=CharGrid> {
     active: u64,
+    config: TerminalConfig,
     screen: ScreenSection<T>,
+    damage: Damage,
 }
 
 impl<T: ConstructGrid + Resizeable> Screen<T> {
     pub fn split(&mut self, save: SaveGrid, kind: SplitKind, rule: ResizeRule,
                  split_tag: Option<u64>, l_tag: u64, r_tag: u64, retain_offscreen_state: bool) {
+        let config = self.config;
         self.find_mut(split_tag).map(|section| section.split(save, kind, rule, l_tag, r_tag,
-                                                             retain_offscreen_state));
+                                                             retain_offscreen_state, config));
         if split_tag.map_or(true, |tag| tag == self.active) {
             self.active = match save {
                 SaveGrid::Left  => l_tag,
                 SaveGrid::Right => r_tag,
             };
         }
+        self.damage.add_layout();
     }
 }
 
 impl<T: ConstructGrid> Screen<T> {
-    pub fn new(width: u32, height: u32) -> Screen<T> {
+    pub fn new(width: u32, height: u32, config: TerminalConfig) -> Screen<T> {
         Screen {
             active: 0,
-            screen: ScreenSection::new(0, Region::new(0, 0, width, height), true),
+            config: config,
+            screen: ScreenSection::new(0, Region::new(0, 0, width, height), true, config),
+            damage: Damage::default(),
         }
     }
 
     pub fn push(&mut self, tag: Option<u64>, retain_offscreen_state: bool) {
-        self.find_mut(tag).map(|section| section.push(retain_offscreen_state));
+        let config = self.config;
+        self.find_mut(tag).map(|section| section.push(retain_offscreen_state, config));
+        self.damage.add_layout();
+    }
+
+    /// Set the scrollback policy given to panels created after this point. Existing panels keep
+    /// their own policy.
+    pub fn set_default_scrollback_policy(&mut self, scrollback: ScrollbackPolicy) {
+        self.config.scrollback = scrollback;
     }
 }
 
 impl<T: Resizeable> Screen<T> {
     pub fn adjust_split(&mut self, tag: u64, kind: SplitKind) {
         self.find_mut(Some(tag)).map(|section| section.adjust_split(kind));
+        self.damage.add_layout();
     }
 
     pub fn unsplit(&mut self, save: SaveGrid, tag: u64) {
@@ -62,6 +81,7 @@ impl<T: Resizeable> Screen<T> {
             }
         }
         self.find_mut(Some(tag)).map(|section| section.unsplit(save));
+        self.damage.add_layout();
     }
 }
 
@@ -72,14 +92,17 @@ impl<T: Resizeable> Resizeable for Screen<T> {
 
     fn resize_width(&mut self, width: u32) {
         self.screen.resize_width(width);
+        self.damage.add_layout();
     }
 
     fn resize_height(&mut self, height: u32) {
         self.screen.resize_height(height);
+        self.damage.add_layout();
     }
 
     fn resize(&mut self, width: u32, height: u32) {
         self.screen.resize(width, height);
+        self.damage.add_layout();
     }
 }
 
@@ -88,22 +111,36 @@ impl<T> Screen<T> {
         self.screen.area()
     }
 
+    /// The tag of the active panel.
+    pub fn active(&self) -> u64 {
+        self.active
+    }
+
+    /// The configuration of the terminal this screen belongs to.
+    pub fn config(&self) -> &TerminalConfig {
+        &self.config
+    }
+
     pub fn switch(&mut self, tag: u64) {
         if self.find(Some(tag)).map_or(false, ScreenSection::is_fill) {
             self.active = tag;
+            self.damage.add_layout();
         }
     }
 
     pub fn pop(&mut self, tag: Option<u64>) {
         self.find_mut(tag).map(ScreenSection::pop);
+        self.damage.add_layout();
     }
 
     pub fn rotate_down(&mut self, tag: Option<u64>) {
         self.find_mut(tag).map(ScreenSection::rotate_down);
+        self.damage.add_layout();
     }
 
     pub fn rotate_up(&mut self, tag: Option<u64>) {
         self.find_mut(tag).map(ScreenSection::rotate_up);
+        self.damage.add_layout();
     }
 
     pub fn cells(&self) -> Cells<T> {
@@ -124,6 +161,70 @@ impl<T> Screen<T> {
 
 }
 
+impl Screen<CharGrid> {
+    /// Take the damage to the screen since it was last taken, in screen coordinates.
+    pub fn take_damage(&mut self) -> Damage {
+        let mut damage = mem::replace(&mut self.damage, Damage::default());
+        self.screen.take_damage(&mut damage);
+        damage
+    }
+
+    /// Discard every panel on the screen, leaving a single section containing one grid. If a grid
+    /// saves its scrollback to a file, that grid is kept and reset in place, so that the file
+    /// stays in step with it; otherwise the grid is a new, empty one.
+    pub fn reset(&mut self) {
+        let area = self.area();
+        let config = self.config;
+        let mut grids = vec![];
+        mem::replace(&mut self.screen, ScreenSection::new(0, area, true, config))
+            .into_grids(&mut grids);
+        if let Some(mut grid) = grids.into_iter().find(CharGrid::has_scrollback_file) {
+            grid.resize(area.width(), area.height());
+            grid.reset(config.tab_stop);
+            *self.screen.fill_mut() = grid;
+        }
+        self.active = 0;
+        self.damage.add_reset();
+    }
+
+    /// Iterate over the visible rows of the screen, merging the cells of each row into runs of
+    /// cells with the same styles.
+    pub fn rows(&self) -> StyledRows {
+        let panels = self.panels().map(|cells| (cells.area(), cells.section.fill())).collect();
+        StyledRows::new(panels, self.area().height())
+    }
+
+    /// Take a snapshot of everything which is visible on the screen.
+    pub fn snapshot(&self) -> Frame {
+        let mut frame = FrameBuilder::new(self.area());
+        self.screen.snapshot_into(self.active, &mut frame);
+        frame.finish()
+    }
+}
+
+impl<T: Persist> Persist for Screen<T> {
+    fn persist(&self, session: &mut SessionWriter) {
+        session.write_uint(self.active);
+        self.config.persist(session);
+        self.screen.persist(session);
+    }
+
+    fn restore(session: &mut SessionReader) -> Option<Screen<T>> {
+        let active = match session.read_uint() { Some(tag) => tag, None => return None };
+        let config = match TerminalConfig::restore(session) { Some(c) => c, None => return None };
+        let screen = match ScreenSection::restore(session) { Some(s) => s, None => return None };
+        if !screen.find(active).map_or(false, ScreenSection::is_fill) { return None; }
+        let mut damage = Damage::default();
+        damage.add_layout();
+        Some(Screen {
+            active: active,
+            config: config,
+            screen: screen,
+            damage: damage,
+        })
+    }
+}
+
 impl Deref for Screen<CharGrid> {
     type Target = CharGrid;
     fn deref(&self) -> &CharGrid {
@@ -2,6 +2,7 @@ use std::mem;
 
 use datatypes::Region;
 use terminal::interfaces::Resizeable;
+use terminal::session::{Persist, SessionReader, SessionWriter};
 
 use super::section::ScreenSection;
 use super::split::SplitSection;
@@ -48,6 +49,30 @@ impl<T: Resizeable> Resizeable for Panel<T> {
     }
 }
 
+impl<T: Persist> Persist for Panel<T> {
+    fn persist(&self, session: &mut SessionWriter) {
+        match *self {
+            Fill(ref fill)      => {
+                session.write_u8(0);
+                fill.persist(session);
+            }
+            Split(ref split)    => {
+                session.write_u8(1);
+                split.persist(session);
+            }
+            Dead                => unreachable!(),
+        }
+    }
+
+    fn restore(session: &mut SessionReader) -> Option<Panel<T>> {
+        match session.read_u8() {
+            Some(0) => T::restore(session).map(Fill),
+            Some(1) => session.read_nested(SplitSection::restore).map(Split),
+            _       => None,
+        }
+    }
+}
+
 impl<T: Resizeable> Panel<T> {
     pub fn shift_into(&mut self, area: Region) {
         match *self {
@@ -124,8 +149,8 @@ mod tests {
 
         fn split_panel() -> Panel<MockFill> {
             Panel::Split(SplitSection::new(
-                Box::new(ScreenSection::new(0, Region::new(0, 0, 8, 4), false)),
-                Box::new(ScreenSection::new(1, Region::new(0, 4, 8, 8), false)),
+                Box::new(ScreenSection::new(0, Region::new(0, 0, 8, 4), false, CONFIG)),
+                Box::new(ScreenSection::new(1, Region::new(0, 4, 8, 8), false, CONFIG)),
                 Region::new(0, 0, 8, 8),
                 SplitKind::Horizontal(4),
             ))
@@ -138,12 +163,12 @@ mod tests {
 
         #[test]
         fn find() {
-            assert_eq!(split_panel().find(0), Some(&ScreenSection::new(0, Region::new(0, 0, 8, 4), false)));
+            assert_eq!(split_panel().find(0), Some(&ScreenSection::new(0, Region::new(0, 0, 8, 4), false, CONFIG)));
         }
 
         #[test]
         fn find_mut() {
-            assert_eq!(split_panel().find_mut(1), Some(&mut ScreenSection::new(1, Region::new(0, 4, 8, 8), false)));
+            assert_eq!(split_panel().find_mut(1), Some(&mut ScreenSection::new(1, Region::new(0, 4, 8, 8), false, CONFIG)));
         }
     }
 }
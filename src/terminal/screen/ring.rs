@@ -1,6 +1,8 @@
 use std::collections::VecDeque;
 use std::mem;
 
+use terminal::session::{Persist, SessionReader, SessionWriter};
+
 const E_NOT_EMPTY: &'static str = "The Ring deque can't be empty if it is not None.";
 
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -73,6 +75,33 @@ impl<T> Ring<T> {
 
 }
 
+impl<T: Persist> Persist for Ring<T> {
+    /// Items are saved from the top of the ring down.
+    fn persist(&self, session: &mut SessionWriter) {
+        session.write_uint(self.len() as u64);
+        for item in self {
+            item.persist(session);
+        }
+    }
+
+    fn restore(session: &mut SessionReader) -> Option<Ring<T>> {
+        let len = match session.read_uint() { Some(n) => n, None => return None };
+        if len == 0 { return None; }
+        let top = match T::restore(session) { Some(top) => top, None => return None };
+        let mut rest = VecDeque::new();
+        for _ in 1..len {
+            match T::restore(session) {
+                Some(item)  => rest.push_front(item),
+                None        => return None,
+            }
+        }
+        Some(Ring {
+            top: top,
+            rest: if rest.is_empty() { None } else { Some(rest) },
+        })
+    }
+}
+
 impl<'a, T> IntoIterator for &'a Ring<T> {
     type Item = &'a T;
     type IntoIter = Iter<'a, T>;
@@ -1,8 +1,12 @@
 use std::mem;
 use std::ops::Index;
 
-use datatypes::{Flow, Region, Coords, CoordsIter, SaveGrid, SplitKind, ResizeRule, GridSettings};
+use cfg::TerminalConfig;
+use datatypes::{Region, Coords, CoordsIter, SaveGrid, SplitKind, ResizeRule};
+use terminal::{CharGrid, Damage, MAX_ROW_WIDTH};
+use terminal::frame::FrameBuilder;
 use terminal::interfaces::{ConstructGrid, Resizeable};
+use terminal::session::{Persist, SessionReader, SessionWriter};
 
 use super::panel::Panel;
 use super::panel::Panel::*;
@@ -20,7 +24,8 @@ pub struct ScreenSection<T> {
 impl<T: ConstructGrid + Resizeable> ScreenSection<T> {
     /// Split the top panel this section into two sections.
     pub fn split(&mut self, save: SaveGrid, kind: SplitKind, rule: ResizeRule,
-                 l_tag: u64, r_tag: u64, retain_offscreen_state: bool) {
+                 l_tag: u64, r_tag: u64, retain_offscreen_state: bool,
+                 config: TerminalConfig) {
         let (kind, l_area, r_area) = self.area.split(kind, rule);
         match save {
             SaveGrid::Left => {
@@ -28,7 +33,8 @@ impl<T: ConstructGrid + Resizeable> ScreenSection<T> {
                 l_panel.shift_into(l_area);
                 self.ring.top = Split(SplitSection::new(
                     Box::new(ScreenSection::with_data(l_tag, l_area, l_panel)),
-                    Box::new(ScreenSection::new(r_tag, r_area, retain_offscreen_state)),
+                    Box::new(ScreenSection::new(r_tag, r_area, retain_offscreen_state,
+                                                config)),
                     self.area,
                     kind,
                 ));
@@ -37,7 +43,8 @@ impl<T: ConstructGrid + Resizeable> ScreenSection<T> {
                 let mut r_panel = mem::replace(&mut self.ring.top, Dead);
                 r_panel.shift_into(r_area);
                 self.ring.top = Split(SplitSection::new(
-                    Box::new(ScreenSection::new(l_tag, l_area, retain_offscreen_state)),
+                    Box::new(ScreenSection::new(l_tag, l_area, retain_offscreen_state,
+                                                config)),
                     Box::new(ScreenSection::with_data(r_tag, r_area, r_panel)),
                     self.area,
                     kind,
@@ -51,25 +58,17 @@ impl<T: ConstructGrid> ScreenSection<T> {
 
     /// Construct a new ScreenSection with a given tag for this area of the screen. It will be
     /// filled with an empty grid.
-    pub fn new(tag: u64, area: Region, retain_offscreen_state: bool)
-            -> ScreenSection<T> {
-        let fill = T::new(GridSettings {
-            width: area.width(),
-            height: area.height(),
-            retain_offscreen_state: retain_offscreen_state,
-            flow: Flow::Moveable,
-        });
+    pub fn new(tag: u64, area: Region, retain_offscreen_state: bool,
+               config: TerminalConfig) -> ScreenSection<T> {
+        let fill = T::new(config.grid_settings(area.width(), area.height(),
+                                               retain_offscreen_state));
         ScreenSection::with_data(tag, area, Fill(fill))
     }
 
     /// Push a new empty grid panel on top of this section.
-    pub fn push(&mut self, retain_offscreen_state: bool) {
-        let fill = T::new(GridSettings {
-            width: self.area.width(),
-            height: self.area.height(),
-            retain_offscreen_state: retain_offscreen_state,
-            flow: Flow::Moveable,
-        });
+    pub fn push(&mut self, retain_offscreen_state: bool, config: TerminalConfig) {
+        let fill = T::new(config.grid_settings(self.area.width(), self.area.height(),
+                                               retain_offscreen_state));
         self.ring.push(Fill(fill));
     }
 }
@@ -101,6 +100,24 @@ impl<T: Resizeable> ScreenSection<T> {
     }
 }
 
+impl<T: Persist> Persist for ScreenSection<T> {
+    fn persist(&self, session: &mut SessionWriter) {
+        session.write_uint(self.tag);
+        self.area.persist(session);
+        self.ring.persist(session);
+    }
+
+    fn restore(session: &mut SessionReader) -> Option<ScreenSection<T>> {
+        let tag = match session.read_uint() { Some(tag) => tag, None => return None };
+        let area = match Region::restore(session) {
+            Some(area) if area.right as usize <= MAX_ROW_WIDTH
+                       && area.bottom as usize <= MAX_ROW_WIDTH => area,
+            _                                                   => return None,
+        };
+        Ring::restore(session).map(|ring| ScreenSection { tag: tag, area: area, ring: ring })
+    }
+}
+
 impl<T> ScreenSection<T> {
     fn with_data(tag: u64, area: Region, data: Panel<T>) -> ScreenSection<T> {
         ScreenSection {
@@ -173,6 +190,17 @@ impl<T> ScreenSection<T> {
         } else { None }
     }
 
+    /// Take this section apart, adding every grid in it, whether visible or not, to `grids`.
+    pub fn into_grids(self, grids: &mut Vec<T>) {
+        for panel in self.ring {
+            match panel {
+                Fill(fill)      => grids.push(fill),
+                Split(split)    => split.into_grids(grids),
+                Dead            => unreachable!(),
+            }
+        }
+    }
+
     /// Remove the top panel of this section.
     pub fn pop(&mut self) {
         self.ring.pop();
@@ -203,6 +231,27 @@ impl<T> ScreenSection<T> {
 
 }
 
+impl ScreenSection<CharGrid> {
+    /// Take the damage to every visible grid in this section of the screen, adding it to
+    /// `damage` in screen coordinates.
+    pub fn take_damage(&mut self, damage: &mut Damage) {
+        match self.ring.top {
+            Fill(ref mut fill)      => damage.merge(fill.take_damage(), self.area),
+            Split(ref mut split)    => split.take_damage(damage),
+            _ => unreachable!()
+        }
+    }
+
+    /// Add every visible grid in this section of the screen to a frame.
+    pub fn snapshot_into(&self, active: u64, frame: &mut FrameBuilder) {
+        match self.ring.top {
+            Fill(ref fill)      => frame.add_panel(self.tag, self.area, self.tag == active, fill),
+            Split(ref split)    => split.snapshot_into(active, frame),
+            _ => unreachable!()
+        }
+    }
+}
+
 impl<T: Resizeable> Resizeable for ScreenSection<T> {
     fn dims(&self) -> (u32, u32) {
         (self.area().width(), self.area().height())
@@ -248,7 +297,7 @@ mod tests {
     pub use terminal::screen::tests::*;
 
     fn split(mut section: ScreenSection<MockFill>, save: SaveGrid) -> ScreenSection<MockFill> {
-        section.split(save, Horizontal(4), Percentage, 3, 4, false);
+        section.split(save, Horizontal(4), Percentage, 3, 4, false, CONFIG);
         section
     }
 
@@ -265,8 +314,8 @@ mod tests {
                 tag: 0,
                 area: Region::new(0, 0, 8, 8),
                 ring: Ring::new(Split(SplitSection::new(
-                    Box::new(ScreenSection::new(3, Region::new(0, 0, 8, 4), false)),
-                    Box::new(ScreenSection::new(4, Region::new(0, 4, 8, 8), false)),
+                    Box::new(ScreenSection::new(3, Region::new(0, 0, 8, 4), false, CONFIG)),
+                    Box::new(ScreenSection::new(4, Region::new(0, 4, 8, 8), false, CONFIG)),
                     Region::new(0, 0, 8, 8),
                     Horizontal(4),
                 )))
@@ -279,8 +328,8 @@ mod tests {
                 tag: 0,
                 area: Region::new(0, 0, 8, 8),
                 ring: Ring::new(Split(SplitSection::new(
-                    Box::new(ScreenSection::new(3, Region::new(0, 0, 8, 4), false)),
-                    Box::new(ScreenSection::new(4, Region::new(0, 4, 8, 8), false)),
+                    Box::new(ScreenSection::new(3, Region::new(0, 0, 8, 4), false, CONFIG)),
+                    Box::new(ScreenSection::new(4, Region::new(0, 4, 8, 8), false, CONFIG)),
                     Region::new(0, 0, 8, 8),
                     Horizontal(4),
                 )))
@@ -306,8 +355,8 @@ mod tests {
                 tag: 0,
                 area: Region::new(0, 0, 8, 8),
                 ring: Ring::new(Split(SplitSection::new(
-                    Box::new(ScreenSection::new(1, Region::new(0, 0, 4, 8), false)),
-                    Box::new(ScreenSection::new(2, Region::new(4, 0, 8, 8), false)),
+                    Box::new(ScreenSection::new(1, Region::new(0, 0, 4, 8), false, CONFIG)),
+                    Box::new(ScreenSection::new(2, Region::new(4, 0, 8, 8), false, CONFIG)),
                     Region::new(0, 0, 8, 8),
                     Vertical(4),
                 ))),
@@ -324,13 +373,13 @@ mod tests {
                         tag: 3,
                         area: Region::new(0, 0, 8, 4),
                         ring: Ring::new(Split(SplitSection::new(
-                            Box::new(ScreenSection::new(1, Region::new(0, 0, 4, 4), false)),
-                            Box::new(ScreenSection::new(2, Region::new(4, 0, 8, 4), false)),
+                            Box::new(ScreenSection::new(1, Region::new(0, 0, 4, 4), false, CONFIG)),
+                            Box::new(ScreenSection::new(2, Region::new(4, 0, 8, 4), false, CONFIG)),
                             Region::new(0, 0, 8, 4),
                             Vertical(4),
                         )))
                     }),
-                    Box::new(ScreenSection::new(4, Region::new(0, 4, 8, 8), false)),
+                    Box::new(ScreenSection::new(4, Region::new(0, 4, 8, 8), false, CONFIG)),
                     Region::new(0, 0, 8, 8),
                     Horizontal(4),
                 )))
@@ -343,13 +392,13 @@ mod tests {
                 tag: 0,
                 area: Region::new(0, 0, 8, 8),
                 ring: Ring::new(Split(SplitSection::new(
-                    Box::new(ScreenSection::new(3, Region::new(0, 0, 8, 4), false)),
+                    Box::new(ScreenSection::new(3, Region::new(0, 0, 8, 4), false, CONFIG)),
                     Box::new(ScreenSection {
                         tag: 4,
                         area: Region::new(0, 4, 8, 8),
                         ring: Ring::new(Split(SplitSection::new(
-                            Box::new(ScreenSection::new(1, Region::new(0, 4, 4, 8), false)),
-                            Box::new(ScreenSection::new(2, Region::new(4, 4, 8, 8), false)),
+                            Box::new(ScreenSection::new(1, Region::new(0, 4, 4, 8), false, CONFIG)),
+                            Box::new(ScreenSection::new(2, Region::new(4, 4, 8, 8), false, CONFIG)),
                             Region::new(0, 4, 8, 8),
                             Vertical(4),
                        ))),
@@ -376,7 +425,7 @@ mod tests {
 
         pub fn section() -> ScreenSection<MockFill> {
             let mut section = super::split::section();
-            section.push(false);
+            section.push(false, CONFIG);
             section
         }
 
@@ -387,14 +436,14 @@ mod tests {
                 area: Region::new(0, 0, 8, 8),
                 ring: {
                     let mut ring = Ring::new(Split(SplitSection::new(
-                        Box::new(ScreenSection::new(1, Region::new(0, 0, 4, 8), false)),
-                        Box::new(ScreenSection::new(2, Region::new(4, 0, 8, 8), false)),
+                        Box::new(ScreenSection::new(1, Region::new(0, 0, 4, 8), false, CONFIG)),
+                        Box::new(ScreenSection::new(2, Region::new(4, 0, 8, 8), false, CONFIG)),
                         Region::new(0, 0, 8, 8),
                         Vertical(4),
                     )));
                     ring.push(Split(SplitSection::new(
-                        Box::new(ScreenSection::new(3, Region::new(0, 0, 8, 4), false)),
-                        Box::new(ScreenSection::new(4, Region::new(0, 4, 8, 8), false)),
+                        Box::new(ScreenSection::new(3, Region::new(0, 0, 8, 4), false, CONFIG)),
+                        Box::new(ScreenSection::new(4, Region::new(0, 4, 8, 8), false, CONFIG)),
                         Region::new(0, 0, 8, 8),
                         Horizontal(4),
                     )));
@@ -410,14 +459,14 @@ mod tests {
                 area: Region::new(0, 0, 8, 8),
                 ring: {
                     let mut ring = Ring::new(Split(SplitSection::new(
-                        Box::new(ScreenSection::new(1, Region::new(0, 0, 4, 8), false)),
-                        Box::new(ScreenSection::new(2, Region::new(4, 0, 8, 8), false)),
+                        Box::new(ScreenSection::new(1, Region::new(0, 0, 4, 8), false, CONFIG)),
+                        Box::new(ScreenSection::new(2, Region::new(4, 0, 8, 8), false, CONFIG)),
                         Region::new(0, 0, 8, 8),
                         Vertical(4),
                     )));
                     ring.push(Split(SplitSection::new(
-                        Box::new(ScreenSection::new(3, Region::new(0, 0, 8, 4), false)),
-                        Box::new(ScreenSection::new(4, Region::new(0, 4, 8, 8), false)),
+                        Box::new(ScreenSection::new(3, Region::new(0, 0, 8, 4), false, CONFIG)),
+                        Box::new(ScreenSection::new(4, Region::new(0, 4, 8, 8), false, CONFIG)),
                         Region::new(0, 0, 8, 8),
                         Horizontal(4),
                     )));
@@ -442,7 +491,7 @@ mod tests {
 
     #[test]
     fn new() {
-        assert_eq!(grid_section(), ScreenSection::new(0, Region::new(0, 0, 8, 8), true));
+        assert_eq!(grid_section(), ScreenSection::new(0, Region::new(0, 0, 8, 8), true, CONFIG));
     }
 
     #[test]
@@ -450,8 +499,8 @@ mod tests {
         assert_eq!(split_section(), ScreenSection::with_data(0, Region::new(0, 0, 8, 8),
         Split {
             kind: Vertical(4),
-            left: Box::new(ScreenSection::new(1, Region::new(0, 0, 4, 8), false)),
-            right: Box::new(ScreenSection::new(2, Region::new(4, 0, 8, 8), false)),
+            left: Box::new(ScreenSection::new(1, Region::new(0, 0, 4, 8), false, CONFIG)),
+            right: Box::new(ScreenSection::new(2, Region::new(4, 0, 8, 8), false, CONFIG)),
         }));
     }
 
@@ -493,13 +542,13 @@ mod tests {
             section.resize(6, 6);
             section
         }, [
-            ScreenSection::new(0, Region::new(0, 0, 6, 6), false),
+            ScreenSection::new(0, Region::new(0, 0, 6, 6), false, CONFIG),
             ScreenSection {
                 tag: 0,
                 area: Region::new(0, 0, 6, 6),
                 ring: Ring::new(Split(SplitSection::new(
-                    Box::new(ScreenSection::new(1, Region::new(0, 0, 3, 6), false)),
-                    Box::new(ScreenSection::new(2, Region::new(3, 0, 6, 6), false)),
+                    Box::new(ScreenSection::new(1, Region::new(0, 0, 3, 6), false, CONFIG)),
+                    Box::new(ScreenSection::new(2, Region::new(3, 0, 6, 6), false, CONFIG)),
                     Region::new(0, 0, 6, 6),
                     Vertical(3),
                 ))),
@@ -509,8 +558,8 @@ mod tests {
                 area: Region::new(0, 0, 6, 6),
                 ring: {
                     let mut ring = Ring::new(Split(SplitSection::new(
-                        Box::new(ScreenSection::new(1, Region::new(0, 0, 3, 6), false)),
-                        Box::new(ScreenSection::new(2, Region::new(3, 0, 6, 6), false)),
+                        Box::new(ScreenSection::new(1, Region::new(0, 0, 3, 6), false, CONFIG)),
+                        Box::new(ScreenSection::new(2, Region::new(3, 0, 6, 6), false, CONFIG)),
                         Region::new(0, 0, 6, 6),
                         Vertical(3),
                     )));
@@ -550,8 +599,8 @@ mod tests {
                 tag: 0,
                 area: Region::new(0, 0, 8, 8),
                 ring: Ring::new(Split(SplitSection::new(
-                    Box::new(ScreenSection::new(1, Region::new(0, 0, 8, 4), false)),
-                    Box::new(ScreenSection::new(2, Region::new(0, 4, 8, 8), false)),
+                    Box::new(ScreenSection::new(1, Region::new(0, 0, 8, 4), false, CONFIG)),
+                    Box::new(ScreenSection::new(2, Region::new(0, 4, 8, 8), false, CONFIG)),
                     Region::new(0, 0, 8, 8),
                     Horizontal(4),
                 ))),
@@ -562,7 +611,7 @@ mod tests {
 
     #[test]
     fn push() {
-        run_test(|mut section| { section.push(false); *section.grid() }, [
+        run_test(|mut section| { section.push(false, CONFIG); *section.grid() }, [
             Region::new(0, 0, 8, 8),
             Region::new(0, 0, 8, 8),
             Region::new(0, 0, 8, 8),
@@ -2,9 +2,13 @@ use std::mem;
 use std::ops::Index;
 
 use datatypes::{Coords, Region, ResizeRule, SplitKind, SaveGrid};
+use datatypes::args::Argument;
 use datatypes::ResizeRule::*;
 use datatypes::SplitKind::*;
+use terminal::{CharGrid, Damage};
+use terminal::frame::FrameBuilder;
 use terminal::interfaces::Resizeable;
+use terminal::session::{Persist, SessionReader, SessionWriter};
 
 use super::panel::Panel;
 use super::ring::Ring;
@@ -38,6 +42,11 @@ impl<T> SplitSection<T> {
         (&self.left, &self.right)
     }
 
+    pub fn into_grids(self, grids: &mut Vec<T>) {
+        self.left.into_grids(grids);
+        self.right.into_grids(grids);
+    }
+
     pub fn find(&self, tag: u64) -> Option<&ScreenSection<T>> {
         let SplitSection { ref left, ref right, .. } = *self;
         left.find(tag).or_else(move || right.find(tag))
@@ -49,6 +58,47 @@ impl<T> SplitSection<T> {
     }
 }
 
+impl<T: Persist> Persist for SplitSection<T> {
+    fn persist(&self, session: &mut SessionWriter) {
+        self.left.persist(session);
+        self.right.persist(session);
+        self.area.persist(session);
+        session.write_str(&self.kind.encode());
+        session.write_str(&self.rule.encode());
+    }
+
+    fn restore(session: &mut SessionReader) -> Option<SplitSection<T>> {
+        let left = match ScreenSection::restore(session) { Some(s) => s, None => return None };
+        let right = match ScreenSection::restore(session) { Some(s) => s, None => return None };
+        let area = match Region::restore(session) { Some(area) => area, None => return None };
+        let kind = match session.read_str().and_then(|s| SplitKind::decode(Some(s), None)) {
+            Some(kind)  => kind,
+            None        => return None,
+        };
+        session.read_str().and_then(|s| ResizeRule::decode(Some(s), None)).map(|rule| {
+            SplitSection {
+                left: Box::new(left),
+                right: Box::new(right),
+                area: area,
+                kind: kind,
+                rule: rule,
+            }
+        })
+    }
+}
+
+impl SplitSection<CharGrid> {
+    pub fn take_damage(&mut self, damage: &mut Damage) {
+        self.left.take_damage(damage);
+        self.right.take_damage(damage);
+    }
+
+    pub fn snapshot_into(&self, active: u64, frame: &mut FrameBuilder) {
+        self.left.snapshot_into(active, frame);
+        self.right.snapshot_into(active, frame);
+    }
+}
+
 impl<T: Resizeable> SplitSection<T> {
     pub fn shift_into(&mut self, area: Region) {
         self.area = area;
@@ -1,5 +1,7 @@
-pub use datatypes::{GridSettings, Region, ResizeRule, SplitKind, SaveGrid};
+pub use cfg::{Encoding, ResponsePolicy, TerminalConfig, WidthPolicy};
+pub use datatypes::{Flow, GridSettings, Region, ResizeRule, ScrollbackPolicy, SplitKind, SaveGrid};
 pub use datatypes::ResizeRule::*;
+pub use datatypes::ScrollbackPolicy::*;
 pub use datatypes::SplitKind::*;
 pub use terminal::interfaces::{Resizeable, ConstructGrid};
 pub use super::panel::Panel;
@@ -31,6 +33,15 @@ impl ConstructGrid for MockFill {
     }
 }
 
+pub const CONFIG: TerminalConfig = TerminalConfig {
+    tab_stop: 4,
+    scrollback: Unlimited,
+    flow: Flow::Moveable,
+    width: WidthPolicy::Narrow,
+    encoding: Encoding::Utf8,
+    responses: ResponsePolicy { status: true, position: true },
+};
+
 pub const GRID: MockFill = MockFill(8, 8);
 pub const OLD_AREA: Region = Region { left: 0, top: 2, right: 8, bottom: 10 };
 pub const NEW_AREA: Region = Region { left: 1, top: 1, right: 7, bottom: 11 };
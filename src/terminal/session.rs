@@ -0,0 +1,354 @@
+//  notty is a new kind of terminal emulator.
+//  Copyright (C) 2015 without boats
+//  
+//  This program is free software: you can redistribute it and/or modify
+//  it under the terms of the GNU Affero General Public License as published by
+//  the Free Software Foundation, either version 3 of the License, or
+//  (at your option) any later version.
+//  
+//  This program is distributed in the hope that it will be useful,
+//  but WITHOUT ANY WARRANTY; without even the implied warranty of
+//  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+//  GNU Affero General Public License for more details.
+//  
+//  You should have received a copy of the GNU Affero General Public License
+//  along with this program.  If not, see <http://www.gnu.org/licenses/>.
+//! Saving the whole state of a terminal, so that a session can be checkpointed and restored in
+//! another process. The format of a session is documented in `docs/session.md`.
+//!
+//! A session is written in the same encoding as packed rows. Images are written once, in a
+//! table at the start of the session, and are referred to everywhere else by their index in the
+//! table; images which were shared before the session was saved are shared after it has been
+//! restored.
+use std::collections::HashMap;
+use std::io::{self, Read, Write};
+use std::ops::{Deref, DerefMut};
+use std::sync::Arc;
+
+use datatypes::{Coords, LineSize, Region, ScrollbackPolicy};
+use terminal::{ImageData, PackedRow, RowReader, RowWriter};
+
+const HEADER: &'static [u8] = b"notty session 2\n";
+const E_SESSION: &'static str = "invalid notty session";
+
+/// How deeply split panels may be nested in a session. Every split divides the area of a panel,
+/// so no real screen comes close to this.
+const MAX_NESTING: u32 = 64;
+
+/// A part of the terminal state which can be saved in a session.
+pub(crate) trait Persist: Sized {
+    fn persist(&self, session: &mut SessionWriter);
+    fn restore(session: &mut SessionReader) -> Option<Self>;
+}
+
+/// Write a session, with `f` writing the state which is saved in it.
+pub(crate) fn save<W: Write, F: FnOnce(&mut SessionWriter)>(w: &mut W, f: F) -> io::Result<()> {
+    let mut session = SessionWriter::new();
+    f(&mut session);
+    session.finish(w)
+}
+
+/// Read a session, with `f` reading the state which was saved in it. `f` returns `None` if the
+/// session is malformed.
+pub(crate) fn restore<R, T, F>(r: &mut R, f: F) -> io::Result<T>
+        where R: Read, F: FnOnce(&mut SessionReader) -> Option<T> {
+    let mut data = vec![];
+    try!(r.read_to_end(&mut data));
+    if !data.starts_with(HEADER) {
+        return Err(invalid_data("not a notty session"));
+    }
+    let data = &data[HEADER.len()..];
+    let mut images = vec![];
+    let start = {
+        let mut reader = RowReader::new(data, &[]);
+        let count = try!(reader.read_uint().ok_or_else(|| invalid_data(E_SESSION)));
+        for _ in 0..count {
+            match (reader.read_uint(), reader.read_uint(), reader.read_bytes()) {
+                (Some(x), Some(y), Some(bytes)) => images.push(Arc::new(ImageData {
+                    data: bytes.to_vec(),
+                    coords: Coords { x: x as u32, y: y as u32 },
+                })),
+                _                               => return Err(invalid_data(E_SESSION)),
+            }
+        }
+        reader.position()
+    };
+    let mut session = SessionReader {
+        reader: RowReader::new(&data[start..], &images),
+        depth: 0,
+    };
+    match f(&mut session) {
+        Some(state) if session.is_empty()   => Ok(state),
+        _                                   => Err(invalid_data(E_SESSION)),
+    }
+}
+
+/// Writes a session. Everything but images is written directly into the body of the session.
+pub(crate) struct SessionWriter {
+    body: RowWriter,
+    images: Vec<Arc<ImageData>>,
+    ids: HashMap<*const ImageData, u64>,
+}
+
+impl SessionWriter {
+    fn new() -> SessionWriter {
+        SessionWriter {
+            body: RowWriter::new(),
+            images: vec![],
+            ids: HashMap::new(),
+        }
+    }
+
+    /// Write a reference to an image, adding it to the image table if it has not been written
+    /// already.
+    pub fn write_shared_image(&mut self, image: &Arc<ImageData>) {
+        let next = self.images.len() as u64;
+        let id = *self.ids.entry(&**image as *const ImageData).or_insert(next);
+        if id == next { self.images.push(image.clone()); }
+        self.body.write_uint(id);
+    }
+
+    pub fn write_bool(&mut self, flag: bool) {
+        self.body.write_u8(flag as u8);
+    }
+
+    pub fn write_option(&mut self, n: Option<usize>) {
+        match n {
+            Some(n) => {
+                self.body.write_u8(1);
+                self.body.write_uint(n as u64);
+            }
+            None    => self.body.write_u8(0),
+        }
+    }
+
+    /// Write a packed row, with references to its images.
+    pub fn write_row(&mut self, row: &PackedRow) {
+        self.body.write_bytes(row.bytes());
+        self.body.write_uint(row.images().len() as u64);
+        for image in row.images() {
+            self.write_shared_image(image);
+        }
+    }
+
+    fn finish<W: Write>(self, w: &mut W) -> io::Result<()> {
+        let mut table = RowWriter::new();
+        table.write_uint(self.images.len() as u64);
+        for image in &self.images {
+            table.write_uint(image.coords.x as u64);
+            table.write_uint(image.coords.y as u64);
+            table.write_bytes(&image.data);
+        }
+        try!(w.write_all(HEADER));
+        try!(w.write_all(&table.into_bytes()));
+        w.write_all(&self.body.into_bytes())
+    }
+}
+
+impl Deref for SessionWriter {
+    type Target = RowWriter;
+    fn deref(&self) -> &RowWriter {
+        &self.body
+    }
+}
+
+impl DerefMut for SessionWriter {
+    fn deref_mut(&mut self) -> &mut RowWriter {
+        &mut self.body
+    }
+}
+
+/// Reads a session back. Images read with `read_image` are looked up in the image table.
+pub(crate) struct SessionReader<'a> {
+    reader: RowReader<'a>,
+    depth: u32,
+}
+
+impl<'a> SessionReader<'a> {
+    pub fn read_bool(&mut self) -> Option<bool> {
+        match self.reader.read_u8() {
+            Some(0) => Some(false),
+            Some(1) => Some(true),
+            _       => None,
+        }
+    }
+
+    pub fn read_option(&mut self) -> Option<Option<usize>> {
+        match self.reader.read_u8() {
+            Some(0) => Some(None),
+            Some(1) => self.reader.read_uint().map(|n| Some(n as usize)),
+            _       => None,
+        }
+    }
+
+    /// Read a part of the session which may contain parts of the same kind, such as a split
+    /// panel, with `f`. Returns `None` if parts are nested too deeply.
+    pub fn read_nested<T, F: FnOnce(&mut Self) -> Option<T>>(&mut self, f: F) -> Option<T> {
+        if self.depth >= MAX_NESTING { return None; }
+        self.depth += 1;
+        let state = f(self);
+        self.depth -= 1;
+        state
+    }
+
+    pub fn read_row(&mut self) -> Option<PackedRow> {
+        let bytes = match self.reader.read_bytes() { Some(bytes) => bytes, None => return None };
+        let count = match self.reader.read_uint() { Some(n) => n, None => return None };
+        let mut images = vec![];
+        for _ in 0..count {
+            match self.reader.read_image() {
+                Some(image) => images.push(image),
+                None        => return None,
+            }
+        }
+        Some(PackedRow::from_parts(bytes.to_vec(), images))
+    }
+}
+
+impl<'a> Deref for SessionReader<'a> {
+    type Target = RowReader<'a>;
+    fn deref(&self) -> &RowReader<'a> {
+        &self.reader
+    }
+}
+
+impl<'a> DerefMut for SessionReader<'a> {
+    fn deref_mut(&mut self) -> &mut RowReader<'a> {
+        &mut self.reader
+    }
+}
+
+impl Persist for Coords {
+    fn persist(&self, session: &mut SessionWriter) {
+        session.write_uint(self.x as u64);
+        session.write_uint(self.y as u64);
+    }
+
+    fn restore(session: &mut SessionReader) -> Option<Coords> {
+        match (session.read_uint(), session.read_uint()) {
+            (Some(x), Some(y))  => Some(Coords { x: x as u32, y: y as u32 }),
+            _                   => None,
+        }
+    }
+}
+
+impl Persist for Region {
+    fn persist(&self, session: &mut SessionWriter) {
+        session.write_uint(self.left as u64);
+        session.write_uint(self.top as u64);
+        session.write_uint(self.right as u64);
+        session.write_uint(self.bottom as u64);
+    }
+
+    fn restore(session: &mut SessionReader) -> Option<Region> {
+        let left = match session.read_uint() { Some(n) => n as u32, None => return None };
+        let top = match session.read_uint() { Some(n) => n as u32, None => return None };
+        let right = match session.read_uint() { Some(n) => n as u32, None => return None };
+        let bottom = match session.read_uint() { Some(n) => n as u32, None => return None };
+        if left <= right && top <= bottom {
+            Some(Region { left: left, top: top, right: right, bottom: bottom })
+        } else { None }
+    }
+}
+
+impl Persist for ScrollbackPolicy {
+    fn persist(&self, session: &mut SessionWriter) {
+        match *self {
+            ScrollbackPolicy::Unlimited     => session.write_u8(0),
+            ScrollbackPolicy::MaxRows(n)    => {
+                session.write_u8(1);
+                session.write_uint(n as u64);
+            }
+            ScrollbackPolicy::MaxBytes(n)   => {
+                session.write_u8(2);
+                session.write_uint(n as u64);
+            }
+        }
+    }
+
+    fn restore(session: &mut SessionReader) -> Option<ScrollbackPolicy> {
+        match session.read_u8() {
+            Some(0) => Some(ScrollbackPolicy::Unlimited),
+            Some(1) => session.read_uint().map(|n| ScrollbackPolicy::MaxRows(n as usize)),
+            Some(2) => session.read_uint().map(|n| ScrollbackPolicy::MaxBytes(n as usize)),
+            _       => None,
+        }
+    }
+}
+
+impl Persist for LineSize {
+    fn persist(&self, session: &mut SessionWriter) {
+        session.write_u8(match *self {
+            LineSize::Normal                => 0,
+            LineSize::DoubleWidth           => 1,
+            LineSize::DoubleHeightTop       => 2,
+            LineSize::DoubleHeightBottom    => 3,
+        });
+    }
+
+    fn restore(session: &mut SessionReader) -> Option<LineSize> {
+        match session.read_u8() {
+            Some(0) => Some(LineSize::Normal),
+            Some(1) => Some(LineSize::DoubleWidth),
+            Some(2) => Some(LineSize::DoubleHeightTop),
+            Some(3) => Some(LineSize::DoubleHeightBottom),
+            _       => None,
+        }
+    }
+}
+
+fn invalid_data(msg: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, msg)
+}
+
+#[cfg(test)]
+mod tests {
+    use cfg::TerminalConfig;
+    use datatypes::{Coords, MediaPosition, ResizeRule, SaveGrid, SplitKind};
+    use terminal::{CharGrid, Image, Screen};
+    use super::*;
+
+    fn round_trip<T: Persist>(state: &T) -> (Vec<u8>, T) {
+        let mut data = vec![];
+        save(&mut data, |session| state.persist(session)).unwrap();
+        let state = restore(&mut &data[..], T::restore).unwrap();
+        (data, state)
+    }
+
+    #[test]
+    fn restore_screen() {
+        let mut screen: Screen<CharGrid> = Screen::new(8, 4, TerminalConfig::default());
+        screen.split(SaveGrid::Left, SplitKind::Horizontal(2), ResizeRule::Percentage, None, 1, 2,
+                     true);
+        screen.write('a');
+        screen.add_tooltip(Coords { x: 0, y: 0 }, String::from("tooltip"));
+        screen.switch(2);
+        screen.write('b');
+        let (_, restored) = round_trip(&screen);
+        assert_eq!(restored.snapshot(), screen.snapshot());
+        assert_eq!(restored.config(), screen.config());
+    }
+
+    #[test]
+    fn images_saved_once() {
+        let mut screen: Screen<CharGrid> = Screen::new(8, 4, TerminalConfig::default());
+        let image = Image::new(b"notty image".to_vec(), "image/png".parse().unwrap(),
+                               MediaPosition::default(), 2, 2);
+        screen.write(image);
+        let (data, restored) = round_trip(&screen);
+        assert_eq!(data.windows(11).filter(|w| *w == &b"notty image"[..]).count(), 1);
+        assert_eq!(restored.snapshot(), screen.snapshot());
+    }
+
+    #[test]
+    fn malformed_session() {
+        let screen: Screen<CharGrid> = Screen::new(8, 4, TerminalConfig::default());
+        let (mut data, _) = round_trip(&screen);
+        data.pop();
+        assert!(restore(&mut &data[..], Screen::<CharGrid>::restore).is_err());
+        assert!(restore(&mut &b"notty scrollback 1\n"[..], Screen::<CharGrid>::restore).is_err());
+        let (data, _) = round_trip(&screen);
+        let old = [&b"notty session 1\n"[..], &data[HEADER.len()..]].concat();
+        assert!(restore(&mut &old[..], Screen::<CharGrid>::restore).is_err());
+    }
+}
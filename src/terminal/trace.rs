@@ -0,0 +1,122 @@
+//  notty is a new kind of terminal emulator.
+//  Copyright (C) 2015 without boats
+//  
+//  This program is free software: you can redistribute it and/or modify
+//  it under the terms of the GNU Affero General Public License as published by
+//  the Free Software Foundation, either version 3 of the License, or
+//  (at your option) any later version.
+//  
+//  This program is distributed in the hope that it will be useful,
+//  but WITHOUT ANY WARRANTY; without even the implied warranty of
+//  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+//  GNU Affero General Public License for more details.
+//  
+//  You should have received a copy of the GNU Affero General Public License
+//  along with this program.  If not, see <http://www.gnu.org/licenses/>.
+use std::collections::{vec_deque, VecDeque};
+use std::io::{self, Write};
+use std::time::{Duration, Instant};
+
+use datatypes::Coords;
+
+/// The most recent commands applied to a terminal, kept so that the commands which led to a
+/// misrendered screen can be inspected after the fact.
+pub struct Trace {
+    entries: VecDeque<TraceEntry>,
+    capacity: usize,
+    start: Instant,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TraceEntry {
+    /// When the command was applied, relative to when tracing began.
+    pub time: Duration,
+    /// A description of the command.
+    pub command: String,
+    /// The tag of the panel which was active when the command was applied.
+    pub panel: u64,
+    /// The position of the cursor before the command was applied.
+    pub before: Coords,
+    /// The position of the cursor after the command was applied.
+    pub after: Coords,
+}
+
+impl Trace {
+    /// Begin a trace which keeps the last `capacity` commands.
+    pub fn new(capacity: usize) -> Trace {
+        Trace {
+            entries: VecDeque::with_capacity(capacity),
+            capacity: capacity,
+            start: Instant::now(),
+        }
+    }
+
+    /// The commands in the trace, the oldest first.
+    pub fn entries(&self) -> vec_deque::Iter<TraceEntry> {
+        self.entries.iter()
+    }
+
+    /// Write the trace, one command per line. Control characters in the descriptions of
+    /// commands are escaped.
+    pub fn dump<W: Write>(&self, w: &mut W) -> io::Result<()> {
+        for entry in &self.entries {
+            let Coords { x, y } = entry.before;
+            try!(write!(w, "{:>6}.{:06} panel {} cursor {},{} -> {},{} ",
+                        entry.time.as_secs(), entry.time.subsec_nanos() / 1000, entry.panel,
+                        x, y, entry.after.x, entry.after.y));
+            for c in entry.command.chars() {
+                if c.is_control() {
+                    for c in c.escape_default() { try!(write!(w, "{}", c)); }
+                } else {
+                    try!(write!(w, "{}", c));
+                }
+            }
+            try!(writeln!(w, ""));
+        }
+        Ok(())
+    }
+
+    pub(crate) fn push(&mut self, command: String, panel: u64, before: Coords, after: Coords) {
+        if self.capacity == 0 { return }
+        if self.entries.len() == self.capacity {
+            self.entries.pop_front();
+        }
+        self.entries.push_back(TraceEntry {
+            time: self.start.elapsed(),
+            command: command,
+            panel: panel,
+            before: before,
+            after: after,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io;
+
+    use Output;
+    use cfg::TerminalConfig;
+    use datatypes::Coords;
+    use terminal::Terminal;
+
+    #[test]
+    fn keeps_last_commands() {
+        let mut terminal = Terminal::new(8, 2, io::sink(), TerminalConfig::default());
+        terminal.start_trace(2);
+        for cmd in Output::new(&b"ab\x1b[2;1H"[..]) {
+            terminal.apply(cmd.unwrap()).unwrap();
+        }
+        let trace = terminal.stop_trace().unwrap();
+        let entries = trace.entries().map(|entry| (&entry.command[..], entry.before, entry.after))
+                                     .collect::<Vec<_>>();
+        assert_eq!(entries, vec![
+            ("ab", Coords { x: 0, y: 0 }, Coords { x: 2, y: 0 }),
+            ("MOVE TO 0,1", Coords { x: 2, y: 0 }, Coords { x: 0, y: 1 }),
+        ]);
+        let mut dump = vec![];
+        trace.dump(&mut dump).unwrap();
+        assert_eq!(String::from_utf8(dump).unwrap().lines().count(), 2);
+        assert!(terminal.trace().is_none());
+    }
+}
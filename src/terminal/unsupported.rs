@@ -0,0 +1,136 @@
+//  notty is a new kind of terminal emulator.
+//  Copyright (C) 2015 without boats
+//  
+//  This program is free software: you can redistribute it and/or modify
+//  it under the terms of the GNU Affero General Public License as published by
+//  the Free Software Foundation, either version 3 of the License, or
+//  (at your option) any later version.
+//  
+//  This program is distributed in the hope that it will be useful,
+//  but WITHOUT ANY WARRANTY; without even the implied warranty of
+//  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+//  GNU Affero General Public License for more details.
+//  
+//  You should have received a copy of the GNU Affero General Public License
+//  along with this program.  If not, see <http://www.gnu.org/licenses/>.
+use std::collections::HashMap;
+use std::time::SystemTime;
+
+/// The most distinct unsupported sequences which are counted. Once this many have been seen,
+/// sequences which have not been seen before are only counted as untracked.
+pub const MAX_UNSUPPORTED: usize = 256;
+
+/// Counts of the escape sequences which the terminal received but does not support, so that
+/// the features which programs actually use can be found.
+#[derive(Default)]
+pub struct Unsupported {
+    sequences: HashMap<String, UnsupportedSequence>,
+    untracked: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UnsupportedSequence {
+    /// The first sequence of this kind which was received, as it was parsed.
+    pub sequence: String,
+    /// How many times the sequence was received.
+    pub count: u64,
+    /// When the sequence was first received.
+    pub first_seen: SystemTime,
+    /// The title of the terminal when the sequence was first received, which usually names the
+    /// program that sent it.
+    pub title: String,
+}
+
+impl Unsupported {
+    /// Every unsupported sequence which has been counted, the most common first.
+    pub fn sequences(&self) -> Vec<&UnsupportedSequence> {
+        let mut sequences = self.sequences.values().collect::<Vec<_>>();
+        sequences.sort_by(|a, b| (b.count, &a.sequence).cmp(&(a.count, &b.sequence)));
+        sequences
+    }
+
+    /// How many unsupported sequences were received after `MAX_UNSUPPORTED` distinct sequences
+    /// had been counted, which were not like any sequence counted before them.
+    pub fn untracked(&self) -> u64 {
+        self.untracked
+    }
+
+    pub fn clear(&mut self) {
+        self.sequences.clear();
+        self.untracked = 0;
+    }
+
+    pub(crate) fn record(&mut self, sequence: &str, title: &str) {
+        let key = kind(sequence);
+        if let Some(seen) = self.sequences.get_mut(&key) {
+            seen.count += 1;
+            return
+        }
+        if self.sequences.len() < MAX_UNSUPPORTED {
+            self.sequences.insert(key, UnsupportedSequence {
+                sequence: String::from(sequence),
+                count: 1,
+                first_seen: SystemTime::now(),
+                title: String::from(title),
+            });
+        } else {
+            self.untracked += 1;
+        }
+    }
+}
+
+/// The kind of an unsupported sequence. Control sequences are of the same kind if they have the
+/// same private mode, intermediate and final bytes, whatever their parameters; any other sequence
+/// is its own kind.
+fn kind(sequence: &str) -> String {
+    if sequence.starts_with("^[[") {
+        let mut kind = String::from("^[[");
+        kind.extend(sequence[3..].chars().filter(|&ch| !ch.is_digit(10) && ch != ';'));
+        kind
+    } else {
+        String::from(sequence)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn counts_sequences() {
+        let mut unsupported = Unsupported::default();
+        unsupported.record("CSI >0c", "vim");
+        unsupported.record("ESC Z", "less");
+        unsupported.record("CSI >0c", "less");
+        let sequences = unsupported.sequences();
+        assert_eq!(sequences.iter().map(|seq| (&seq.sequence[..], seq.count, &seq.title[..]))
+                            .collect::<Vec<_>>(),
+                   vec![("CSI >0c", 2, "vim"), ("ESC Z", 1, "less")]);
+    }
+
+    #[test]
+    fn keyed_by_kind() {
+        let mut unsupported = Unsupported::default();
+        unsupported.record("^[[>0c", "vim");
+        unsupported.record("^[[>1;2c", "less");
+        unsupported.record("^[[c", "less");
+        unsupported.record("^[[2 q", "less");
+        unsupported.record("^[[0 q", "less");
+        let sequences = unsupported.sequences();
+        assert_eq!(sequences.iter().map(|seq| (&seq.sequence[..], seq.count, &seq.title[..]))
+                            .collect::<Vec<_>>(),
+                   vec![("^[[2 q", 2, "less"), ("^[[>0c", 2, "vim"), ("^[[c", 1, "less")]);
+    }
+
+    #[test]
+    fn bounded() {
+        let mut unsupported = Unsupported::default();
+        for n in 0..MAX_UNSUPPORTED + 2 {
+            unsupported.record(&n.to_string(), "");
+        }
+        unsupported.record("0", "");
+        assert_eq!(unsupported.sequences().len(), MAX_UNSUPPORTED);
+        assert_eq!(unsupported.untracked(), 2);
+        assert_eq!(unsupported.sequences()[0].count, 2);
+    }
+}